@@ -13,9 +13,22 @@ module rbmk_constants
     ! Physical constants
     ! RBMK is a graphite-moderated reactor with relatively long neutron lifetime
     ! Typical values: 1.0e-3 s (graphite) vs 1.0e-4 s (LWR)
-    real(c_double), parameter :: NEUTRON_LIFETIME = 1.0d-3      ! Prompt neutron lifetime [s]
+    real(c_double), parameter :: DEFAULT_NEUTRON_LIFETIME = 1.0d-3  ! Prompt neutron lifetime [s]
+    
+    ! Prompt neutron lifetime in use [s]
+    ! Updated from Rust via set_neutron_lifetime (for lifetime sensitivity studies)
+    real(c_double), save :: neutron_lifetime_s = DEFAULT_NEUTRON_LIFETIME
     real(c_double), parameter :: BETA_EFF = 0.0065d0            ! Total effective delayed neutron fraction (U-235)
     
+    ! Single-step bound on neutron population growth (see bound_population_growth)
+    ! A prompt excursion at the reactivity ceiling grows as exp(rho_max * dt / Lambda);
+    ! the bound allows twice that exponent, capped so the population stays finite.
+    ! Below the floor population, source multiplication is not limited.
+    real(c_double), parameter :: REACTIVITY_CEILING = 0.02d0            ! Highest total reactivity [dk/k]
+    real(c_double), parameter :: POPULATION_GROWTH_MARGIN = 2.0d0
+    real(c_double), parameter :: POPULATION_GROWTH_MAX_EXPONENT = 40.0d0
+    real(c_double), parameter :: POPULATION_GROWTH_FLOOR = 1.0d-6
+    
     ! 6-group delayed neutron parameters for U-235 thermal fission
     ! βᵢ - delayed neutron fraction for group i
     ! λᵢ - decay constant for group i [1/s]
@@ -60,6 +73,11 @@ module rbmk_constants
     real(c_double), parameter :: GAMMA_XE = 0.003d0             ! Direct Xenon-135 fission yield
     real(c_double), parameter :: SIGMA_F = 0.0025d0             ! Fission cross-section [1/cm]
     
+    ! Promethium/Samarium constants
+    real(c_double), parameter :: GAMMA_PM = 0.0113d0            ! Promethium-149 cumulative fission yield
+    real(c_double), parameter :: LAMBDA_PM = 3.63d-6            ! Promethium-149 decay constant [1/s] (T1/2 = 53.1 h)
+    real(c_double), parameter :: SIGMA_SM = 4.1d-20             ! Samarium-149 absorption cross-section [cm^2]
+    
     ! RBMK-1000 specific parameters
     real(c_double), parameter :: CORE_HEIGHT = 700.0d0          ! Active core height [cm]
     real(c_double), parameter :: CORE_RADIUS = 593.0d0          ! Core radius [cm]
@@ -76,16 +94,27 @@ module rbmk_constants
     real(c_double), parameter :: ALPHA_VOID = 1.0d-4            ! Void coefficient [1/%void] - POSITIVE but reduced
     real(c_double), parameter :: ALPHA_GRAPHITE = 1.0d-5        ! Graphite temperature coefficient [1/K] - POSITIVE
     
+    ! Fuel state feedback (spatial model), relative to fresh fuel at reference enrichment
+    real(c_double), parameter :: REF_ENRICHMENT = 2.0d0         ! Reference U-235 enrichment [%]
+    real(c_double), parameter :: ALPHA_ENRICHMENT = 2.0d-2      ! Enrichment coefficient [1/% U-235] - POSITIVE
+    real(c_double), parameter :: ALPHA_BURNUP = -4.0d-3         ! Burnup coefficient [1/(MWd/kgU)] - NEGATIVE
+    
     ! Reference temperatures
     real(c_double), parameter :: REF_FUEL_TEMP = 900.0d0        ! Reference fuel temperature [K]
     real(c_double), parameter :: REF_GRAPHITE_TEMP = 650.0d0    ! Reference graphite temperature [K]
-    real(c_double), parameter :: SATURATION_TEMP = 558.0d0      ! Coolant saturation temperature at 7 MPa [K]
+    real(c_double), parameter :: NOMINAL_PRESSURE = 7.0d0       ! Nominal coolant pressure [MPa] (Tsat ≈ 559 K)
     
     ! Thermal time constants
     real(c_double), parameter :: COOLANT_TIME_CONST = 3.0d0     ! Coolant response time [s]
     real(c_double), parameter :: GRAPHITE_TIME_CONST = 60.0d0   ! Graphite response time [s] - large thermal mass
     real(c_double), parameter :: VOID_TIME_CONST = 2.0d0        ! Void formation time [s]
     
+    ! Boiling crisis (DNBR) parameters
+    real(c_double), parameter :: HEATED_AREA_PER_CHANNEL = 5.38d0 ! 18 rods x 13.6 mm x 7 m [m^2]
+    real(c_double), parameter :: CHF_NOMINAL = 0.75d0           ! Critical heat flux at nominal flow, no void [MW/m^2]
+    real(c_double), parameter :: NOMINAL_CHANNEL_FLOW = 5.5d0   ! Nominal channel flow [kg/s]
+    real(c_double), parameter :: DNBR_MAX = 99.0d0              ! Reported DNBR with no heat flux
+    
     ! Base excess reactivity (reactor is supercritical without rods)
     ! This should be balanced so that with normal rod positions reactor is critical
     real(c_double), parameter :: BASE_REACTIVITY = 0.08d0
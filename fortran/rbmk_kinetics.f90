@@ -25,6 +25,9 @@ module rbmk_kinetics
     use rbmk_constants
     implicit none
     
+    ! Sub-steps taken by the last call of a sub-stepping solver (RK4, 6-group)
+    integer(c_int), save :: last_substep_count = 1
+    
 contains
 
     ! =========================================================================
@@ -48,10 +51,10 @@ contains
         
         ! Point kinetics equations
         ! dn/dt = (ρ - β)/Λ · n + λ·C
-        dn_dt = ((reactivity - BETA_EFF) / NEUTRON_LIFETIME) * n_neutrons + delayed_source
+        dn_dt = ((reactivity - BETA_EFF) / neutron_lifetime_s) * n_neutrons + delayed_source
         
         ! dC/dt = β/Λ · n - λ·C
-        dC_dt = (BETA_EFF / NEUTRON_LIFETIME) * n_neutrons - LAMBDA_DECAY * precursors
+        dC_dt = (BETA_EFF / neutron_lifetime_s) * n_neutrons - LAMBDA_DECAY * precursors
         
         ! Update values
         n_new = n_neutrons + dn_dt * dt
@@ -62,6 +65,82 @@ contains
         if (precursors_new < 0.0d0) precursors_new = 0.0d0
         
     end subroutine solve_point_kinetics
+    
+    ! =========================================================================
+    ! Sub-steps of the last kinetics solve (1 for single-step schemes)
+    ! =========================================================================
+    subroutine get_last_substep_count(count) bind(C, name="get_last_substep_count")
+        integer(c_int), intent(out) :: count
+        
+        count = last_substep_count
+        
+    end subroutine get_last_substep_count
+    
+    ! =========================================================================
+    ! Safety net against unphysical single-step excursions
+    ! At large positive reactivity with a coarse dt a solver can grow the
+    ! population by many orders of magnitude (or to Inf/NaN) before feedback
+    ! responds. Limits n_new to the bounded growth factor; clamped = 1 if it did.
+    ! =========================================================================
+    subroutine bound_population_growth(n_old, dt, n_new, clamped)
+        real(c_double), intent(in) :: n_old
+        real(c_double), intent(in) :: dt
+        real(c_double), intent(inout) :: n_new
+        integer(c_int), intent(out) :: clamped
+        
+        real(c_double) :: exponent, n_max
+        
+        exponent = min(POPULATION_GROWTH_MARGIN * REACTIVITY_CEILING * dt / neutron_lifetime_s, &
+                       POPULATION_GROWTH_MAX_EXPONENT)
+        n_max = max(n_old, POPULATION_GROWTH_FLOOR) * exp(exponent)
+        
+        ! Written as "not below" so a NaN population is caught as well
+        if (.not. (n_new <= n_max)) then
+            n_new = n_max
+            clamped = 1
+        else
+            clamped = 0
+        end if
+        
+    end subroutine bound_population_growth
+    
+    ! =========================================================================
+    ! Prompt-jump approximation with single-group precursors
+    ! Neglects Λ·dn/dt, so the population follows the precursors instantly:
+    !   n = Λ·(λ·C + S) / (β - ρ)
+    ! and the precursors change at the stable-period rate
+    !   dC/dt = λ·C·ρ / (β - ρ) + β·S / (β - ρ)
+    ! Only meaningful below prompt critical: valid = 0 if ρ >= β.
+    ! =========================================================================
+    subroutine solve_point_kinetics_prompt_jump(precursors, reactivity, source_term, dt, &
+                                                n_new, precursors_new, valid) &
+                                                bind(C, name="solve_point_kinetics_prompt_jump")
+        real(c_double), intent(in), value :: precursors      ! Total delayed neutron precursors
+        real(c_double), intent(in), value :: reactivity      ! Current reactivity
+        real(c_double), intent(in), value :: source_term     ! External source S
+        real(c_double), intent(in), value :: dt              ! Time step [s]
+        real(c_double), intent(out) :: n_new                 ! New neutron population
+        real(c_double), intent(out) :: precursors_new        ! New total precursors
+        integer(c_int), intent(out) :: valid                 ! 1 if ρ < β, else 0
+        
+        real(c_double) :: margin, growth_rate
+        
+        margin = BETA_EFF - reactivity
+        if (margin <= 1.0d-6 * BETA_EFF) then
+            n_new = 0.0d0
+            precursors_new = precursors
+            valid = 0
+            return
+        end if
+        
+        ! Exact exponential over the step (exponent capped against overflow)
+        growth_rate = LAMBDA_DECAY * reactivity / margin
+        precursors_new = precursors * exp(min(growth_rate * dt, 50.0d0)) &
+                       + BETA_EFF * source_term / margin * dt
+        n_new = neutron_lifetime_s * (LAMBDA_DECAY * precursors_new + source_term) / margin
+        valid = 1
+        
+    end subroutine solve_point_kinetics_prompt_jump
 
     ! =========================================================================
     ! 6-Group Point Kinetics Solver with RK4 and Temperature Feedback
@@ -103,6 +182,7 @@ contains
         end if
         num_substeps = ceiling(dt / effective_dt)
         substep_dt = dt / dble(num_substeps)
+        last_substep_count = num_substeps
         
         ! Initialize
         n_current = n_neutrons
@@ -198,13 +278,13 @@ contains
         
         ! Neutron population equation:
         ! dn/dt = (ρ - β)/Λ · n + Σᵢ λᵢCᵢ + S
-        prompt_term = ((effective_rho - BETA_EFF) / NEUTRON_LIFETIME) * n
+        prompt_term = ((effective_rho - BETA_EFF) / neutron_lifetime_s) * n
         dn_dt = prompt_term + delayed_source + source
         
         ! Precursor equations for each group:
         ! dCᵢ/dt = βᵢ/Λ · n - λᵢCᵢ
         do g = 1, NUM_DELAYED_GROUPS
-            dc_dt(g) = (BETA_I(g) / NEUTRON_LIFETIME) * n - LAMBDA_I(g) * c(g)
+            dc_dt(g) = (BETA_I(g) / neutron_lifetime_s) * n - LAMBDA_I(g) * c(g)
         end do
         
         ! For very low neutron population with positive reactivity,
@@ -212,7 +292,7 @@ contains
         if (effective_rho > 0.0d0 .and. n < 1.0d-4) then
             if (effective_rho >= BETA_EFF) then
                 ! Prompt supercritical - very fast growth
-                dn_dt = max(dn_dt, ((effective_rho - BETA_EFF) / NEUTRON_LIFETIME) * n)
+                dn_dt = max(dn_dt, ((effective_rho - BETA_EFF) / neutron_lifetime_s) * n)
             else
                 ! Delayed supercritical - growth via delayed neutrons
                 ! Use weighted average lambda for period estimate
@@ -256,6 +336,7 @@ contains
         end if
         num_substeps = ceiling(dt / effective_dt)
         substep_dt = dt / dble(num_substeps)
+        last_substep_count = num_substeps
         
         ! Initialize
         n_current = n_neutrons
@@ -327,16 +408,16 @@ contains
         effective_rho = max(min(effective_rho, 0.02d0), -0.15d0)
         
         ! Point kinetics equations
-        prompt_term = ((effective_rho - BETA_EFF) / NEUTRON_LIFETIME) * n
+        prompt_term = ((effective_rho - BETA_EFF) / neutron_lifetime_s) * n
         delayed_term = LAMBDA_DECAY * c
         
         dn_dt = prompt_term + delayed_term
-        dc_dt = (BETA_EFF / NEUTRON_LIFETIME) * n - LAMBDA_DECAY * c
+        dc_dt = (BETA_EFF / neutron_lifetime_s) * n - LAMBDA_DECAY * c
         
         ! For very low neutron population with positive reactivity
         if (effective_rho > 0.0d0 .and. n < 1.0d-4) then
             if (effective_rho >= BETA_EFF) then
-                dn_dt = max(dn_dt, ((effective_rho - BETA_EFF) / NEUTRON_LIFETIME) * n)
+                dn_dt = max(dn_dt, ((effective_rho - BETA_EFF) / neutron_lifetime_s) * n)
             else
                 dn_dt = max(dn_dt, n * LAMBDA_DECAY * effective_rho / BETA_EFF)
             end if
@@ -362,7 +443,7 @@ contains
         
         ! At steady state: Cᵢ = βᵢ·n / (λᵢ·Λ)
         do g = 1, NUM_DELAYED_GROUPS
-            precursors_6(g) = (BETA_I(g) * n_neutrons) / (LAMBDA_I(g) * NEUTRON_LIFETIME)
+            precursors_6(g) = (BETA_I(g) * n_neutrons) / (LAMBDA_I(g) * neutron_lifetime_s)
         end do
         
     end subroutine init_precursors_6group
@@ -384,6 +465,29 @@ contains
         
     end subroutine sum_precursors_6group
     
+    ! =========================================================================
+    ! Advance 6-group precursors over dt at a given neutron population
+    ! dCᵢ/dt = βᵢ·n/Λ - λᵢ·Cᵢ, integrated exactly with n held constant:
+    ! Cᵢ(t+dt) = Cᵢ·e^(-λᵢ·dt) + βᵢ·n/(λᵢ·Λ)·(1 - e^(-λᵢ·dt))
+    ! =========================================================================
+    subroutine advance_precursors_6group(n_neutrons, precursors_6, dt, precursors_6_new) &
+                                         bind(C, name="advance_precursors_6group")
+        real(c_double), intent(in), value :: n_neutrons
+        real(c_double), intent(in) :: precursors_6(NUM_DELAYED_GROUPS)
+        real(c_double), intent(in), value :: dt
+        real(c_double), intent(out) :: precursors_6_new(NUM_DELAYED_GROUPS)
+        
+        integer :: g
+        real(c_double) :: decay, equilibrium
+        
+        do g = 1, NUM_DELAYED_GROUPS
+            decay = exp(-LAMBDA_I(g) * dt)
+            equilibrium = (BETA_I(g) * max(n_neutrons, 0.0d0)) / (LAMBDA_I(g) * neutron_lifetime_s)
+            precursors_6_new(g) = precursors_6(g) * decay + equilibrium * (1.0d0 - decay)
+        end do
+        
+    end subroutine advance_precursors_6group
+    
     ! =========================================================================
     ! Calculate reactor period from current state
     ! For delayed supercritical: T ≈ (β - ρ) / (λ_eff · ρ)
@@ -400,7 +504,7 @@ contains
         else if (reactivity > 0.0d0) then
             if (reactivity >= BETA_EFF) then
                 ! Prompt supercritical
-                period = NEUTRON_LIFETIME / (reactivity - BETA_EFF)
+                period = neutron_lifetime_s / (reactivity - BETA_EFF)
             else
                 ! Delayed supercritical
                 period = (BETA_EFF - reactivity) / (LAMBDA_DECAY * reactivity)
@@ -413,6 +517,31 @@ contains
         
     end subroutine calculate_reactor_period
     
+    ! =========================================================================
+    ! Reactivity that sustains a stable reactor period (inhour equation)
+    ! ρ = Λ/T + Σ βᵢ / (1 + λᵢ·T)
+    ! Negative periods are only meaningful if longer than 1/λ₁ (about -80 s).
+    ! =========================================================================
+    subroutine reactivity_from_period(period, reactivity) &
+                                      bind(C, name="reactivity_from_period")
+        real(c_double), intent(in), value :: period
+        real(c_double), intent(out) :: reactivity
+        
+        integer :: g
+        
+        if (abs(period) >= 1.0d10) then
+            ! Infinite period - critical
+            reactivity = 0.0d0
+            return
+        end if
+        
+        reactivity = neutron_lifetime_s / period
+        do g = 1, NUM_DELAYED_GROUPS
+            reactivity = reactivity + BETA_I(g) / (1.0d0 + LAMBDA_I(g) * period)
+        end do
+        
+    end subroutine reactivity_from_period
+    
     ! =========================================================================
     ! Convert reactivity to dollars
     ! 1 dollar = β_eff ≈ 0.0065
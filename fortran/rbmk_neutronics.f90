@@ -108,5 +108,32 @@ contains
         end do
         
     end subroutine update_axial_flux
+    
+    ! =========================================================================
+    ! Axial flux with a top/bottom tilt from absorber insertion
+    ! Point 1 is the bottom of the core, point n_points the top.
+    ! flux(z) = n * (1 - z^2) * (1 + tilt * z), z from -1 (bottom) to 1 (top)
+    ! tilt > 0: top-heavy (USP rods suppress the bottom)
+    ! tilt < 0: bottom-heavy (top-entry rods suppress the top)
+    ! =========================================================================
+    subroutine update_axial_flux_tilted(n_points, neutron_population, tilt, axial_flux) &
+               bind(C, name="update_axial_flux_tilted")
+        integer(c_int), intent(in), value :: n_points
+        real(c_double), intent(in), value :: neutron_population
+        real(c_double), intent(in), value :: tilt
+        real(c_double), intent(out) :: axial_flux(n_points)
+        
+        integer :: i
+        real(c_double) :: z, center, t
+        
+        center = dble(n_points) / 2.0d0
+        t = max(min(tilt, 0.9d0), -0.9d0)
+        
+        do i = 1, n_points
+            z = (dble(i) - center) / center  ! -1 (bottom) to 1 (top)
+            axial_flux(i) = neutron_population * max(1.0d0 - z * z, 0.0d0) * (1.0d0 + t * z)
+        end do
+        
+    end subroutine update_axial_flux_tilted
 
 end module rbmk_neutronics
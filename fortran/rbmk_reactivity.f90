@@ -8,8 +8,22 @@ module rbmk_reactivity
     use rbmk_constants
     implicit none
     
+    ! Time constant of the reactivity smoothing outside a SCRAM [s]
+    ! Updated from Rust via set_reactivity_smoothing_tau
+    real(c_double), save :: reactivity_smoothing_tau = 0.5d0
+    
 contains
 
+    ! =========================================================================
+    ! Set the reactivity smoothing time constant used outside a SCRAM [s]
+    ! =========================================================================
+    subroutine set_reactivity_smoothing_tau(tau) bind(C, name="set_reactivity_smoothing_tau")
+        real(c_double), intent(in), value :: tau
+        
+        reactivity_smoothing_tau = tau
+        
+    end subroutine set_reactivity_smoothing_tau
+
     ! =========================================================================
     ! Calculate reactivity with temperature and xenon feedback
     ! rho = (k_eff - 1) / k_eff
@@ -40,9 +54,7 @@ contains
         delta_rho_void = ALPHA_VOID * coolant_void
         
         ! Xenon poisoning (negative reactivity)
-        ! Using empirical coefficient for reactivity effect
-        ! At equilibrium ~100% power, Xe-135 ≈ 5e13 at/cm³ should give ~-3% reactivity
-        delta_rho_xe = -1.5d-16 * xe_concentration
+        call calculate_xenon_reactivity(xe_concentration, delta_rho_xe)
         
         ! Graphite temperature feedback (POSITIVE in RBMK)
         delta_rho_graphite = ALPHA_GRAPHITE * (graphite_temp - REF_GRAPHITE_TEMP)
@@ -53,27 +65,37 @@ contains
     end subroutine calculate_reactivity
 
     ! =========================================================================
-    ! Calculate total reactivity from all sources for simulation step
-    ! This is the main reactivity calculation used in the simulation
+    ! Xenon poisoning reactivity for the point (lumped) model
+    ! At equilibrium ~100% power, Xe-135 ≈ 1e14 at/cm³ should give ~-3% reactivity
+    ! Using coefficient 1.5e-16 gives: 1e14 * 1.5e-16 = 0.015 = 1.5% per 1e14
+    ! At 2e14 this gives ~3% negative reactivity, which is realistic
     ! =========================================================================
-    subroutine calculate_total_reactivity(fuel_temp, graphite_temp, coolant_void, &
-                                          xenon_135, rod_worth, smoothed_reactivity, &
-                                          dt, scram_active, new_reactivity) &
-                                          bind(C, name="calculate_total_reactivity")
+    subroutine calculate_xenon_reactivity(xenon_135, reactivity) &
+               bind(C, name="calculate_xenon_reactivity")
+        real(c_double), intent(in), value :: xenon_135        ! [atoms/cm^3]
+        real(c_double), intent(out) :: reactivity             ! [dk/k] (negative)
+        
+        reactivity = -1.5d-16 * xenon_135
+        
+    end subroutine calculate_xenon_reactivity
+
+    ! =========================================================================
+    ! Steady-state (target) reactivity for given conditions
+    ! The unsmoothed value that calculate_total_reactivity relaxes toward;
+    ! also used to search for the critical rod position
+    ! =========================================================================
+    subroutine calculate_target_reactivity(fuel_temp, graphite_temp, coolant_void, &
+                                           xenon_135, rod_worth, target_reactivity) &
+                                           bind(C, name="calculate_target_reactivity")
         real(c_double), intent(in), value :: fuel_temp        ! [K]
         real(c_double), intent(in), value :: graphite_temp    ! [K]
         real(c_double), intent(in), value :: coolant_void     ! [%]
         real(c_double), intent(in), value :: xenon_135        ! [atoms/cm^3]
         real(c_double), intent(in), value :: rod_worth        ! Total rod worth (positive value)
-        real(c_double), intent(in), value :: smoothed_reactivity ! Previous smoothed reactivity
-        real(c_double), intent(in), value :: dt               ! Time step [s]
-        integer(c_int), intent(in), value :: scram_active     ! 1 if SCRAM active, 0 otherwise
-        real(c_double), intent(out) :: new_reactivity         ! New smoothed reactivity
+        real(c_double), intent(out) :: target_reactivity      ! [dk/k]
         
         real(c_double) :: fuel_temp_reactivity, graphite_temp_reactivity
         real(c_double) :: void_reactivity, xe_reactivity, rod_reactivity
-        real(c_double) :: target_reactivity, smoothing_tau, smoothing_alpha
-        real(c_double) :: max_reactivity_rate, reactivity_change
         
         ! 1. Fuel temperature feedback (Doppler effect - NEGATIVE feedback only)
         ! ALPHA_FUEL is negative (-5e-5), so higher temperature = more negative reactivity
@@ -95,24 +117,47 @@ contains
         void_reactivity = ALPHA_VOID * coolant_void
         
         ! 4. Xenon poisoning (negative)
-        ! At equilibrium ~100% power, Xe-135 ≈ 1e14 at/cm³ should give ~-3% reactivity
-        ! Using coefficient 1.5e-16 gives: 1e14 * 1.5e-16 = 0.015 = 1.5% per 1e14
-        ! At 2e14 this gives ~3% negative reactivity, which is realistic
-        xe_reactivity = -1.5d-16 * xenon_135
+        call calculate_xenon_reactivity(xenon_135, xe_reactivity)
         
         ! 5. Control rod worth (negative when inserted)
         rod_reactivity = -rod_worth
         
-        ! Calculate target reactivity
         target_reactivity = BASE_REACTIVITY + fuel_temp_reactivity + graphite_temp_reactivity &
                           + void_reactivity + xe_reactivity + rod_reactivity
         
+    end subroutine calculate_target_reactivity
+
+    ! =========================================================================
+    ! Calculate total reactivity from all sources for simulation step
+    ! This is the main reactivity calculation used in the simulation
+    ! =========================================================================
+    subroutine calculate_total_reactivity(fuel_temp, graphite_temp, coolant_void, &
+                                          xenon_135, rod_worth, smoothed_reactivity, &
+                                          dt, scram_active, new_reactivity) &
+                                          bind(C, name="calculate_total_reactivity")
+        real(c_double), intent(in), value :: fuel_temp        ! [K]
+        real(c_double), intent(in), value :: graphite_temp    ! [K]
+        real(c_double), intent(in), value :: coolant_void     ! [%]
+        real(c_double), intent(in), value :: xenon_135        ! [atoms/cm^3]
+        real(c_double), intent(in), value :: rod_worth        ! Total rod worth (positive value)
+        real(c_double), intent(in), value :: smoothed_reactivity ! Previous smoothed reactivity
+        real(c_double), intent(in), value :: dt               ! Time step [s]
+        integer(c_int), intent(in), value :: scram_active     ! 1 if SCRAM active, 0 otherwise
+        real(c_double), intent(out) :: new_reactivity         ! New smoothed reactivity
+        
+        real(c_double) :: target_reactivity, smoothing_tau, smoothing_alpha
+        real(c_double) :: max_reactivity_rate, reactivity_change
+        
+        ! Steady-state reactivity for the current conditions
+        call calculate_target_reactivity(fuel_temp, graphite_temp, coolant_void, &
+                                         xenon_135, rod_worth, target_reactivity)
+        
         ! Apply exponential smoothing to reactivity changes for numerical stability
         ! Use shorter time constant for faster response to operator actions
         if (scram_active == 1) then
             smoothing_tau = 0.05d0  ! Fast response during SCRAM (gravity drop)
         else
-            smoothing_tau = 0.5d0   ! Faster response for better simulation interactivity
+            smoothing_tau = reactivity_smoothing_tau  ! Tunable (default 0.5 s)
         end if
         smoothing_alpha = min(dt / smoothing_tau, 1.0d0)
         
@@ -141,17 +186,21 @@ contains
         end if
         
         ! Clamp to physically reasonable bounds
-        new_reactivity = max(min(new_reactivity, 0.02d0), -0.10d0)
+        new_reactivity = max(min(new_reactivity, REACTIVITY_CEILING), -0.10d0)
         
     end subroutine calculate_total_reactivity
 
     ! =========================================================================
     ! Calculate control rod worth based on position
     ! Uses S-curve worth distribution (more realistic than linear)
+    ! The shape exponent stretches the curve along the stroke: 1.0 is the
+    ! symmetric S-curve, larger values keep most of the worth near the bottom
+    ! (shortened USP rods), smaller values near the top.
     ! =========================================================================
-    subroutine calculate_rod_worth(rod_position, max_worth, worth) bind(C, name="calculate_rod_worth")
+    subroutine calculate_rod_worth(rod_position, max_worth, shape, worth) bind(C, name="calculate_rod_worth")
         real(c_double), intent(in), value :: rod_position    ! 0.0 = fully inserted, 1.0 = fully withdrawn
         real(c_double), intent(in), value :: max_worth       ! Maximum rod worth [dk/k]
+        real(c_double), intent(in), value :: shape           ! Worth curve shape exponent (> 0)
         real(c_double), intent(out) :: worth                 ! Current rod worth [dk/k]
         
         real(c_double) :: normalized_pos
@@ -159,6 +208,7 @@ contains
         
         ! Clamp position to valid range
         normalized_pos = max(min(rod_position, 1.0d0), 0.0d0)
+        if (shape > 0.0d0) normalized_pos = normalized_pos**shape
         
         ! S-curve worth distribution
         ! Worth = max_worth * (1 - sin^2(pi/2 * position))
@@ -37,6 +37,34 @@ contains
         in_excursion = .false.
     end subroutine reset_explosion_state
 
+    ! =========================================================================
+    ! Read and restore explosion tracking state (checkpoints, rewind)
+    ! in_excursion is passed as 0/1
+    ! =========================================================================
+    subroutine get_explosion_state(peak_power, peak_fuel, energy, start_time, excursion) &
+        bind(C, name="get_explosion_state")
+        real(c_double), intent(out) :: peak_power, peak_fuel, energy, start_time
+        integer(c_int), intent(out) :: excursion
+        
+        peak_power = peak_power_percent
+        peak_fuel = peak_fuel_temp
+        energy = energy_deposited
+        start_time = excursion_start_time
+        excursion = merge(1, 0, in_excursion)
+    end subroutine get_explosion_state
+
+    subroutine set_explosion_state(peak_power, peak_fuel, energy, start_time, excursion) &
+        bind(C, name="set_explosion_state")
+        real(c_double), intent(in), value :: peak_power, peak_fuel, energy, start_time
+        integer(c_int), intent(in), value :: excursion
+        
+        peak_power_percent = peak_power
+        peak_fuel_temp = peak_fuel
+        energy_deposited = energy
+        excursion_start_time = start_time
+        in_excursion = excursion /= 0
+    end subroutine set_explosion_state
+
     ! =========================================================================
     ! Detect steam explosion based on physics conditions
     ! Returns explosion severity (0 = safe, >= 1.0 = explosion)
@@ -163,6 +191,7 @@ contains
     ! Check safety limits and return alert flags
     ! Returns bit flags: 1=power, 2=reactivity, 4=prompt_critical, 
     !                    8=fuel_temp, 16=void, 32=period
+    ! (simulation_step adds 64 when the population growth was clamped)
     ! =========================================================================
     subroutine check_safety_limits(power_percent, reactivity_dollars, fuel_temp, &
                                    coolant_void, period, alert_flags) &
@@ -19,6 +19,14 @@ module rbmk_simulation
     real(c_double), save :: precursors_6_state(NUM_DELAYED_GROUPS) = 0.0d0
     logical, save :: precursors_initialized = .false.
     
+    ! Point kinetics solver used by simulation_step
+    ! Updated from Rust via set_kinetics_solver
+    integer(c_int), parameter :: SOLVER_SIX_GROUP = 0   ! 6-group RK4 (default)
+    integer(c_int), parameter :: SOLVER_EULER = 1       ! 1-group explicit Euler
+    integer(c_int), parameter :: SOLVER_RK4 = 2         ! 1-group RK4
+    integer(c_int), parameter :: SOLVER_PROMPT_JUMP = 3 ! 1-group prompt-jump approximation
+    integer(c_int), save :: kinetics_solver = SOLVER_SIX_GROUP
+    
 contains
 
     ! =========================================================================
@@ -81,6 +89,8 @@ contains
         real(c_double) :: fuel_temp_kinetics
         real(c_double) :: precursors_6_new(NUM_DELAYED_GROUPS)
         real(c_double) :: source_term
+        integer(c_int) :: prompt_jump_valid
+        integer(c_int) :: growth_clamped
         
         ! Initialize 6-group precursors on first call or if reset
         if (.not. precursors_initialized .or. precursors < 1.0d-10) then
@@ -105,23 +115,54 @@ contains
             end if
         end if
         
-        ! Step 3: Solve point kinetics with 6-group RK4
+        ! Step 3: Solve point kinetics with the selected solver
         ! External source term (for subcritical startup)
         source_term = 0.0d0
         if (neutron_population < 1.0d-4) then
             source_term = 1.0d-8  ! Small neutron source for startup
         end if
         
-        call solve_point_kinetics_6group( &
-            neutron_population, precursors_6_state, fuel_temp, reactivity_new, &
-            source_term, dt, &
-            neutron_population_new, precursors_6_new, fuel_temp_kinetics)
-        
-        ! Update stored 6-group precursors
-        precursors_6_state = precursors_6_new
+        select case (kinetics_solver)
+        case (SOLVER_EULER)
+            ! Explicit Euler goes unstable once dt exceeds ~Λ/β
+            call solve_point_kinetics( &
+                neutron_population, precursors, reactivity_new, dt, &
+                neutron_population_new, precursors_new)
+            neutron_population_new = neutron_population_new + source_term * dt
+            fuel_temp_kinetics = fuel_temp
+            last_substep_count = 1
+        case (SOLVER_RK4)
+            call solve_point_kinetics_rk4( &
+                neutron_population, precursors, fuel_temp, reactivity_new, dt, &
+                neutron_population_new, precursors_new, fuel_temp_kinetics)
+            neutron_population_new = neutron_population_new + source_term * dt
+        case (SOLVER_PROMPT_JUMP)
+            call solve_point_kinetics_prompt_jump( &
+                precursors, reactivity_new, source_term, dt, &
+                neutron_population_new, precursors_new, prompt_jump_valid)
+            fuel_temp_kinetics = fuel_temp
+            last_substep_count = 1
+            if (prompt_jump_valid == 0) then
+                ! Prompt critical: the approximation breaks down, integrate fully
+                call solve_point_kinetics_rk4( &
+                    neutron_population, precursors, fuel_temp, reactivity_new, dt, &
+                    neutron_population_new, precursors_new, fuel_temp_kinetics)
+            end if
+        case default
+            call solve_point_kinetics_6group( &
+                neutron_population, precursors_6_state, fuel_temp, reactivity_new, &
+                source_term, dt, &
+                neutron_population_new, precursors_6_new, fuel_temp_kinetics)
+            
+            ! Update stored 6-group precursors
+            precursors_6_state = precursors_6_new
+            
+            ! Calculate total precursors for output (sum of all 6 groups)
+            call sum_precursors_6group(precursors_6_new, precursors_new)
+        end select
         
-        ! Calculate total precursors for output (sum of all 6 groups)
-        call sum_precursors_6group(precursors_6_new, precursors_new)
+        ! Keep a catastrophic excursion finite (flagged below)
+        call bound_population_growth(neutron_population, dt, neutron_population_new, growth_clamped)
         
         ! Step 4: Calculate power
         call calculate_thermal_power(neutron_population_new, 1.0d0, power_mw)
@@ -167,6 +208,9 @@ contains
         call check_safety_limits( &
             power_percent, reactivity_dollars, fuel_temp_new, &
             coolant_void_new, period, alert_flags)
+        if (growth_clamped /= 0) then
+            alert_flags = ior(alert_flags, 64)
+        end if
         
     end subroutine simulation_step
 
@@ -180,11 +224,36 @@ contains
         real(c_double), intent(out) :: nominal_power_out
         
         beta_eff_out = BETA_EFF
-        neutron_lifetime_out = NEUTRON_LIFETIME
+        neutron_lifetime_out = neutron_lifetime_s
         nominal_power_out = NOMINAL_POWER
         
     end subroutine get_constants
     
+    ! =========================================================================
+    ! Set prompt neutron lifetime used by all kinetics solvers
+    ! =========================================================================
+    subroutine set_neutron_lifetime(lifetime) bind(C, name="set_neutron_lifetime")
+        real(c_double), intent(in), value :: lifetime  ! [s]
+        
+        neutron_lifetime_s = lifetime
+        
+    end subroutine set_neutron_lifetime
+    
+    ! =========================================================================
+    ! Select the point kinetics solver used by simulation_step
+    ! The 6-group precursors are re-initialized from the current population
+    ! when the solver changes, since the 1-group solvers do not update them.
+    ! =========================================================================
+    subroutine set_kinetics_solver(solver) bind(C, name="set_kinetics_solver")
+        integer(c_int), intent(in), value :: solver  ! SOLVER_* code
+        
+        if (solver /= kinetics_solver) then
+            kinetics_solver = solver
+            precursors_initialized = .false.
+        end if
+        
+    end subroutine set_kinetics_solver
+    
     ! =========================================================================
     ! Reset 6-group precursor state (called when simulation is reset)
     ! =========================================================================
@@ -198,6 +267,17 @@ contains
         
     end subroutine reset_precursors_6group_state
     
+    ! =========================================================================
+    ! Restore saved 6-group precursor concentrations (rewind to a checkpoint)
+    ! =========================================================================
+    subroutine set_precursors_6group_state(precursors_in) bind(C, name="set_precursors_6group_state")
+        real(c_double), intent(in) :: precursors_in(NUM_DELAYED_GROUPS)
+        
+        precursors_6_state = precursors_in
+        precursors_initialized = .true.
+        
+    end subroutine set_precursors_6group_state
+    
     ! =========================================================================
     ! Get current 6-group precursor concentrations (for diagnostics/UI)
     ! =========================================================================
@@ -17,6 +17,8 @@
 module rbmk_spatial
     use iso_c_binding
     use rbmk_constants
+    use rbmk_thermal, only: saturation_temperature, coolant_pressure, graphite_heat_removal, &
+                            fuel_conductivity_factor
     implicit none
     
     ! Maximum number of channels
@@ -37,6 +39,9 @@ module rbmk_spatial
     ! Grid spacing (cm) - distance between channel centers
     real(c_double), parameter :: GRID_SPACING = 25.0d0
     
+    ! Inlet temperature the coolant targets are calibrated for (K, 270 C)
+    real(c_double), parameter :: NOMINAL_INLET_TEMP = 543.0d0
+    
 contains
 
     ! =========================================================================
@@ -53,8 +58,8 @@ contains
         ! Per-channel input arrays (size: num_channels)
         neutron_flux_in, precursors_in, &
         fuel_temp_in, coolant_temp_in, graphite_temp_in, coolant_void_in, &
-        iodine_in, xenon_in, &
-        local_rod_worth_in, &
+        iodine_in, xenon_in, promethium_in, samarium_in, &
+        local_rod_worth_in, enrichment_in, burnup_in, inlet_temp_in, &
         ! Neighbor connectivity (size: num_channels * MAX_NEIGHBORS)
         ! -1 means no neighbor at that position
         neighbor_indices, num_neighbors, &
@@ -63,7 +68,7 @@ contains
         ! Per-channel output arrays (size: num_channels)
         neutron_flux_out, precursors_out, &
         fuel_temp_out, coolant_temp_out, graphite_temp_out, coolant_void_out, &
-        iodine_out, xenon_out, &
+        iodine_out, xenon_out, promethium_out, samarium_out, &
         local_power_out, local_reactivity_out &
     ) bind(C, name="spatial_simulation_step")
         
@@ -86,7 +91,12 @@ contains
         real(c_double), intent(in) :: coolant_void_in(num_channels)
         real(c_double), intent(in) :: iodine_in(num_channels)
         real(c_double), intent(in) :: xenon_in(num_channels)
+        real(c_double), intent(in) :: promethium_in(num_channels)
+        real(c_double), intent(in) :: samarium_in(num_channels)
         real(c_double), intent(in) :: local_rod_worth_in(num_channels)
+        real(c_double), intent(in) :: enrichment_in(num_channels)   ! U-235 enrichment [%]
+        real(c_double), intent(in) :: burnup_in(num_channels)       ! Burnup [MWd/kgU]
+        real(c_double), intent(in) :: inlet_temp_in(num_channels)   ! Coolant inlet temperature [K]
         
         ! Neighbor connectivity
         integer(c_int), intent(in) :: neighbor_indices(num_channels * MAX_NEIGHBORS)
@@ -105,6 +115,8 @@ contains
         real(c_double), intent(out) :: coolant_void_out(num_channels)
         real(c_double), intent(out) :: iodine_out(num_channels)
         real(c_double), intent(out) :: xenon_out(num_channels)
+        real(c_double), intent(out) :: promethium_out(num_channels)
+        real(c_double), intent(out) :: samarium_out(num_channels)
         real(c_double), intent(out) :: local_power_out(num_channels)
         real(c_double), intent(out) :: local_reactivity_out(num_channels)
         
@@ -127,8 +139,8 @@ contains
             ! =====================================================
             call calculate_local_reactivity( &
                 fuel_temp_in(i), graphite_temp_in(i), coolant_void_in(i), &
-                xenon_in(i), local_rod_worth_in(i), total_rod_worth, &
-                scram_active, local_reactivity)
+                xenon_in(i), samarium_in(i), local_rod_worth_in(i), total_rod_worth, &
+                enrichment_in(i), burnup_in(i), scram_active, local_reactivity)
             
             local_reactivity_out(i) = local_reactivity
             
@@ -173,7 +185,7 @@ contains
             ! Production term: (k-1)/l * φ (fission minus absorption)
             ! For prompt neutrons: (1-β)(k-1)/l * φ
             ! This is the main driver of power changes
-            production_term = (1.0d0 - BETA_EFF) * (k_local - 1.0d0) / NEUTRON_LIFETIME * neutron_flux_in(i)
+            production_term = (1.0d0 - BETA_EFF) * (k_local - 1.0d0) / neutron_lifetime_s * neutron_flux_in(i)
             
             ! Xenon absorption term (already included in k_local via reactivity)
             ! Don't double-count xenon
@@ -195,7 +207,7 @@ contains
             ! Step 4: Delayed Neutron Precursors
             ! =====================================================
             ! dC/dt = β/l * φ - λC
-            dc_dt = BETA_EFF / NEUTRON_LIFETIME * neutron_flux_in(i) - &
+            dc_dt = BETA_EFF / neutron_lifetime_s * neutron_flux_in(i) - &
                     LAMBDA_DECAY * precursors_in(i)
             
             precursors_out(i) = precursors_in(i) + dc_dt * dt
@@ -247,7 +259,7 @@ contains
             ! Step 6: Per-channel thermal-hydraulics
             ! =====================================================
             call update_channel_temperatures( &
-                local_power_out(i), &
+                local_power_out(i), burnup_in(i), inlet_temp_in(i), &
                 fuel_temp_in(i), coolant_temp_in(i), graphite_temp_in(i), coolant_void_in(i), &
                 dt, &
                 fuel_temp_out(i), coolant_temp_out(i), graphite_temp_out(i), coolant_void_out(i))
@@ -274,6 +286,13 @@ contains
                 iodine_in(i), xenon_in(i), local_power_out(i), dt, &
                 iodine_out(i), xenon_out(i))
             
+            ! =====================================================
+            ! Step 8: Per-channel promethium/samarium dynamics
+            ! =====================================================
+            call update_channel_samarium( &
+                promethium_in(i), samarium_in(i), local_power_out(i), dt, &
+                promethium_out(i), samarium_out(i))
+            
         end do
         
     end subroutine spatial_simulation_step
@@ -295,24 +314,28 @@ contains
     ! - Temperature feedbacks (Doppler, graphite)
     ! - Void coefficient
     ! - Xenon poisoning
+    ! - Fuel state (enrichment and burnup of this channel's fuel)
     ! =========================================================================
     subroutine calculate_local_reactivity( &
         fuel_temp, graphite_temp, coolant_void, &
-        xenon_conc, local_rod_worth, total_rod_worth, &
-        scram_active, reactivity)
+        xenon_conc, samarium_conc, local_rod_worth, total_rod_worth, &
+        enrichment, burnup, scram_active, reactivity)
         
         real(c_double), intent(in) :: fuel_temp
         real(c_double), intent(in) :: graphite_temp
         real(c_double), intent(in) :: coolant_void
         real(c_double), intent(in) :: xenon_conc
+        real(c_double), intent(in) :: samarium_conc
         real(c_double), intent(in) :: local_rod_worth  ! Distance-weighted sum of nearby rod worths
         real(c_double), intent(in) :: total_rod_worth  ! Total worth of ALL inserted rods (global)
+        real(c_double), intent(in) :: enrichment       ! U-235 enrichment [%]
+        real(c_double), intent(in) :: burnup           ! Burnup [MWd/kgU]
         integer(c_int), intent(in) :: scram_active
         real(c_double), intent(out) :: reactivity
         
-        real(c_double) :: rho_fuel, rho_void, rho_graphite, rho_xenon, rho_rods
+        real(c_double) :: rho_fuel, rho_void, rho_graphite, rho_xenon, rho_samarium, rho_rods
+        real(c_double) :: rho_fuel_state
         real(c_double) :: rho_local_effect
-        real(c_double) :: xenon_eq
         real(c_double) :: max_local_rod_worth
         
         ! Base reactivity (excess reactivity of fresh core)
@@ -331,13 +354,14 @@ contains
         rho_graphite = ALPHA_GRAPHITE * (graphite_temp - REF_GRAPHITE_TEMP)
         
         ! Xenon poisoning (always negative)
-        ! Xe-135 has huge neutron absorption cross-section
-        xenon_eq = 3.0d15  ! Equilibrium xenon at full power
-        if (xenon_conc > 0.0d0) then
-            rho_xenon = -0.03d0 * (xenon_conc / xenon_eq)
-        else
-            rho_xenon = 0.0d0
-        end if
+        call calculate_local_xenon_reactivity(xenon_conc, rho_xenon)
+        
+        ! Samarium poisoning (always negative, does not decay)
+        call calculate_local_samarium_reactivity(samarium_conc, rho_samarium)
+        
+        ! Fuel state: richer fuel multiplies more, burnt fuel less
+        ! (fresh fuel at the reference enrichment contributes nothing)
+        rho_fuel_state = ALPHA_ENRICHMENT * (enrichment - REF_ENRICHMENT) + ALPHA_BURNUP * burnup
         
         ! GLOBAL control rod effect
         ! total_rod_worth is the sum of all inserted rod worths
@@ -375,23 +399,66 @@ contains
         
         ! Total reactivity for this channel
         ! = base + feedbacks + global rod effect + local effect
-        reactivity = reactivity + rho_fuel + rho_void + rho_graphite + rho_xenon + rho_rods + rho_local_effect
+        reactivity = reactivity + rho_fuel + rho_void + rho_graphite + rho_xenon + rho_samarium &
+                   + rho_fuel_state + rho_rods + rho_local_effect
         
         ! Clamp to reasonable range
         reactivity = max(-0.2d0, min(0.15d0, reactivity))
         
     end subroutine calculate_local_reactivity
     
+    ! =========================================================================
+    ! Xenon poisoning reactivity for a single channel (always negative)
+    ! Xe-135 has huge neutron absorption cross-section
+    ! =========================================================================
+    subroutine calculate_local_xenon_reactivity(xenon_conc, reactivity) &
+               bind(C, name="calculate_local_xenon_reactivity")
+        real(c_double), intent(in), value :: xenon_conc       ! [atoms/cm^3]
+        real(c_double), intent(out) :: reactivity             ! [dk/k]
+        
+        real(c_double), parameter :: XENON_EQ = 3.0d15        ! Equilibrium xenon at full power
+        
+        if (xenon_conc > 0.0d0) then
+            reactivity = -0.03d0 * (xenon_conc / XENON_EQ)
+        else
+            reactivity = 0.0d0
+        end if
+        
+    end subroutine calculate_local_xenon_reactivity
+    
+    ! =========================================================================
+    ! Samarium poisoning reactivity for a single channel (always negative)
+    ! Sm-149 is stable, so this worth persists after shutdown
+    ! =========================================================================
+    subroutine calculate_local_samarium_reactivity(samarium_conc, reactivity) &
+               bind(C, name="calculate_local_samarium_reactivity")
+        real(c_double), intent(in), value :: samarium_conc    ! [atoms/cm^3]
+        real(c_double), intent(out) :: reactivity             ! [dk/k]
+        
+        ! Equilibrium samarium (independent of power level):
+        ! Sm_eq = γ_Pm * F / (σ_Sm * φ) = 0.0113 * 5.3e11 / (4.1e-20 * 1e14) ≈ 1.46e15
+        real(c_double), parameter :: SAMARIUM_EQ = 1.46d15
+        
+        if (samarium_conc > 0.0d0) then
+            reactivity = -0.007d0 * (samarium_conc / SAMARIUM_EQ)
+        else
+            reactivity = 0.0d0
+        end if
+        
+    end subroutine calculate_local_samarium_reactivity
+    
     ! =========================================================================
     ! Update temperatures for a single channel
     ! =========================================================================
     subroutine update_channel_temperatures( &
-        local_power, &
+        local_power, burnup, inlet_temp, &
         fuel_temp_in, coolant_temp_in, graphite_temp_in, coolant_void_in, &
         dt, &
         fuel_temp_out, coolant_temp_out, graphite_temp_out, coolant_void_out)
         
         real(c_double), intent(in) :: local_power
+        real(c_double), intent(in) :: burnup           ! [MWd/kgU]
+        real(c_double), intent(in) :: inlet_temp       ! Coolant entering the channel [K]
         real(c_double), intent(in) :: fuel_temp_in
         real(c_double), intent(in) :: coolant_temp_in
         real(c_double), intent(in) :: graphite_temp_in
@@ -405,6 +472,7 @@ contains
         real(c_double) :: power_fraction, target_fuel_temp, target_coolant_temp
         real(c_double) :: target_graphite_temp, target_void
         real(c_double) :: fuel_tau, coolant_tau, graphite_tau, void_tau
+        real(c_double) :: t_sat, conductivity
         
         ! Power fraction (relative to nominal per-channel power)
         power_fraction = local_power / (NOMINAL_POWER / real(NUM_CHANNELS, c_double))
@@ -412,21 +480,25 @@ contains
         
         ! Target temperatures based on power
         ! At 100% power: fuel ~900K, coolant ~560K, graphite ~650K
-        target_fuel_temp = 300.0d0 + 600.0d0 * power_fraction
-        target_coolant_temp = 300.0d0 + 260.0d0 * power_fraction
-        target_graphite_temp = 300.0d0 + 350.0d0 * power_fraction
+        ! Aged fuel conducts worse and runs hotter (see fuel_conductivity_factor)
+        ! Coolant shifts with the inlet temperature away from nominal
+        conductivity = fuel_conductivity_factor(burnup)
+        target_fuel_temp = 300.0d0 + 600.0d0 * power_fraction / conductivity
+        target_coolant_temp = 300.0d0 + 260.0d0 * power_fraction + (inlet_temp - NOMINAL_INLET_TEMP)
+        target_graphite_temp = 300.0d0 + 350.0d0 * power_fraction / graphite_heat_removal()
         
         ! Void formation above saturation temperature
-        if (coolant_temp_in > SATURATION_TEMP) then
-            target_void = min(100.0d0, (coolant_temp_in - SATURATION_TEMP) * 2.0d0)
+        t_sat = saturation_temperature(coolant_pressure)
+        if (coolant_temp_in > t_sat) then
+            target_void = min(100.0d0, (coolant_temp_in - t_sat) * 2.0d0)
         else
             target_void = 0.0d0
         end if
         
         ! Time constants (faster response at higher power)
-        fuel_tau = 5.0d0 / max(power_fraction, 0.1d0)
+        fuel_tau = 5.0d0 / max(power_fraction, 0.1d0) / conductivity
         coolant_tau = COOLANT_TIME_CONST
-        graphite_tau = GRAPHITE_TIME_CONST
+        graphite_tau = GRAPHITE_TIME_CONST / graphite_heat_removal()
         void_tau = VOID_TIME_CONST
         
         ! First-order lag response
@@ -504,6 +576,69 @@ contains
         
     end subroutine update_channel_xenon
     
+    ! =========================================================================
+    ! Update promethium/samarium for a single channel
+    ! Pm-149 is produced by fission and decays to stable Sm-149,
+    ! which is removed only by neutron absorption (burnout)
+    ! =========================================================================
+    subroutine update_channel_samarium( &
+        promethium_in, samarium_in, local_power, dt, &
+        promethium_out, samarium_out)
+        
+        real(c_double), intent(in) :: promethium_in
+        real(c_double), intent(in) :: samarium_in
+        real(c_double), intent(in) :: local_power  ! Local channel power [MW]
+        real(c_double), intent(in) :: dt
+        real(c_double), intent(out) :: promethium_out
+        real(c_double), intent(out) :: samarium_out
+        
+        real(c_double) :: fission_rate_density, flux_scaled, power_fraction
+        real(c_double) :: dpm_dt, dsm_dt
+        
+        ! Same scaling as update_channel_xenon
+        real(c_double), parameter :: NOMINAL_FISSION_RATE = 5.3d11
+        real(c_double), parameter :: CHANNEL_NOMINAL_POWER = 1.93d0  ! MW
+        
+        power_fraction = max(0.0d0, local_power / CHANNEL_NOMINAL_POWER)
+        flux_scaled = power_fraction * 1.0d14
+        fission_rate_density = NOMINAL_FISSION_RATE * power_fraction
+        
+        ! Promethium-149 dynamics
+        ! dPm/dt = γ_Pm * F - λ_Pm * Pm
+        dpm_dt = GAMMA_PM * fission_rate_density - LAMBDA_PM * promethium_in
+        promethium_out = max(0.0d0, promethium_in + dpm_dt * dt)
+        
+        ! Samarium-149 dynamics (stable, no decay term)
+        ! dSm/dt = λ_Pm * Pm - σ_Sm * φ * Sm
+        dsm_dt = LAMBDA_PM * promethium_in - SIGMA_SM * flux_scaled * samarium_in
+        samarium_out = max(0.0d0, samarium_in + dsm_dt * dt)
+        
+    end subroutine update_channel_samarium
+    
+    ! =========================================================================
+    ! Equilibrium promethium/samarium at a given power fraction
+    ! Pm_eq = γ_Pm * F / λ_Pm       (proportional to power)
+    ! Sm_eq = γ_Pm * F / (σ_Sm * φ) (independent of power level)
+    ! =========================================================================
+    subroutine samarium_equilibrium(power_fraction, promethium_eq, samarium_eq) &
+               bind(C, name="samarium_equilibrium")
+        real(c_double), intent(in), value :: power_fraction
+        real(c_double), intent(out) :: promethium_eq
+        real(c_double), intent(out) :: samarium_eq
+        
+        real(c_double), parameter :: NOMINAL_FISSION_RATE = 5.3d11
+        real(c_double), parameter :: NOMINAL_FLUX = 1.0d14
+        
+        if (power_fraction > 0.0d0) then
+            promethium_eq = GAMMA_PM * NOMINAL_FISSION_RATE * power_fraction / LAMBDA_PM
+            samarium_eq = GAMMA_PM * NOMINAL_FISSION_RATE / (SIGMA_SM * NOMINAL_FLUX)
+        else
+            promethium_eq = 0.0d0
+            samarium_eq = 0.0d0
+        end if
+        
+    end subroutine samarium_equilibrium
+    
     ! =========================================================================
     ! Initialize channel flux with cosine radial distribution
     ! =========================================================================
@@ -8,8 +8,147 @@ module rbmk_thermal
     use rbmk_constants
     implicit none
     
+    ! Current coolant pressure used for the boiling threshold [MPa]
+    ! Updated from Rust via set_coolant_pressure
+    real(c_double), save :: coolant_pressure = NOMINAL_PRESSURE
+    
+    ! Graphite-to-channel gas-gap conductance relative to nominal (1.0)
+    ! Lower values (gap degradation) raise graphite temperature and slow its response
+    ! Updated from Rust via set_graphite_gap_conductance
+    real(c_double), save :: graphite_gap_conductance = 1.0d0
+    
+    ! Effectiveness of the nitrogen-helium gas circuit that cools the graphite
+    ! stack independently of the coolant, relative to nominal (1.0)
+    ! Higher values lower graphite temperature for a given power
+    ! Updated from Rust via set_graphite_cooling_effectiveness
+    real(c_double), save :: graphite_cooling_effectiveness = 1.0d0
+    
+    ! Fuel thermal conductivity models (see set_fuel_conductivity_model)
+    integer(c_int), parameter :: FUEL_CONDUCTIVITY_CONSTANT = 0  ! Fresh-fuel conductivity at any burnup
+    integer(c_int), parameter :: FUEL_CONDUCTIVITY_BURNUP = 1    ! Degrades with burnup
+    
+    ! UO2 conductivity loss with burnup: k/k0 = 1 / (1 + c * burnup)
+    ! Fission products and lattice damage cost ~20% by 20 MWd/kgU
+    real(c_double), parameter :: FUEL_CONDUCTIVITY_BURNUP_COEFF = 0.0125d0  ! [1/(MWd/kgU)]
+    
+    ! Conductivity model used by the per-channel thermal step
+    ! Updated from Rust via set_fuel_conductivity_model
+    integer(c_int), save :: fuel_conductivity_model = FUEL_CONDUCTIVITY_CONSTANT
+    
 contains
 
+    ! =========================================================================
+    ! Saturation temperature of water at given pressure
+    ! IAPWS-IF97 region 4 backward equation Tsat(p)
+    ! Valid from the triple point (611 Pa) to the critical point (22.064 MPa)
+    ! =========================================================================
+    pure function saturation_temperature(pressure) result(t_sat)
+        real(c_double), intent(in) :: pressure      ! [MPa]
+        real(c_double) :: t_sat                     ! [K]
+        
+        real(c_double), parameter :: N1 = 0.11670521452767d4
+        real(c_double), parameter :: N2 = -0.72421316703206d6
+        real(c_double), parameter :: N3 = -0.17073846940092d2
+        real(c_double), parameter :: N4 = 0.12020824702470d5
+        real(c_double), parameter :: N5 = -0.32325550322333d7
+        real(c_double), parameter :: N6 = 0.14915108613530d2
+        real(c_double), parameter :: N7 = -0.48232657361591d4
+        real(c_double), parameter :: N8 = 0.40511340542057d6
+        real(c_double), parameter :: N9 = -0.23855557567849d0
+        real(c_double), parameter :: N10 = 0.65017534844798d3
+        
+        real(c_double) :: p, beta, e, f, g, d
+        
+        p = max(min(pressure, 22.064d0), 611.213d-6)
+        beta = p**0.25d0
+        e = beta**2 + N3 * beta + N6
+        f = N1 * beta**2 + N4 * beta + N7
+        g = N2 * beta**2 + N5 * beta + N8
+        d = 2.0d0 * g / (-f - sqrt(f**2 - 4.0d0 * e * g))
+        
+        t_sat = (N10 + d - sqrt((N10 + d)**2 - 4.0d0 * (N9 + N10 * d))) / 2.0d0
+        
+    end function saturation_temperature
+
+    ! =========================================================================
+    ! C interface for saturation temperature
+    ! =========================================================================
+    subroutine calculate_saturation_temp(pressure, t_sat) bind(C, name="calculate_saturation_temp")
+        real(c_double), intent(in), value :: pressure        ! [MPa]
+        real(c_double), intent(out) :: t_sat                 ! [K]
+        
+        t_sat = saturation_temperature(pressure)
+        
+    end subroutine calculate_saturation_temp
+
+    ! =========================================================================
+    ! Set coolant pressure used for the boiling threshold
+    ! =========================================================================
+    subroutine set_coolant_pressure(pressure) bind(C, name="set_coolant_pressure")
+        real(c_double), intent(in), value :: pressure        ! [MPa]
+        
+        coolant_pressure = pressure
+        
+    end subroutine set_coolant_pressure
+    
+    ! =========================================================================
+    ! Set graphite gas-gap conductance (relative to nominal)
+    ! =========================================================================
+    subroutine set_graphite_gap_conductance(conductance) bind(C, name="set_graphite_gap_conductance")
+        real(c_double), intent(in), value :: conductance     ! [-], 1.0 = nominal
+        
+        graphite_gap_conductance = conductance
+        
+    end subroutine set_graphite_gap_conductance
+    
+    ! =========================================================================
+    ! Set graphite gas-circuit cooling effectiveness (relative to nominal)
+    ! =========================================================================
+    subroutine set_graphite_cooling_effectiveness(effectiveness) &
+            bind(C, name="set_graphite_cooling_effectiveness")
+        real(c_double), intent(in), value :: effectiveness   ! [-], 1.0 = nominal
+        
+        graphite_cooling_effectiveness = effectiveness
+        
+    end subroutine set_graphite_cooling_effectiveness
+    
+    ! =========================================================================
+    ! Select the fuel thermal conductivity model (FUEL_CONDUCTIVITY_* code)
+    ! =========================================================================
+    subroutine set_fuel_conductivity_model(model) bind(C, name="set_fuel_conductivity_model")
+        integer(c_int), intent(in), value :: model
+        
+        fuel_conductivity_model = model
+        
+    end subroutine set_fuel_conductivity_model
+    
+    ! =========================================================================
+    ! Fuel thermal conductivity relative to fresh fuel at the given burnup
+    ! Pellet temperature rise scales with 1/conductivity, its time constant too
+    ! =========================================================================
+    pure function fuel_conductivity_factor(burnup) result(factor)
+        real(c_double), intent(in) :: burnup     ! [MWd/kgU]
+        real(c_double) :: factor
+        
+        if (fuel_conductivity_model == FUEL_CONDUCTIVITY_BURNUP) then
+            factor = 1.0d0 / (1.0d0 + FUEL_CONDUCTIVITY_BURNUP_COEFF * max(burnup, 0.0d0))
+        else
+            factor = 1.0d0
+        end if
+        
+    end function fuel_conductivity_factor
+
+    ! =========================================================================
+    ! Graphite heat removal relative to nominal (gap conductance x gas cooling)
+    ! Graphite temperature rise scales with 1/removal, its time constant too
+    ! =========================================================================
+    pure function graphite_heat_removal() result(removal)
+        real(c_double) :: removal
+        
+        removal = graphite_gap_conductance * graphite_cooling_effectiveness
+        
+    end function graphite_heat_removal
+
     ! =========================================================================
     ! Update temperatures based on power (thermal model)
     ! =========================================================================
@@ -30,7 +169,7 @@ contains
         
         real(c_double) :: power_fraction, target_coolant_temp, target_graphite_temp, target_fuel_temp
         real(c_double) :: coolant_alpha, graphite_alpha, void_alpha, fuel_alpha
-        real(c_double) :: excess_temp, target_void
+        real(c_double) :: excess_temp, target_void, t_sat
         
         power_fraction = max(min(power_percent / 100.0d0, 10.0d0), 0.0d0)
         
@@ -38,7 +177,8 @@ contains
         ! At 100% power: coolant ~550K, graphite ~650K, fuel ~900K
         ! At higher power, temperatures increase proportionally
         target_coolant_temp = 400.0d0 + 150.0d0 * power_fraction
-        target_graphite_temp = 400.0d0 + 250.0d0 * power_fraction
+        ! Graphite heat leaves through the gas gap and the gas circuit
+        target_graphite_temp = 400.0d0 + 250.0d0 * power_fraction / graphite_heat_removal()
         target_fuel_temp = 400.0d0 + 500.0d0 * power_fraction
         
         ! Coolant temperature update (fast response)
@@ -46,7 +186,7 @@ contains
         coolant_temp_new = coolant_temp + coolant_alpha * (target_coolant_temp - coolant_temp)
         
         ! Graphite temperature update (SLOW - large thermal mass)
-        graphite_alpha = min(dt / GRAPHITE_TIME_CONST, 1.0d0)
+        graphite_alpha = min(dt * graphite_heat_removal() / GRAPHITE_TIME_CONST, 1.0d0)
         graphite_temp_new = graphite_temp + graphite_alpha * (target_graphite_temp - graphite_temp)
         
         ! Fuel temperature update - FAST response at high power (critical for safety!)
@@ -67,8 +207,9 @@ contains
         ! Update void fraction (boiling model)
         void_alpha = min(dt / VOID_TIME_CONST, 1.0d0)
         
-        if (coolant_temp_new > SATURATION_TEMP) then
-            excess_temp = coolant_temp_new - SATURATION_TEMP
+        t_sat = saturation_temperature(coolant_pressure)
+        if (coolant_temp_new > t_sat) then
+            excess_temp = coolant_temp_new - t_sat
             ! More aggressive void formation - positive feedback mechanism
             target_void = min(excess_temp * 2.0d0, 80.0d0)  ! Max 80% void
             coolant_void_new = coolant_void + void_alpha * (target_void - coolant_void)
@@ -80,6 +221,34 @@ contains
         
     end subroutine update_temperatures
 
+    ! =========================================================================
+    ! Departure from nucleate boiling ratio (simplified correlation)
+    ! DNBR = q_chf / q, with the critical heat flux scaled by sqrt(flow)
+    ! and degraded by void: q_chf = CHF_NOMINAL * sqrt(G/G0) * (1 - 0.8*void)
+    ! Nominal channel (1.93 MW, 5.5 kg/s, no void) gives DNBR ~ 2.1
+    ! =========================================================================
+    subroutine calculate_dnbr(local_power, flow_rate, coolant_void, dnbr) &
+               bind(C, name="calculate_dnbr")
+        real(c_double), intent(in), value :: local_power     ! Channel thermal power [MW]
+        real(c_double), intent(in), value :: flow_rate       ! Channel coolant flow [kg/s]
+        real(c_double), intent(in), value :: coolant_void    ! Void fraction [%]
+        real(c_double), intent(out) :: dnbr                  ! [-]
+        
+        real(c_double) :: heat_flux, chf, flow_ratio, void_fraction
+        
+        heat_flux = local_power / HEATED_AREA_PER_CHANNEL
+        flow_ratio = max(flow_rate, 0.0d0) / NOMINAL_CHANNEL_FLOW
+        void_fraction = max(min(coolant_void / 100.0d0, 1.0d0), 0.0d0)
+        chf = CHF_NOMINAL * sqrt(flow_ratio) * (1.0d0 - 0.8d0 * void_fraction)
+        
+        if (heat_flux * DNBR_MAX > chf) then
+            dnbr = chf / heat_flux
+        else
+            dnbr = DNBR_MAX
+        end if
+        
+    end subroutine calculate_dnbr
+
     ! =========================================================================
     ! Calculate thermal power from neutron population
     ! =========================================================================
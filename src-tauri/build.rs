@@ -29,6 +29,12 @@ fn main() {
         println!("cargo:rerun-if-changed=../fortran/{}", src);
     }
     
+    // The pure-Rust fallback physics needs no Fortran toolchain
+    if env::var_os("CARGO_FEATURE_PURE_RUST").is_some() {
+        println!("cargo:warning=Feature `pure-rust`: skipping the Fortran DLL build");
+        return;
+    }
+    
     let dll_path = out_dir.join("rbmk_physics.dll");
     
     // Use MSYS2 shell to run gfortran (ensures proper DLL loading)
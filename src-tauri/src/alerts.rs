@@ -0,0 +1,394 @@
+//! Structured alerts and alarm annunciation
+//!
+//! Every message in `ReactorState::alerts` is raised together with a
+//! structured `Alert` (code + severity) so clients can filter and sort
+//! without parsing text. Alerts are rebuilt every step, so `AlertTracker`
+//! latches each one for a hold time after its condition clears. A client
+//! polling slower than the step rate then sees stable annunciation instead
+//! of flicker, and short transients are not missed.
+//!
+//! `alert_catalog` lists every code up front so clients can register
+//! alarms, translations and severity mappings before the first step.
+
+use std::cmp::Reverse;
+
+use serde::{Deserialize, Serialize};
+
+/// Default time an alert stays annunciated after its condition clears [s]
+pub const DEFAULT_HOLD_TIME_S: f64 = 5.0;
+
+/// Identifies the condition behind an alert
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum AlertCode {
+    PowerHigh,
+    ReactivityHigh,
+    PromptCritical,
+    FuelTempHigh,
+    VoidHigh,
+    ShortPeriod,
+    SteamExplosion,
+    ScramInitiated,
+    ChannelRupture,
+    StartupRateHigh,
+    DnbrLow,
+    CoolantLevelLow,
+    ChannelPowerClamped,
+    LocalPowerDensityHigh,
+    PowerExcursionClamped,
+    NumericalDivergence,
+    TimeSpeedHigh,
+}
+
+/// Where the condition behind an alert is detected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AlertSource {
+    Fortran, // Flag or explosion severity reported by the Fortran point-kinetics step
+    Rust,    // Check run by the simulator after a step
+}
+
+/// Alert severity (ordered: Info < Warning < Critical)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Severity {
+    Info,
+    Warning,
+    Critical,
+}
+
+impl AlertCode {
+    /// Every alert code, in declaration (annunciation) order; a test checks
+    /// it against the derived variant list, so a new code cannot be left out
+    pub const ALL: [AlertCode; 17] = [
+        AlertCode::PowerHigh,
+        AlertCode::ReactivityHigh,
+        AlertCode::PromptCritical,
+        AlertCode::FuelTempHigh,
+        AlertCode::VoidHigh,
+        AlertCode::ShortPeriod,
+        AlertCode::SteamExplosion,
+        AlertCode::ScramInitiated,
+        AlertCode::ChannelRupture,
+        AlertCode::StartupRateHigh,
+        AlertCode::DnbrLow,
+        AlertCode::CoolantLevelLow,
+        AlertCode::ChannelPowerClamped,
+        AlertCode::LocalPowerDensityHigh,
+        AlertCode::PowerExcursionClamped,
+        AlertCode::NumericalDivergence,
+        AlertCode::TimeSpeedHigh,
+    ];
+
+    /// Default severity of this alert
+    pub fn severity(self) -> Severity {
+        match self {
+            AlertCode::PromptCritical
+            | AlertCode::SteamExplosion
+            | AlertCode::ChannelRupture
+            | AlertCode::PowerExcursionClamped => Severity::Critical,
+            AlertCode::ScramInitiated => Severity::Info,
+            _ => Severity::Warning,
+        }
+    }
+
+    /// Whether this alert is suppressed in the low-power (startup) regime,
+    /// where power and period readings jitter around zero and infinity
+    pub fn suppressed_at_low_power(self) -> bool {
+        matches!(
+            self,
+            AlertCode::PowerHigh | AlertCode::ReactivityHigh | AlertCode::ShortPeriod
+        )
+    }
+
+    /// Where the condition is detected in `step()`. The spatial step runs
+    /// every check in Rust, the Fortran ones included.
+    pub fn source(self) -> AlertSource {
+        match self {
+            AlertCode::PowerHigh
+            | AlertCode::ReactivityHigh
+            | AlertCode::PromptCritical
+            | AlertCode::FuelTempHigh
+            | AlertCode::VoidHigh
+            | AlertCode::ShortPeriod
+            | AlertCode::SteamExplosion
+            | AlertCode::PowerExcursionClamped => AlertSource::Fortran,
+            AlertCode::ScramInitiated
+            | AlertCode::ChannelRupture
+            | AlertCode::StartupRateHigh
+            | AlertCode::DnbrLow
+            | AlertCode::CoolantLevelLow
+            | AlertCode::ChannelPowerClamped
+            | AlertCode::LocalPowerDensityHigh
+            | AlertCode::NumericalDivergence
+            | AlertCode::TimeSpeedHigh => AlertSource::Rust,
+        }
+    }
+
+    /// Message raised with this alert; `{name}` marks a value filled in at runtime
+    pub fn message_template(self) -> &'static str {
+        match self {
+            AlertCode::PowerHigh => "WARNING: Power exceeds 110% nominal!",
+            AlertCode::ReactivityHigh => "WARNING: Reactivity exceeds 0.5$!",
+            AlertCode::PromptCritical => "CRITICAL: Prompt critical condition!",
+            AlertCode::FuelTempHigh => "WARNING: Fuel temperature exceeds limit!",
+            AlertCode::VoidHigh => "WARNING: High void fraction - positive reactivity feedback!",
+            AlertCode::ShortPeriod => "WARNING: Short reactor period: {period_s}s",
+            AlertCode::SteamExplosion => "*** STEAM EXPLOSION - CORE DESTRUCTION ***",
+            AlertCode::ScramInitiated => "SCRAM INITIATED!",
+            AlertCode::ChannelRupture => "CRITICAL: Pressure tube rupture in {count} channel(s)!",
+            AlertCode::StartupRateHigh => "WARNING: High startup rate: {rate_dpm} DPM",
+            AlertCode::DnbrLow => "WARNING: Minimum DNBR {dnbr} in channel {channel_id} - approaching boiling crisis!",
+            AlertCode::CoolantLevelLow => "WARNING: Low coolant inventory: {level_percent}%",
+            AlertCode::ChannelPowerClamped => {
+                "WARNING: Power clamped at {ceiling_mw} MW in {count} channel(s) - spatial solver unstable?"
+            }
+            AlertCode::LocalPowerDensityHigh => {
+                "WARNING: Local power density {density_mw_m3} MW/m³ in channel {channel_id} at ({grid_x}, {grid_y}) - {count} channel(s) over limit"
+            }
+            AlertCode::PowerExcursionClamped => {
+                "CRITICAL: Power excursion exceeds the solver step bound - growth clamped!"
+            }
+            AlertCode::NumericalDivergence => {
+                "WARNING: Power oscillation is numerical - reduce the time step to {dt_s} s"
+            }
+            AlertCode::TimeSpeedHigh => {
+                "WARNING: Time speed {speed}x exceeds the safe {max_speed}x - physics fidelity reduced"
+            }
+        }
+    }
+}
+
+/// Static description of one alert code, for registering alarms up front
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlertDefinition {
+    pub code: AlertCode,
+    pub severity: Severity,                 // Default severity
+    pub source: AlertSource,
+    pub message_template: String,           // `{name}` placeholders are filled in at runtime
+    pub suppressed_at_low_power: bool,
+}
+
+/// Every alert the simulator can raise
+pub fn alert_catalog() -> Vec<AlertDefinition> {
+    AlertCode::ALL
+        .iter()
+        .map(|&code| AlertDefinition {
+            code,
+            severity: code.severity(),
+            source: code.source(),
+            message_template: code.message_template().to_string(),
+            suppressed_at_low_power: code.suppressed_at_low_power(),
+        })
+        .collect()
+}
+
+/// An alert raised during the current step
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Alert {
+    pub code: AlertCode,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Alert {
+    pub fn new(code: AlertCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            severity: code.severity(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Order alerts for annunciation: most severe first, then by code
+pub fn sort_by_priority(alerts: &mut [Alert]) {
+    alerts.sort_by_key(|a| (Reverse(a.severity), a.code));
+}
+
+/// An alert held by the annunciator
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActiveAlert {
+    pub code: AlertCode,
+    pub severity: Severity,
+    pub message: String,      // Most recent message for this code
+    pub first_seen: f64,      // Simulation time the alert was first raised [s]
+    pub last_seen: f64,       // Simulation time the condition was last present [s]
+    pub condition_active: bool, // false = condition cleared, held until hold time expires
+}
+
+/// Latches alerts across steps for a configurable hold time
+#[derive(Debug, Clone)]
+pub struct AlertTracker {
+    hold_time: f64,
+    active: Vec<ActiveAlert>,
+    last_raised: Option<f64>, // Time an alert not already held last appeared [s]
+}
+
+impl Default for AlertTracker {
+    fn default() -> Self {
+        Self::new(DEFAULT_HOLD_TIME_S)
+    }
+}
+
+impl AlertTracker {
+    pub fn new(hold_time: f64) -> Self {
+        Self {
+            hold_time,
+            active: Vec::new(),
+            last_raised: None,
+        }
+    }
+
+    pub fn hold_time(&self) -> f64 {
+        self.hold_time
+    }
+
+    pub fn set_hold_time(&mut self, hold_time: f64) {
+        self.hold_time = hold_time.max(0.0);
+    }
+
+    /// Drop all held alerts (on reset)
+    pub fn clear(&mut self) {
+        self.active.clear();
+        self.last_raised = None;
+    }
+
+    /// Record the alerts present at simulation time `time`
+    ///
+    /// Present alerts are refreshed (or added), absent ones are marked
+    /// cleared and dropped once `hold_time` has passed since last seen.
+    pub fn update(&mut self, time: f64, alerts: &[Alert]) {
+        for entry in self.active.iter_mut() {
+            entry.condition_active = false;
+        }
+
+        for alert in alerts {
+            match self.active.iter_mut().find(|a| a.code == alert.code) {
+                Some(entry) => {
+                    entry.message = alert.message.clone();
+                    entry.severity = alert.severity;
+                    entry.last_seen = time;
+                    entry.condition_active = true;
+                }
+                None => {
+                    self.active.push(ActiveAlert {
+                        code: alert.code,
+                        severity: alert.severity,
+                        message: alert.message.clone(),
+                        first_seen: time,
+                        last_seen: time,
+                        condition_active: true,
+                    });
+                    self.last_raised = Some(time);
+                }
+            }
+        }
+
+        let hold_time = self.hold_time;
+        self.active
+            .retain(|a| a.condition_active || time - a.last_seen <= hold_time);
+    }
+
+    /// Simulation time a new alert was last raised (None since the last clear)
+    pub fn last_raised(&self) -> Option<f64> {
+        self.last_raised
+    }
+
+    /// Currently held alerts, most severe first, then by code
+    pub fn active(&self) -> Vec<ActiveAlert> {
+        let mut active = self.active.clone();
+        active.sort_by_key(|a| (Reverse(a.severity), a.code));
+        active
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alert_held_after_condition_clears() {
+        let mut tracker = AlertTracker::new(1.0);
+        let alert = Alert::new(AlertCode::PowerHigh, "WARNING: Power exceeds 110% nominal!");
+
+        tracker.update(0.0, std::slice::from_ref(&alert));
+        tracker.update(0.5, std::slice::from_ref(&alert));
+        tracker.update(1.0, &[]);
+
+        let held = tracker.active();
+        assert_eq!(held.len(), 1);
+        assert_eq!(held[0].first_seen, 0.0);
+        assert_eq!(held[0].last_seen, 0.5);
+        assert!(!held[0].condition_active);
+
+        tracker.update(1.6, &[]);
+        assert!(tracker.active().is_empty());
+        assert_eq!(tracker.last_raised(), Some(0.0));
+    }
+
+    #[test]
+    fn test_alerts_sorted_by_severity_then_code() {
+        let mut alerts = vec![
+            Alert::new(AlertCode::VoidHigh, "void"),
+            Alert::new(AlertCode::ScramInitiated, "scram"),
+            Alert::new(AlertCode::PromptCritical, "prompt"),
+            Alert::new(AlertCode::PowerHigh, "power"),
+        ];
+        sort_by_priority(&mut alerts);
+        let codes: Vec<AlertCode> = alerts.iter().map(|a| a.code).collect();
+        assert_eq!(
+            codes,
+            [AlertCode::PromptCritical, AlertCode::PowerHigh, AlertCode::VoidHigh, AlertCode::ScramInitiated]
+        );
+    }
+
+    /// Variant names of a derived `Deserialize` enum, in declaration order
+    fn variant_names<'de, T: Deserialize<'de>>() -> &'static [&'static str] {
+        use serde::de::{self, value, Visitor};
+        
+        struct Names(&'static [&'static str]);
+        
+        impl<'de> de::Deserializer<'de> for &mut Names {
+            type Error = value::Error;
+            
+            fn deserialize_enum<V: Visitor<'de>>(
+                self,
+                _name: &'static str,
+                variants: &'static [&'static str],
+                _visitor: V,
+            ) -> Result<V::Value, Self::Error> {
+                self.0 = variants;
+                Err(de::Error::custom("variant names only"))
+            }
+            
+            fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+                Err(de::Error::custom("not an enum"))
+            }
+            
+            serde::forward_to_deserialize_any! {
+                bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string bytes byte_buf option
+                unit unit_struct newtype_struct seq tuple tuple_struct map struct identifier ignored_any
+            }
+        }
+        
+        let mut names = Names(&[]);
+        let _ = T::deserialize(&mut names);
+        names.0
+    }
+
+    #[test]
+    fn test_all_lists_every_code() {
+        // Taken from the derive, so a code missing from ALL fails here
+        let declared = variant_names::<AlertCode>();
+        let listed: Vec<String> = AlertCode::ALL.iter()
+            .map(|code| serde_json::to_value(code).unwrap().as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(listed, declared);
+    }
+
+    #[test]
+    fn test_catalog_lists_each_code_once() {
+        let catalog = alert_catalog();
+        assert_eq!(catalog.len(), AlertCode::ALL.len());
+        // Declaration order is the derived `Ord`, so sorted means no duplicates
+        assert!(catalog.windows(2).all(|w| w[0].code < w[1].code));
+    }
+}
@@ -0,0 +1,274 @@
+//! Core physics backend
+//!
+//! `ReactorSimulator` runs its core-level model (point kinetics, lumped
+//! thermal-hydraulics, xenon, axial flux, rod worth) through a
+//! `PhysicsBackend`. `FortranBackend` forwards to the Fortran library and is
+//! the default; tests and embedding applications can inject their own via
+//! `ReactorSimulatorBuilder::backend`. Without the library the simulator
+//! falls back to `PureRustBackend` (see `default_backend`).
+//!
+//! The 2D spatial solver used by `step_spatial` always runs in Fortran.
+
+use serde::{Deserialize, Serialize};
+
+use crate::fortran_ffi::{self, ExplosionTracker, SimulationStepResult, NUM_DELAYED_GROUPS};
+use crate::pure_rust_backend::PureRustBackend;
+
+/// Integration scheme for the point kinetics equations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum KineticsSolver {
+    Euler,      // 1-group explicit Euler (unstable at large dt)
+    Rk4,        // 1-group 4th-order Runge-Kutta
+    PromptJump, // 1-group prompt jump (Λ·dn/dt neglected; falls back to RK4 above prompt critical)
+    #[default]
+    SixGroup, // 6-group RK4
+}
+
+/// Core-level state passed to one point-kinetics step
+#[derive(Debug, Clone)]
+pub struct PointKineticsInput {
+    pub dt: f64,                  // Time step [s]
+    pub neutron_population: f64,  // Normalized to nominal power
+    pub precursors: f64,          // Total delayed neutron precursors
+    pub fuel_temp: f64,           // [K]
+    pub coolant_temp: f64,        // [K]
+    pub graphite_temp: f64,       // [K]
+    pub coolant_void: f64,        // [%]
+    pub iodine_135: f64,          // [atoms/cm³]
+    pub xenon_135: f64,           // [atoms/cm³]
+    pub total_rod_worth: f64,     // Inserted control rod worth [Δk/k]
+    pub smoothed_reactivity: f64, // Reactivity of the previous step [Δk/k]
+    pub scram_active: bool,
+    pub kinetics_solver: KineticsSolver,
+}
+
+/// Physics model behind `ReactorSimulator::step`
+pub trait PhysicsBackend: Send + Sync {
+    /// Short name for logs and diagnostics
+    fn name(&self) -> &str;
+
+    /// Advance the core-level model by one time step
+    fn simulation_step(&self, input: &PointKineticsInput) -> SimulationStepResult;
+
+    /// Reactivity after one smoothing step for `input`, without advancing
+    /// the model (used when a SCRAM starts between steps) [Δk/k]
+    fn total_reactivity(&self, input: &PointKineticsInput) -> f64;
+
+    /// 6-group precursor concentrations after the last `simulation_step`
+    fn precursors_6group(&self) -> [f64; NUM_DELAYED_GROUPS];
+
+    /// Continue the next `simulation_step` from saved precursor concentrations
+    fn restore_precursors_6group(&self, precursors_6: &[f64; NUM_DELAYED_GROUPS]);
+
+    /// Kinetics sub-steps taken by the last `simulation_step` (1 = none)
+    fn last_substep_count(&self) -> usize;
+
+    /// Steady-state reactivity the kinetics relax toward [Δk/k]
+    fn target_reactivity(&self, fuel_temp: f64, graphite_temp: f64, coolant_void: f64, xenon_135: f64, rod_worth: f64) -> f64;
+
+    /// Core-average Xe-135 reactivity [Δk/k]
+    fn xenon_reactivity(&self, xenon_135: f64) -> f64;
+
+    /// Core-average I-135 and Xe-135 [atoms/cm³] after `dt` at a thermal flux [n/cm²/s]
+    fn xenon_step(&self, iodine_135: f64, xenon_135: f64, flux: f64, dt: f64) -> (f64, f64);
+
+    /// Lumped fuel, coolant, graphite temperatures [K] and void [%] after
+    /// `dt` at a power [% nominal], without advancing the kinetics
+    fn update_temperatures(
+        &self,
+        power_percent: f64,
+        fuel_temp: f64,
+        coolant_temp: f64,
+        graphite_temp: f64,
+        coolant_void: f64,
+        dt: f64,
+    ) -> (f64, f64, f64, f64);
+
+    /// β_eff and the prompt neutron lifetime Λ [s] the kinetics use
+    fn kinetics_constants(&self) -> (f64, f64);
+
+    /// Whether the kinetics read the tuning `ReactorSimulator` sends to the
+    /// Fortran modules (neutron lifetime, graphite gap conductance and gas
+    /// cooling, reactivity smoothing time constant)
+    fn applies_fortran_tuning(&self) -> bool;
+
+    /// Reactivity that sustains a stable period, from the inhour equation [Δk/k]
+    fn reactivity_from_period(&self, period: f64) -> f64;
+
+    /// Normalized axial flux profile with a top/bottom tilt in [-1, 1]
+    fn axial_flux(&self, n_points: usize, neutron_population: f64, tilt: f64) -> Vec<f64>;
+
+    /// Inserted worth of a rod at `rod_position` (0 = inserted, 1 = withdrawn) [Δk/k]
+    /// `shape` is the worth curve exponent of the rod type (1.0 = symmetric S-curve).
+    fn rod_worth(&self, rod_position: f64, max_worth: f64, shape: f64) -> f64;
+
+    /// Set the coolant pressure that determines the boiling threshold [MPa]
+    fn set_coolant_pressure(&self, pressure_mpa: f64);
+
+    /// Saturation temperature at a coolant pressure [K]
+    fn saturation_temp(&self, pressure_mpa: f64) -> f64;
+
+    /// Explosion tracking history kept between steps, for checkpoints
+    /// (None when the backend cannot report it)
+    fn explosion_tracker(&self) -> Option<ExplosionTracker>;
+
+    /// Continue explosion tracking from a saved history
+    fn restore_explosion_tracker(&self, tracker: &ExplosionTracker);
+
+    /// Clear history kept between steps (explosion tracking, precursor groups)
+    /// Never panics; returns the resets that could not run (empty when all did).
+    fn reset(&self) -> Vec<String>;
+}
+
+/// Backend used when none is given to the builder: Fortran if the library
+/// loads, otherwise the pure-Rust fallback (always with feature `pure-rust`)
+pub fn default_backend() -> Box<dyn PhysicsBackend> {
+    if cfg!(feature = "pure-rust") {
+        return Box::new(PureRustBackend::default());
+    }
+    if fortran_ffi::library_available() {
+        return Box::new(FortranBackend);
+    }
+    eprintln!(
+        "[backend] Warning: Fortran physics library not found, using the reduced-fidelity \
+         pure-Rust fallback (spatial steps fall back to point kinetics)"
+    );
+    Box::new(PureRustBackend::default())
+}
+
+/// Default backend: the Fortran physics library
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FortranBackend;
+
+impl PhysicsBackend for FortranBackend {
+    fn name(&self) -> &str {
+        "fortran"
+    }
+
+    fn simulation_step(&self, input: &PointKineticsInput) -> SimulationStepResult {
+        fortran_ffi::set_kinetics_solver(match input.kinetics_solver {
+            KineticsSolver::Euler => fortran_ffi::KINETICS_SOLVER_EULER,
+            KineticsSolver::Rk4 => fortran_ffi::KINETICS_SOLVER_RK4,
+            KineticsSolver::PromptJump => fortran_ffi::KINETICS_SOLVER_PROMPT_JUMP,
+            KineticsSolver::SixGroup => fortran_ffi::KINETICS_SOLVER_SIX_GROUP,
+        });
+        fortran_ffi::simulation_step(
+            input.dt,
+            input.neutron_population,
+            input.precursors,
+            input.fuel_temp,
+            input.coolant_temp,
+            input.graphite_temp,
+            input.coolant_void,
+            input.iodine_135,
+            input.xenon_135,
+            input.total_rod_worth,
+            input.smoothed_reactivity,
+            input.scram_active,
+        )
+    }
+
+    fn total_reactivity(&self, input: &PointKineticsInput) -> f64 {
+        fortran_ffi::calc_total_reactivity(
+            input.fuel_temp,
+            input.graphite_temp,
+            input.coolant_void,
+            input.xenon_135,
+            input.total_rod_worth,
+            input.smoothed_reactivity,
+            input.dt,
+            input.scram_active,
+        )
+    }
+
+    fn precursors_6group(&self) -> [f64; NUM_DELAYED_GROUPS] {
+        fortran_ffi::get_precursors_6group()
+    }
+
+    fn restore_precursors_6group(&self, precursors_6: &[f64; NUM_DELAYED_GROUPS]) {
+        fortran_ffi::set_precursors_6group_state(precursors_6);
+    }
+
+    fn last_substep_count(&self) -> usize {
+        fortran_ffi::get_last_substep_count().max(1) as usize
+    }
+
+    fn target_reactivity(&self, fuel_temp: f64, graphite_temp: f64, coolant_void: f64, xenon_135: f64, rod_worth: f64) -> f64 {
+        fortran_ffi::calc_target_reactivity(fuel_temp, graphite_temp, coolant_void, xenon_135, rod_worth)
+    }
+
+    fn xenon_reactivity(&self, xenon_135: f64) -> f64 {
+        fortran_ffi::calc_xenon_reactivity(xenon_135)
+    }
+
+    fn xenon_step(&self, iodine_135: f64, xenon_135: f64, flux: f64, dt: f64) -> (f64, f64) {
+        fortran_ffi::calc_xenon(iodine_135, xenon_135, flux, dt)
+    }
+
+    fn update_temperatures(
+        &self,
+        power_percent: f64,
+        fuel_temp: f64,
+        coolant_temp: f64,
+        graphite_temp: f64,
+        coolant_void: f64,
+        dt: f64,
+    ) -> (f64, f64, f64, f64) {
+        fortran_ffi::update_temperatures(power_percent, fuel_temp, coolant_temp, graphite_temp, coolant_void, dt)
+    }
+
+    fn kinetics_constants(&self) -> (f64, f64) {
+        let (beta_eff, neutron_lifetime, _) = fortran_ffi::get_constants();
+        (beta_eff, neutron_lifetime)
+    }
+
+    fn applies_fortran_tuning(&self) -> bool {
+        fortran_ffi::library_available()
+    }
+
+    fn reactivity_from_period(&self, period: f64) -> f64 {
+        fortran_ffi::reactivity_from_period(period)
+    }
+
+    fn axial_flux(&self, n_points: usize, neutron_population: f64, tilt: f64) -> Vec<f64> {
+        fortran_ffi::update_axial_flux_tilted(n_points, neutron_population, tilt)
+    }
+
+    fn rod_worth(&self, rod_position: f64, max_worth: f64, shape: f64) -> f64 {
+        fortran_ffi::calc_rod_worth(rod_position, max_worth, shape)
+    }
+
+    fn set_coolant_pressure(&self, pressure_mpa: f64) {
+        fortran_ffi::set_coolant_pressure(pressure_mpa);
+    }
+
+    fn saturation_temp(&self, pressure_mpa: f64) -> f64 {
+        fortran_ffi::saturation_temp(pressure_mpa)
+    }
+
+    fn explosion_tracker(&self) -> Option<ExplosionTracker> {
+        fortran_ffi::get_explosion_state()
+            .map_err(|error| eprintln!("[backend] Warning: explosion tracking not saved ({})", error))
+            .ok()
+    }
+
+    fn restore_explosion_tracker(&self, tracker: &ExplosionTracker) {
+        if let Err(error) = fortran_ffi::set_explosion_state(tracker) {
+            eprintln!("[backend] Warning: explosion tracking not restored ({})", error);
+        }
+    }
+
+    fn reset(&self) -> Vec<String> {
+        let results = [
+            ("reset_explosion_state", fortran_ffi::reset_explosion_state()),
+            ("reset_precursors_6group_state", fortran_ffi::reset_precursors_6group_state()),
+        ];
+        results.into_iter()
+            .filter_map(|(name, result)| {
+                let error = result.err()?;
+                eprintln!("[backend] Warning: skipped {} ({})", name, error);
+                Some(name.to_string())
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,196 @@
+//! "Black box" snapshots for accident investigation
+//!
+//! One call captures everything an investigator needs to reconstruct the
+//! moment: the full state, rod positions, the hottest channels, the
+//! annunciator, where the reactivity comes from, the operational reactivity
+//! margin and the reactivity trace of the last minute.
+//!
+//! Snapshots leave the simulator wrapped in a `{ version, payload }` envelope
+//! so a reader can tell a snapshot written by an older release apart from a
+//! corrupt one.
+
+use std::collections::VecDeque;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::alerts::ActiveAlert;
+use crate::reactor::{ControlRod, FuelChannel, ReactorState, SimulationMetadata};
+
+/// Length of the reactivity trace kept for snapshots [s of simulation time]
+pub const REACTIVITY_TRACE_SECONDS: f64 = 60.0;
+
+/// Hard cap on trace samples (guards against very small time steps)
+pub const REACTIVITY_TRACE_MAX_SAMPLES: usize = 60_000;
+
+/// Hottest channels (by fuel temperature) included in a snapshot
+pub const BLACKBOX_HOTTEST_CHANNELS: usize = 10;
+
+/// Format version of serialized snapshots
+/// Bump whenever `BlackboxSnapshot` or anything it contains changes shape,
+/// and teach `migrate_snapshot` to bring the previous version forward.
+pub const SNAPSHOT_FORMAT_VERSION: u32 = 1;
+
+/// One point of the reactivity trace
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReactivitySample {
+    pub time: f64,       // Simulation time [s]
+    pub reactivity: f64, // [Δk/k]
+}
+
+/// Reactivity of the last `REACTIVITY_TRACE_SECONDS`, oldest first
+#[derive(Debug, Clone, Default)]
+pub struct ReactivityTrace {
+    samples: VecDeque<ReactivitySample>,
+}
+
+impl ReactivityTrace {
+    pub fn record(&mut self, time: f64, reactivity: f64) {
+        // Time went backwards (reset, restart): start over
+        if self.samples.back().is_some_and(|last| time < last.time) {
+            self.samples.clear();
+        }
+        self.samples.push_back(ReactivitySample { time, reactivity });
+        while self.samples.len() > REACTIVITY_TRACE_MAX_SAMPLES
+            || self.samples.front().is_some_and(|first| time - first.time > REACTIVITY_TRACE_SECONDS)
+        {
+            self.samples.pop_front();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    pub fn samples(&self) -> Vec<ReactivitySample> {
+        self.samples.iter().cloned().collect()
+    }
+}
+
+/// Steady-state reactivity split by source [Δk/k]
+/// Temperature and void terms are relative to the cold, voidless core.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReactivityBreakdown {
+    pub base: f64,                 // Cold clean core, all rods withdrawn
+    pub fuel_temperature: f64,     // Doppler
+    pub graphite_temperature: f64,
+    pub void: f64,
+    pub xenon: f64,
+    pub rods: f64,                 // Inserted control rods (negative)
+    pub total: f64,                // Value the kinetics relax toward
+}
+
+impl ReactivityBreakdown {
+    /// Sum of the components, without `total`
+    pub fn component_sum(&self) -> f64 {
+        self.base + self.fuel_temperature + self.graphite_temperature + self.void + self.xenon + self.rods
+    }
+}
+
+/// Everything important at one instant
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlackboxSnapshot {
+    pub captured_at_unix_ms: u64, // Wall-clock capture time
+    pub simulation_time: f64,     // [s]
+    pub state: ReactorState,
+    pub control_rods: Vec<ControlRod>,
+    pub hottest_channels: Vec<FuelChannel>, // Hottest fuel first
+    pub active_alerts: Vec<ActiveAlert>,
+    pub reactivity_breakdown: ReactivityBreakdown,
+    pub orm: f64, // Operational reactivity margin [equivalent manual rods]
+    pub reactivity_trace: Vec<ReactivitySample>,
+    pub metadata: SimulationMetadata, // Which scenario this snapshot belongs to
+    pub config_hash: String,          // Geometry hash in hex (a u64 does not survive JSON numbers)
+}
+
+/// Serialized form of a snapshot: the payload tagged with its format version
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotEnvelope<T> {
+    pub version: u32,
+    pub payload: T,
+}
+
+/// Why a serialized snapshot could not be read
+#[derive(Debug, Clone, PartialEq)]
+pub enum SnapshotError {
+    /// Written by a format this build cannot migrate from
+    VersionMismatch { found: u32, expected: u32 },
+    /// Not a snapshot envelope, or the payload does not match the format
+    Malformed(String),
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::VersionMismatch { found, expected } => write!(
+                f,
+                "Snapshot format version {} is not supported (expected {})",
+                found, expected
+            ),
+            SnapshotError::Malformed(reason) => write!(f, "Malformed snapshot: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+/// Serialize a snapshot inside a versioned envelope
+pub fn encode_snapshot(snapshot: &BlackboxSnapshot) -> Result<String, SnapshotError> {
+    let envelope = SnapshotEnvelope { version: SNAPSHOT_FORMAT_VERSION, payload: snapshot };
+    serde_json::to_string(&envelope).map_err(|e| SnapshotError::Malformed(e.to_string()))
+}
+
+/// Read a snapshot written by `encode_snapshot`, migrating older formats
+pub fn decode_snapshot(json: &str) -> Result<BlackboxSnapshot, SnapshotError> {
+    let envelope: SnapshotEnvelope<Value> =
+        serde_json::from_str(json).map_err(|e| SnapshotError::Malformed(e.to_string()))?;
+    let payload = migrate_snapshot(envelope.version, envelope.payload)?;
+    serde_json::from_value(payload).map_err(|e| SnapshotError::Malformed(e.to_string()))
+}
+
+/// Bring a payload of format `version` up to `SNAPSHOT_FORMAT_VERSION`
+/// Each format change adds a step here rewriting version N into N + 1.
+fn migrate_snapshot(version: u32, payload: Value) -> Result<Value, SnapshotError> {
+    match version {
+        SNAPSHOT_FORMAT_VERSION => Ok(payload),
+        found => Err(SnapshotError::VersionMismatch { found, expected: SNAPSHOT_FORMAT_VERSION }),
+    }
+}
+
+/// The `count` channels with the highest fuel temperature, hottest first
+pub fn hottest_channels(channels: &[FuelChannel], count: usize) -> Vec<FuelChannel> {
+    let mut sorted: Vec<&FuelChannel> = channels.iter().collect();
+    sorted.sort_by(|a, b| b.fuel_temp.total_cmp(&a.fuel_temp));
+    sorted.into_iter().take(count).cloned().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trace_keeps_window() {
+        let mut trace = ReactivityTrace::default();
+        for i in 0..=100 {
+            trace.record(i as f64, i as f64 * 1e-5);
+        }
+        let samples = trace.samples();
+        assert_eq!(samples.first().unwrap().time, 40.0);
+        assert_eq!(samples.last().unwrap().time, 100.0);
+
+        // Restart from t = 0 drops the old trace
+        trace.record(0.0, 0.0);
+        assert_eq!(trace.samples().len(), 1);
+    }
+
+    #[test]
+    fn test_decode_rejects_other_versions() {
+        let json = serde_json::json!({ "version": SNAPSHOT_FORMAT_VERSION + 1, "payload": {} }).to_string();
+        assert_eq!(
+            decode_snapshot(&json).unwrap_err(),
+            SnapshotError::VersionMismatch { found: SNAPSHOT_FORMAT_VERSION + 1, expected: SNAPSHOT_FORMAT_VERSION }
+        );
+        assert!(matches!(decode_snapshot("{}"), Err(SnapshotError::Malformed(_))));
+    }
+}
@@ -0,0 +1,130 @@
+//! Rolling in-memory checkpoints for rewinding a run
+//!
+//! Once per `CHECKPOINT_INTERVAL_S` of simulation time the simulator keeps a
+//! full copy of the state, the control rods, the fuel channels and the
+//! history the next steps depend on (LAR zones, event times, armed faults,
+//! explosion tracking). The last few are held in a ring so an instructor can
+//! step back a few seconds after a mistake without reloading a snapshot file.
+
+use std::collections::{HashMap, VecDeque};
+
+use crate::faults::FaultInjector;
+use crate::fortran_ffi::ExplosionTracker;
+use crate::reactor::{ActiveDetectorFault, ControlRod, EventTimes, FuelChannel, LarZone, ReactorState};
+
+/// Simulation time between checkpoints [s]
+pub const CHECKPOINT_INTERVAL_S: f64 = 1.0;
+
+/// Checkpoints kept by default (~10 s of rewind)
+pub const CHECKPOINT_DEFAULT_COUNT: usize = 10;
+
+/// Most checkpoints that can be kept (each holds a full copy of the channels)
+pub const CHECKPOINT_MAX_COUNT: usize = 600;
+
+/// Everything needed to continue a run from one instant
+#[derive(Debug, Clone, Default)]
+pub struct Checkpoint {
+    pub state: ReactorState,
+    pub control_rods: Vec<ControlRod>,
+    pub fuel_channels: Vec<FuelChannel>,
+    pub lar_zones: Vec<LarZone>,
+    pub event_times: EventTimes,
+    pub fault_injector: FaultInjector,
+    pub detector_faults: HashMap<usize, ActiveDetectorFault>,
+    pub explosion_tracker: Option<ExplosionTracker>, // None if the backend could not report it
+}
+
+/// Ring of the most recent checkpoints, oldest first
+#[derive(Debug, Clone)]
+pub struct CheckpointBuffer {
+    checkpoints: VecDeque<Checkpoint>,
+    capacity: usize,
+}
+
+impl Default for CheckpointBuffer {
+    fn default() -> Self {
+        Self { checkpoints: VecDeque::new(), capacity: CHECKPOINT_DEFAULT_COUNT }
+    }
+}
+
+impl CheckpointBuffer {
+    /// Whether a checkpoint should be taken at `time`
+    /// Time going backwards (reset, restart) starts the ring over.
+    pub fn due(&mut self, time: f64) -> bool {
+        match self.checkpoints.back() {
+            Some(last) if time < last.state.time => {
+                self.checkpoints.clear();
+                true
+            }
+            Some(last) => time - last.state.time >= CHECKPOINT_INTERVAL_S,
+            None => true,
+        }
+    }
+
+    pub fn push(&mut self, checkpoint: Checkpoint) {
+        while self.checkpoints.len() >= self.capacity {
+            self.checkpoints.pop_front();
+        }
+        self.checkpoints.push_back(checkpoint);
+    }
+
+    /// Latest checkpoint taken at or before `time`; later ones are dropped
+    /// so the run continues from it as if they never happened
+    pub fn rewind_to(&mut self, time: f64) -> Option<Checkpoint> {
+        let index = self.checkpoints.iter().rposition(|c| c.state.time <= time)?;
+        self.checkpoints.truncate(index + 1);
+        self.checkpoints.back().cloned()
+    }
+
+    pub fn set_capacity(&mut self, capacity: usize) -> Result<(), String> {
+        if !(1..=CHECKPOINT_MAX_COUNT).contains(&capacity) {
+            return Err(format!("Checkpoint count {} out of range [1, {}]", capacity, CHECKPOINT_MAX_COUNT));
+        }
+        self.capacity = capacity;
+        while self.checkpoints.len() > capacity {
+            self.checkpoints.pop_front();
+        }
+        Ok(())
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Simulation times of the kept checkpoints, oldest first [s]
+    pub fn times(&self) -> Vec<f64> {
+        self.checkpoints.iter().map(|c| c.state.time).collect()
+    }
+
+    pub fn clear(&mut self) {
+        self.checkpoints.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkpoint(time: f64) -> Checkpoint {
+        Checkpoint {
+            state: ReactorState { time, ..ReactorState::default() },
+            ..Checkpoint::default()
+        }
+    }
+
+    #[test]
+    fn test_rewind_picks_checkpoint_at_or_before() {
+        let mut buffer = CheckpointBuffer::default();
+        for i in 0..15 {
+            let time = i as f64;
+            if buffer.due(time) {
+                buffer.push(checkpoint(time));
+            }
+        }
+        assert_eq!(buffer.times().len(), CHECKPOINT_DEFAULT_COUNT);
+
+        assert_eq!(buffer.rewind_to(11.5).unwrap().state.time, 11.0);
+        assert_eq!(buffer.times().last(), Some(&11.0));
+        assert!(buffer.rewind_to(2.0).is_none());
+    }
+}
@@ -4,9 +4,19 @@
 
 use serde::{Deserialize, Serialize};
 use tauri::State;
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::reactor::{ReactorSimulator, ReactorState, ControlRod, FuelChannel, RodType, AutoRegulatorSettings};
+use crate::alerts::{ActiveAlert, AlertDefinition};
+use crate::faults::{FaultDrillStatus, FaultKind};
+use crate::backend::KineticsSolver;
+use crate::blackbox::{self, BlackboxSnapshot};
+use crate::delta_stream::VersionedDelta;
+use crate::history::{ChannelSample, KeffSample};
+use crate::stability::StabilityStatus;
+use crate::startup::InverseMCurve;
+use crate::timing::StepTiming;
+use crate::reactor::{constants, ReactorSimulator, ReactorState, ControlRod, FuelChannel, RodType, AutoRegulatorSettings, StateDelta, ScramReadiness, RodWorthReport, RodWorthSummary, PhysicsConstants, GridCell, SimulationMetadata, SimulationParameters, TripProjection, XenonProjection, Trip, TripStatus, AxialPowerOffset, RingStat, BurnupStats, MinDnbr, SubcoolingMargin, ChannelFilter, ChannelSummary, ReactivityUnit, Criticality, Outcome, EventTimes, OperatingPoint, ClampedSetpoint, LayoutWarning, DetectorSignal, DetectorFault, LarZone, RodTravelLimits, EffectiveTimeStep, RodDepletion, RodWorthPoint, PowerBalance, Interlock, ControlManifest, FrameData, FuelConductivityModel};
 
 /// Simulation state wrapper for Tauri
 pub struct SimulatorState(pub Arc<ReactorSimulator>);
@@ -18,6 +28,13 @@ pub struct SimulationResponse {
     pub control_rods: Vec<ControlRod>,
 }
 
+/// Response for a new regulator power setpoint
+#[derive(Serialize, Deserialize)]
+pub struct TargetPowerResponse {
+    pub auto_regulator: AutoRegulatorSettings,
+    pub target: ClampedSetpoint, // Requested vs applied target and the allowed range
+}
+
 /// Request to move control rods
 #[derive(Serialize, Deserialize)]
 pub struct MoveRodRequest {
@@ -33,6 +50,7 @@ pub fn get_reactor_state(simulator: State<SimulatorState>) -> ReactorState {
 }
 
 /// Perform one simulation step using 2D spatial physics
+/// (point kinetics when the spatial solver is unavailable)
 #[tauri::command]
 pub fn simulation_step(simulator: State<SimulatorState>) -> SimulationResponse {
     simulator.0.step_spatial();
@@ -55,6 +73,20 @@ pub fn simulation_run(simulator: State<SimulatorState>, steps: usize) -> Simulat
     }
 }
 
+/// Perform one simulation step and return the signed change of key values
+/// Intended for physics debugging and rate-of-change readouts
+#[tauri::command]
+pub fn simulation_step_diff(simulator: State<SimulatorState>) -> StateDelta {
+    simulator.0.step_and_diff()
+}
+
+/// State and channel fields changed since a version (0 = everything)
+/// For remote clients that stream the state every frame
+#[tauri::command(rename_all = "camelCase")]
+pub fn get_state_delta(simulator: State<SimulatorState>, since_version: u64) -> VersionedDelta {
+    simulator.0.get_state_delta(since_version)
+}
+
 /// Accumulated simulation time for fractional steps
 static ACCUMULATED_SIM_TIME: std::sync::Mutex<f64> = std::sync::Mutex::new(0.0);
 
@@ -90,19 +122,28 @@ pub fn simulation_realtime(
     *accumulated -= (steps_to_run as f64) * dt;
     
     // Clamp to reasonable range
-    let steps_to_run = steps_to_run.min(1000);
+    let steps_to_run = steps_to_run.min(constants::REALTIME_MAX_STEPS_PER_CALL);
     
     // Run the physics steps using 2D spatial physics
     for _ in 0..steps_to_run {
         simulator.0.step_spatial();
     }
     
+    // Warn (TimeSpeedHigh alert) when the speed compromises fidelity
+    simulator.0.check_time_speed(time_speed);
+    
     SimulationResponse {
         state: simulator.0.get_state(),
         control_rods: simulator.0.get_control_rods(),
     }
 }
 
+/// Highest time speed that keeps fidelity at the current dt and reactivity [× real time]
+#[tauri::command]
+pub fn get_max_safe_time_speed(simulator: State<SimulatorState>) -> f64 {
+    simulator.0.get_max_safe_time_speed()
+}
+
 /// Initiate emergency SCRAM
 #[tauri::command]
 pub fn scram(simulator: State<SimulatorState>) -> ReactorState {
@@ -117,15 +158,136 @@ pub fn reset_scram(simulator: State<SimulatorState>) -> ReactorState {
     simulator.0.get_state()
 }
 
+/// Get full-stroke SCRAM insertion time [s]
+#[tauri::command]
+pub fn get_scram_insertion_time(simulator: State<SimulatorState>) -> f64 {
+    simulator.0.get_scram_insertion_time()
+}
+
+/// Set full-stroke SCRAM insertion time [s]
+#[tauri::command]
+pub fn set_scram_insertion_time(simulator: State<SimulatorState>, seconds: f64) -> Result<f64, String> {
+    simulator.0.set_scram_insertion_time(seconds)?;
+    Ok(simulator.0.get_scram_insertion_time())
+}
+
+/// Get the reactivity smoothing time constant used outside a SCRAM [s]
+#[tauri::command]
+pub fn get_reactivity_smoothing_tau(simulator: State<SimulatorState>) -> f64 {
+    simulator.0.get_reactivity_smoothing_tau()
+}
+
+/// Set the reactivity smoothing time constant [s]; returns the value in effect
+#[tauri::command(rename_all = "camelCase")]
+pub fn set_reactivity_smoothing_tau(simulator: State<SimulatorState>, tau_s: f64) -> Result<f64, String> {
+    simulator.0.set_reactivity_smoothing_tau(tau_s)?;
+    Ok(simulator.0.get_reactivity_smoothing_tau())
+}
+
+/// Get the inlet plenum mixing time constant [s]
+#[tauri::command]
+pub fn get_plenum_mixing_time(simulator: State<SimulatorState>) -> f64 {
+    simulator.0.get_plenum_mixing_time()
+}
+
+/// Set the inlet plenum mixing time constant [s]; returns the value in effect
+#[tauri::command]
+pub fn set_plenum_mixing_time(simulator: State<SimulatorState>, seconds: f64) -> Result<f64, String> {
+    simulator.0.set_plenum_mixing_time(seconds)?;
+    Ok(simulator.0.get_plenum_mixing_time())
+}
+
+/// Get the common inlet plenum temperature [K]
+#[tauri::command]
+pub fn get_inlet_plenum_temp(simulator: State<SimulatorState>) -> f64 {
+    simulator.0.get_inlet_plenum_temp()
+}
+
+/// Get pre-shutdown SCRAM readiness (insertable rods, worth, stuck rods)
+#[tauri::command]
+pub fn get_scram_readiness(simulator: State<SimulatorState>) -> ScramReadiness {
+    simulator.0.get_scram_readiness()
+}
+
+/// Check the total and per-type rod worth against the realistic RBMK band
+#[tauri::command]
+pub fn validate_rod_worth(simulator: State<SimulatorState>) -> RodWorthReport {
+    simulator.0.validate_rod_worth()
+}
+
+/// Get inserted vs available rod worth, in total and per rod type [Δk/k]
+#[tauri::command]
+pub fn get_rod_worth_summary(simulator: State<SimulatorState>) -> RodWorthSummary {
+    simulator.0.get_rod_worth_summary()
+}
+
+/// Mark a control rod as stuck (or free it)
+#[tauri::command(rename_all = "camelCase")]
+pub fn set_rod_stuck(
+    simulator: State<SimulatorState>,
+    rod_id: usize,
+    stuck: bool,
+) -> Result<Vec<ControlRod>, String> {
+    if !simulator.0.set_rod_stuck(rod_id, stuck) {
+        return Err(format!("Invalid rod_id {}", rod_id));
+    }
+    Ok(simulator.0.get_control_rods())
+}
+
+/// Restrict the travel range of rods of a channel type
+#[tauri::command(rename_all = "camelCase")]
+pub fn set_rod_travel_limits(
+    simulator: State<SimulatorState>,
+    channel_type: String,
+    min_position: f64,
+    max_position: f64,
+) -> Result<Vec<ControlRod>, String> {
+    simulator.0.set_rod_travel_limits(&channel_type, min_position, max_position)?;
+    Ok(simulator.0.get_control_rods())
+}
+
+/// Travel limits of each channel type that has them
+#[tauri::command]
+pub fn get_rod_travel_limits(simulator: State<SimulatorState>) -> HashMap<String, RodTravelLimits> {
+    simulator.0.get_rod_travel_limits()
+}
+
+/// List the interlocks currently blocking operator rod moves, with reasons
+#[tauri::command]
+pub fn get_active_interlocks(simulator: State<SimulatorState>) -> Vec<Interlock> {
+    simulator.0.get_active_interlocks()
+}
+
+/// Describe every controllable element (rods, rod groups, scalar setpoints) for automated clients
+#[tauri::command]
+pub fn describe_controls(simulator: State<SimulatorState>) -> ControlManifest {
+    simulator.0.describe_controls()
+}
+
 /// Move a single control rod
+/// Returns an error if no rod with the given id exists
 #[tauri::command]
 pub fn move_control_rod(
     simulator: State<SimulatorState>,
     rod_id: usize,
     position: f64,
-) -> Vec<ControlRod> {
-    simulator.0.move_rod(rod_id, position);
-    simulator.0.get_control_rods()
+) -> Result<Vec<ControlRod>, String> {
+    if !simulator.0.move_rod(rod_id, position) {
+        let rod_count = simulator.0.control_rods.lock().unwrap().len();
+        return Err(format!("Invalid rod_id {}: valid range is 0..{}", rod_id, rod_count));
+    }
+    Ok(simulator.0.get_control_rods())
+}
+
+/// Rod type from its lowercase name (manual, automatic, shortened, emergency)
+fn parse_rod_type(rod_type: &str) -> Result<RodType, String> {
+    match rod_type {
+        "manual" => Ok(RodType::Manual),
+        "automatic" => Ok(RodType::Automatic),
+        "shortened" => Ok(RodType::Shortened),
+        "emergency" => Ok(RodType::Emergency),
+        _ => Err(format!("Unknown rod type: {}", rod_type)),
+    }
 }
 
 /// Move a group of control rods by type
@@ -135,12 +297,8 @@ pub fn move_rod_group(
     rod_type: String,
     position: f64,
 ) -> Vec<ControlRod> {
-    let rod_type = match rod_type.as_str() {
-        "manual" => RodType::Manual,
-        "automatic" => RodType::Automatic,
-        "shortened" => RodType::Shortened,
-        "emergency" => RodType::Emergency,
-        _ => return simulator.0.get_control_rods(),
+    let Ok(rod_type) = parse_rod_type(&rod_type) else {
+        return simulator.0.get_control_rods();
     };
     
     simulator.0.move_rod_group(rod_type, position);
@@ -159,6 +317,79 @@ pub fn move_rod_group_by_channel_type(
     simulator.0.get_control_rods()
 }
 
+/// Estimate the critical position of a rod group (RR, AR, LAR, USP, AZ)
+/// under current conditions, holding other rods fixed
+#[tauri::command(rename_all = "camelCase")]
+pub fn predict_critical_position(simulator: State<SimulatorState>, channel_type: String) -> Result<f64, String> {
+    simulator.0.predict_critical_position(&channel_type)
+}
+
+/// Get each rod group's control authority: reactivity per percent withdrawal [$/%]
+#[tauri::command]
+pub fn get_rod_group_sensitivity(simulator: State<SimulatorState>) -> HashMap<String, f64> {
+    simulator.0.get_rod_group_sensitivity()
+}
+
+/// Bias manual rods to flatten the radial power distribution; returns the peaking factor achieved
+#[tauri::command(rename_all = "camelCase")]
+pub fn auto_flatten(simulator: State<SimulatorState>, max_iterations: usize) -> Result<f64, String> {
+    simulator.0.auto_flatten(max_iterations)
+}
+
+/// Rod group position (RR, AR, LAR, USP, AZ) that holds a target power [%] steady
+#[tauri::command(rename_all = "camelCase")]
+pub fn equilibrium_rod_position(simulator: State<SimulatorState>, target_power: f64, channel_type: String) -> Result<f64, String> {
+    simulator.0.equilibrium_rod_position(target_power, &channel_type)
+}
+
+/// Get the shutdown margin in dollars (all rods in, highest-worth rod stuck out)
+#[tauri::command]
+pub fn get_shutdown_margin(simulator: State<SimulatorState>) -> f64 {
+    simulator.0.get_shutdown_margin()
+}
+
+/// Move a rod by an increment, settle for a number of steps and return the
+/// reactivity change in dollars
+#[tauri::command(rename_all = "camelCase")]
+pub fn measure_rod_worth_increment(
+    simulator: State<SimulatorState>,
+    rod_id: usize,
+    delta_position: f64,
+    settle_steps: usize,
+) -> Result<f64, String> {
+    simulator.0.measure_rod_worth_increment(rod_id, delta_position, settle_steps)
+}
+
+/// Step a rod group and record the open-loop power response as (time, power %)
+#[tauri::command(rename_all = "camelCase")]
+pub fn step_response(
+    simulator: State<SimulatorState>,
+    channel_type: String,
+    delta_position: f64,
+    steps: usize,
+) -> Result<Vec<(f64, f64)>, String> {
+    simulator.0.step_response(&channel_type, delta_position, steps)
+}
+
+/// Record the current rod bank position and count rate on the 1/M plot
+#[tauri::command]
+pub fn record_1m_point(simulator: State<SimulatorState>) -> Result<InverseMCurve, String> {
+    simulator.0.record_1m_point()
+}
+
+/// Get the 1/M plot and its extrapolated critical position
+#[tauri::command]
+pub fn get_1m_curve(simulator: State<SimulatorState>) -> InverseMCurve {
+    simulator.0.get_1m_curve()
+}
+
+/// Discard the 1/M plot to start a new approach to criticality
+#[tauri::command]
+pub fn clear_1m_curve(simulator: State<SimulatorState>) -> InverseMCurve {
+    simulator.0.clear_1m_curve();
+    simulator.0.get_1m_curve()
+}
+
 /// Get all control rod positions
 #[tauri::command]
 pub fn get_control_rods(simulator: State<SimulatorState>) -> Vec<ControlRod> {
@@ -185,11 +416,23 @@ pub fn get_fuel_channels(simulator: State<SimulatorState>) -> Vec<FuelChannel> {
     simulator.0.get_fuel_channels_synchronized()
 }
 
+/// Get state, compact rods and compact channels of the same instant in one call
+#[tauri::command]
+pub fn get_frame(simulator: State<SimulatorState>) -> FrameData {
+    simulator.0.get_frame()
+}
+
+/// Get coolant saturation temperature [K] at the current core pressure
+#[tauri::command]
+pub fn get_saturation_temp(simulator: State<SimulatorState>) -> f64 {
+    simulator.0.get_saturation_temp()
+}
+
 /// Set simulation time step
 #[tauri::command]
 pub fn set_time_step(simulator: State<SimulatorState>, dt: f64) {
     let mut state = simulator.0.state.lock().unwrap();
-    state.dt = dt.clamp(0.001, 1.0);
+    state.dt = dt.clamp(constants::TIME_STEP_MIN_S, constants::TIME_STEP_MAX_S);
 }
 
 /// Reset simulation to initial state
@@ -199,6 +442,556 @@ pub fn reset_simulation(simulator: State<SimulatorState>) -> ReactorState {
     simulator.0.get_state()
 }
 
+/// List named layouts (`<name>_layout.json`) in a directory
+#[tauri::command]
+pub fn list_layouts(simulator: State<SimulatorState>, dir: String) -> Result<Vec<String>, String> {
+    simulator.0.list_layouts(&dir)
+}
+
+/// Get the name of the currently loaded layout
+#[tauri::command]
+pub fn get_active_layout(simulator: State<SimulatorState>) -> String {
+    simulator.0.get_active_layout()
+}
+
+/// Switch to a named layout and reset to cold shutdown
+#[tauri::command]
+pub fn load_layout_named(simulator: State<SimulatorState>, name: String) -> Result<ReactorState, String> {
+    simulator.0.load_layout_named(&name)?;
+    Ok(simulator.0.get_state())
+}
+
+/// Get problems found while loading the active layout
+#[tauri::command]
+pub fn get_layout_warnings(simulator: State<SimulatorState>) -> Vec<LayoutWarning> {
+    simulator.0.get_layout_warnings()
+}
+
+/// Pre-seed equilibrium promethium/samarium in all channels
+#[tauri::command(rename_all = "camelCase")]
+pub fn set_samarium_equilibrium(simulator: State<SimulatorState>, power_fraction: f64) -> Vec<FuelChannel> {
+    simulator.0.set_samarium_equilibrium(power_fraction);
+    simulator.0.get_fuel_channels_synchronized()
+}
+
+/// Jump ahead in the xenon transient, holding power and rods
+#[tauri::command]
+pub fn fast_forward_xenon(simulator: State<SimulatorState>, hours: f64) -> Result<ReactorState, String> {
+    simulator.0.fast_forward_xenon(hours)?;
+    Ok(simulator.0.get_state())
+}
+
+/// Project the xenon peak (time and depth) at the current power
+#[tauri::command]
+pub fn get_xenon_projection(simulator: State<SimulatorState>) -> XenonProjection {
+    simulator.0.get_xenon_projection()
+}
+
+/// Get each delayed group's share of the delayed neutron source (λᵢ·Cᵢ)
+#[tauri::command]
+pub fn get_delayed_source_breakdown(simulator: State<SimulatorState>) -> [f64; constants::NUM_DELAYED_GROUPS] {
+    simulator.0.get_delayed_source_breakdown()
+}
+
+/// Set feedwater flow (1.0 = steaming rate at nominal power)
+#[tauri::command]
+pub fn set_feedwater_rate(simulator: State<SimulatorState>, fraction: f64) -> Result<ReactorState, String> {
+    simulator.0.set_feedwater_rate(fraction)?;
+    Ok(simulator.0.get_state())
+}
+
+/// Set the coolant inventory (1.0 = normal drum level)
+#[tauri::command]
+pub fn set_coolant_inventory(simulator: State<SimulatorState>, fraction: f64) -> Result<ReactorState, String> {
+    simulator.0.set_coolant_inventory(fraction)?;
+    Ok(simulator.0.get_state())
+}
+
+/// Get a channel's diffusion neighbors as (id, grid_x, grid_y)
+#[tauri::command(rename_all = "camelCase")]
+pub fn get_channel_neighbors(simulator: State<SimulatorState>, channel_id: usize) -> Result<Vec<(usize, i32, i32)>, String> {
+    let num_channels = simulator.0.fuel_channels.lock().unwrap().len();
+    if channel_id >= num_channels {
+        return Err(format!("Invalid channel_id {}: valid range is 0..{}", channel_id, num_channels));
+    }
+    Ok(simulator.0.get_channel_neighbors(channel_id))
+}
+
+/// Rupture a fuel channel's pressure tube
+#[tauri::command(rename_all = "camelCase")]
+pub fn rupture_channel(simulator: State<SimulatorState>, channel_id: usize) -> Result<Vec<FuelChannel>, String> {
+    simulator.0.rupture_channel(channel_id)?;
+    Ok(simulator.0.get_ruptured_channels())
+}
+
+/// Get the LAR zones (member channels, zone power and error)
+#[tauri::command]
+pub fn get_lar_zones(simulator: State<SimulatorState>) -> Vec<LarZone> {
+    simulator.0.get_lar_zones()
+}
+
+/// Get in-core detector readings (flux, fission rate, ion-chamber current)
+#[tauri::command]
+pub fn get_detector_signals(simulator: State<SimulatorState>) -> Vec<DetectorSignal> {
+    simulator.0.get_detector_signals()
+}
+
+/// Make an in-core detector read wrong (Stuck, Drift, Dead) or repair it (None)
+#[tauri::command(rename_all = "camelCase")]
+pub fn set_detector_fault(simulator: State<SimulatorState>, detector_id: usize, fault: DetectorFault) -> Result<(), String> {
+    simulator.0.set_detector_fault(detector_id, fault)
+}
+
+/// Start recording a channel's time history (power, fuel temp, void, xenon)
+#[tauri::command(rename_all = "camelCase")]
+pub fn track_channel(simulator: State<SimulatorState>, channel_id: usize) -> Result<Vec<usize>, String> {
+    simulator.0.track_channel(channel_id)?;
+    Ok(simulator.0.get_tracked_channels())
+}
+
+/// Stop recording a channel's time history
+#[tauri::command(rename_all = "camelCase")]
+pub fn untrack_channel(simulator: State<SimulatorState>, channel_id: usize) -> Vec<usize> {
+    simulator.0.untrack_channel(channel_id);
+    simulator.0.get_tracked_channels()
+}
+
+/// Get the recorded time history of a tracked channel
+#[tauri::command(rename_all = "camelCase")]
+pub fn get_channel_history(simulator: State<SimulatorState>, channel_id: usize) -> Result<Vec<ChannelSample>, String> {
+    simulator.0.get_channel_history(channel_id)
+        .ok_or_else(|| format!("Channel {} is not tracked", channel_id))
+}
+
+/// Get k_eff of recent steps with the mean rod position (same definition in both stepping modes)
+#[tauri::command]
+pub fn get_keff_history(simulator: State<SimulatorState>) -> Vec<KeffSample> {
+    simulator.0.get_keff_history()
+}
+
+/// Get key values of only the channels matching the given thresholds
+#[tauri::command]
+pub fn query_channels(simulator: State<SimulatorState>, filter: ChannelFilter) -> Vec<ChannelSummary> {
+    simulator.0.query_channels(&filter)
+}
+
+/// Get the core-minimum coolant subcooling margin [K] with its channel id
+#[tauri::command]
+pub fn get_subcooling_margin(simulator: State<SimulatorState>) -> Option<SubcoolingMargin> {
+    simulator.0.get_subcooling_margin()
+}
+
+/// Get the core-minimum DNBR with its channel id
+#[tauri::command]
+pub fn get_min_dnbr(simulator: State<SimulatorState>) -> Option<MinDnbr> {
+    simulator.0.get_min_dnbr()
+}
+
+/// Get all channels with a ruptured pressure tube
+#[tauri::command]
+pub fn get_ruptured_channels(simulator: State<SimulatorState>) -> Vec<FuelChannel> {
+    simulator.0.get_ruptured_channels()
+}
+
+/// Get channels whose power hit the ceiling in the last spatial step
+#[tauri::command]
+pub fn get_clamped_channels(simulator: State<SimulatorState>) -> Vec<FuelChannel> {
+    simulator.0.get_clamped_channels()
+}
+
+/// Set the per-channel power ceiling of the spatial solver [MW]
+#[tauri::command(rename_all = "camelCase")]
+pub fn set_channel_power_ceiling(simulator: State<SimulatorState>, ceiling_mw: f64) -> Result<f64, String> {
+    simulator.0.set_channel_power_ceiling(ceiling_mw)?;
+    Ok(simulator.0.get_channel_power_ceiling())
+}
+
+/// Get the fuel volume per channel used for power density [m³]
+#[tauri::command]
+pub fn get_channel_volume(simulator: State<SimulatorState>) -> f64 {
+    simulator.0.get_channel_volume()
+}
+
+/// Set the fuel volume per channel [m³]; returns the value in effect
+#[tauri::command(rename_all = "camelCase")]
+pub fn set_channel_volume(simulator: State<SimulatorState>, volume_m3: f64) -> Result<f64, String> {
+    simulator.0.set_channel_volume(volume_m3)?;
+    Ok(simulator.0.get_channel_volume())
+}
+
+/// Arm a seeded drill fault that fires within the window (seconds from now)
+#[tauri::command(rename_all = "camelCase")]
+pub fn arm_random_fault(
+    simulator: State<SimulatorState>,
+    seed: u64,
+    fault_set: Vec<FaultKind>,
+    window_start: f64,
+    window_end: f64,
+) -> Result<FaultDrillStatus, String> {
+    simulator.0.arm_random_fault(seed, fault_set, (window_start, window_end))?;
+    Ok(simulator.0.get_fault_drill_status())
+}
+
+/// Get whether a drill is armed and which fault it fired, and when
+#[tauri::command]
+pub fn get_fault_drill_status(simulator: State<SimulatorState>) -> FaultDrillStatus {
+    simulator.0.get_fault_drill_status()
+}
+
+/// Get alerts held by the annunciator (persist for the hold time after clearing)
+#[tauri::command]
+pub fn get_active_alerts(simulator: State<SimulatorState>) -> Vec<ActiveAlert> {
+    simulator.0.get_active_alerts()
+}
+
+/// Get every alert code the simulator can raise, with severity, source and message template
+#[tauri::command]
+pub fn get_alert_catalog() -> Vec<AlertDefinition> {
+    ReactorSimulator::alert_catalog()
+}
+
+/// Capture a "black box" snapshot: state, rods, hottest channels, alerts,
+/// reactivity breakdown, ORM and the recent reactivity trace
+#[tauri::command]
+pub fn get_blackbox_snapshot(simulator: State<SimulatorState>) -> BlackboxSnapshot {
+    simulator.0.get_blackbox_snapshot()
+}
+
+/// Capture a black box snapshot serialized with its format version, for saving
+#[tauri::command]
+pub fn export_blackbox_snapshot(simulator: State<SimulatorState>) -> Result<String, String> {
+    blackbox::encode_snapshot(&simulator.0.get_blackbox_snapshot()).map_err(|e| e.to_string())
+}
+
+/// Read a saved black box snapshot, rejecting unsupported format versions
+#[tauri::command]
+pub fn read_blackbox_snapshot(json: String) -> Result<BlackboxSnapshot, String> {
+    blackbox::decode_snapshot(&json).map_err(|e| e.to_string())
+}
+
+/// Step back at least `seconds` to the latest in-memory checkpoint; false if none is that old
+#[tauri::command]
+pub fn rewind(simulator: State<SimulatorState>, seconds: f64) -> bool {
+    simulator.0.rewind(seconds)
+}
+
+/// Set how many rewind checkpoints are kept (one per second of simulation time)
+#[tauri::command]
+pub fn set_checkpoint_count(simulator: State<SimulatorState>, count: usize) -> Result<Vec<f64>, String> {
+    simulator.0.set_checkpoint_count(count)?;
+    Ok(simulator.0.get_checkpoint_times())
+}
+
+/// Get the simulation times rewind can return to, oldest first [s]
+#[tauri::command]
+pub fn get_checkpoint_times(simulator: State<SimulatorState>) -> Vec<f64> {
+    simulator.0.get_checkpoint_times()
+}
+
+/// Set the alert hold time [s]
+#[tauri::command]
+pub fn set_alert_hold_time(simulator: State<SimulatorState>, seconds: f64) -> Result<Vec<ActiveAlert>, String> {
+    simulator.0.set_alert_hold_time(seconds)?;
+    Ok(simulator.0.get_active_alerts())
+}
+
+/// Get the top vs bottom axial power split (USP rods flatten the bottom)
+#[tauri::command]
+pub fn get_axial_power_offset(simulator: State<SimulatorState>) -> AxialPowerOffset {
+    simulator.0.get_axial_power_offset()
+}
+
+/// Get the radial power profile averaged over concentric rings
+#[tauri::command(rename_all = "camelCase")]
+pub fn get_radial_profile(simulator: State<SimulatorState>, num_rings: usize) -> Result<Vec<RingStat>, String> {
+    simulator.0.get_radial_profile(num_rings)
+}
+
+/// Get the IDs of channels loaded with fresh (zero-burnup) fuel
+#[tauri::command]
+pub fn get_fresh_channels(simulator: State<SimulatorState>) -> Vec<usize> {
+    simulator.0.get_fresh_channels()
+}
+
+/// Get the core-average burnup and its spread over the channels
+#[tauri::command]
+pub fn get_burnup_stats(simulator: State<SimulatorState>) -> BurnupStats {
+    simulator.0.get_burnup_stats()
+}
+
+/// Get whether each protection trip (power high, short period) is armed or bypassed
+#[tauri::command]
+pub fn get_trip_status(simulator: State<SimulatorState>) -> Vec<TripStatus> {
+    simulator.0.get_trip_status()
+}
+
+/// Bypass a protection trip or re-arm it
+#[tauri::command]
+pub fn set_trip_bypass(simulator: State<SimulatorState>, trip: Trip, bypassed: bool) -> Vec<TripStatus> {
+    simulator.0.set_trip_bypass(trip, bypassed);
+    simulator.0.get_trip_status()
+}
+
+/// Project time until the next monitored limit (power 110%, period 30 s) is reached
+#[tauri::command]
+pub fn get_time_to_trip(simulator: State<SimulatorState>) -> TripProjection {
+    simulator.0.get_time_to_trip()
+}
+
+/// Get the phase timing of the last spatial step (None without the `profiling` feature)
+#[tauri::command]
+pub fn get_last_step_timing(simulator: State<SimulatorState>) -> Option<StepTiming> {
+    simulator.0.get_last_step_timing()
+}
+
+/// Get whether the core is subcritical, critical, delayed or prompt supercritical
+#[tauri::command]
+pub fn get_criticality_state(simulator: State<SimulatorState>) -> Criticality {
+    simulator.0.get_criticality_state()
+}
+
+/// Get the simulation time of the last SCRAM, alert, rod move and explosion
+#[tauri::command]
+pub fn get_event_times(simulator: State<SimulatorState>) -> EventTimes {
+    simulator.0.get_event_times()
+}
+
+/// Grade the end state of a run (exploded, damaged, shut down, scrammed, stable, transient)
+#[tauri::command]
+pub fn classify_outcome(simulator: State<SimulatorState>) -> Outcome {
+    simulator.0.classify_outcome()
+}
+
+/// Get the current point on the power-flow map and its operating region
+#[tauri::command]
+pub fn get_operating_point(simulator: State<SimulatorState>) -> OperatingPoint {
+    simulator.0.get_operating_point()
+}
+
+/// Get core age in effective full-power days
+#[tauri::command]
+pub fn get_efpd(simulator: State<SimulatorState>) -> f64 {
+    simulator.0.get_efpd()
+}
+
+/// Get core age in effective full-power hours
+#[tauri::command]
+pub fn get_efph(simulator: State<SimulatorState>) -> f64 {
+    simulator.0.get_efph()
+}
+
+/// Choose the unit reported in `reactivity_display`
+#[tauri::command]
+pub fn set_reactivity_display_unit(simulator: State<SimulatorState>, unit: ReactivityUnit) -> ReactorState {
+    simulator.0.set_reactivity_display_unit(unit);
+    simulator.0.get_state()
+}
+
+/// Get the simulation's name, description, creation time and plant model
+#[tauri::command]
+pub fn get_metadata(simulator: State<SimulatorState>) -> SimulationMetadata {
+    simulator.0.get_metadata()
+}
+
+/// Set the simulation's name, description and plant model (created_at 0 keeps the current one)
+#[tauri::command]
+pub fn set_metadata(simulator: State<SimulatorState>, metadata: SimulationMetadata) -> Result<SimulationMetadata, String> {
+    simulator.0.set_metadata(metadata)?;
+    Ok(simulator.0.get_metadata())
+}
+
+/// Get the geometry hash of the loaded layout as 16 hex digits (equal = identical channels and rods)
+/// Hex because JavaScript numbers cannot hold every u64.
+#[tauri::command]
+pub fn get_config_hash(simulator: State<SimulatorState>) -> String {
+    format!("{:016x}", simulator.0.config_hash())
+}
+
+/// Get the channel id at every occupied grid cell (for clicked cell → channel lookups)
+#[tauri::command]
+pub fn get_grid_index(simulator: State<SimulatorState>) -> Vec<GridCell> {
+    simulator.0.get_grid_cells()
+}
+
+/// Get kinetics constants in effect (β, neutron lifetime, nominal power)
+#[tauri::command]
+pub fn get_physics_constants(simulator: State<SimulatorState>) -> PhysicsConstants {
+    simulator.0.get_physics_constants()
+}
+
+/// Set prompt neutron lifetime [s]
+#[tauri::command]
+pub fn set_neutron_lifetime(simulator: State<SimulatorState>, lifetime: f64) -> Result<PhysicsConstants, String> {
+    simulator.0.set_neutron_lifetime(lifetime)?;
+    Ok(simulator.0.get_physics_constants())
+}
+
+/// Reactivity [Δk/k] that produces a measured stable period [s] (inhour equation)
+#[tauri::command]
+pub fn reactivity_from_period(simulator: State<SimulatorState>, period: f64) -> Result<f64, String> {
+    simulator.0.reactivity_from_period(period)
+}
+
+/// Select the point kinetics solver (Euler, Rk4, PromptJump or SixGroup)
+#[tauri::command]
+pub fn set_kinetics_solver(simulator: State<SimulatorState>, solver: KineticsSolver) -> KineticsSolver {
+    simulator.0.set_kinetics_solver(solver);
+    simulator.0.get_kinetics_solver()
+}
+
+/// Get absorber burnout and remaining worth of every control rod
+#[tauri::command]
+pub fn get_rod_depletion(simulator: State<SimulatorState>) -> Vec<RodDepletion> {
+    simulator.0.get_rod_depletion()
+}
+
+/// Set the rod absorber burnout rate [1/s at full insertion and 100 % power]
+#[tauri::command]
+pub fn set_absorber_depletion_rate(simulator: State<SimulatorState>, rate: f64) -> Result<SimulationParameters, String> {
+    simulator.0.set_absorber_depletion_rate(rate)?;
+    Ok(simulator.0.get_simulation_parameters())
+}
+
+/// Set the worth curve shape exponent of a rod type (1.0 = symmetric S-curve)
+#[tauri::command(rename_all = "camelCase")]
+pub fn set_rod_worth_shape(simulator: State<SimulatorState>, rod_type: String, shape: f64) -> Result<SimulationParameters, String> {
+    simulator.0.set_rod_worth_shape(&parse_rod_type(&rod_type)?, shape)?;
+    Ok(simulator.0.get_simulation_parameters())
+}
+
+/// Inserted worth fraction along the stroke of a rod type
+#[tauri::command(rename_all = "camelCase")]
+pub fn get_rod_worth_curve(simulator: State<SimulatorState>, rod_type: String, points: Option<usize>) -> Result<Vec<RodWorthPoint>, String> {
+    let points = points.unwrap_or(constants::ROD_WORTH_CURVE_POINTS);
+    Ok(simulator.0.get_rod_worth_curve(&parse_rod_type(&rod_type)?, points))
+}
+
+/// Get the point kinetics solver in use
+#[tauri::command]
+pub fn get_kinetics_solver(simulator: State<SimulatorState>) -> KineticsSolver {
+    simulator.0.get_kinetics_solver()
+}
+
+/// Select the fuel conductivity model (Constant or BurnupDegraded) of the spatial thermal step
+#[tauri::command]
+pub fn set_fuel_conductivity_model(simulator: State<SimulatorState>, model: FuelConductivityModel) -> Result<FuelConductivityModel, String> {
+    simulator.0.set_fuel_conductivity_model(model)?;
+    Ok(simulator.0.get_fuel_conductivity_model())
+}
+
+/// Get the fuel conductivity model in use
+#[tauri::command]
+pub fn get_fuel_conductivity_model(simulator: State<SimulatorState>) -> FuelConductivityModel {
+    simulator.0.get_fuel_conductivity_model()
+}
+
+/// Get how far the reactivity breakdown misses the reactivity the kinetics used [Δk/k]
+#[tauri::command]
+pub fn get_reactivity_balance_residual(simulator: State<SimulatorState>) -> f64 {
+    simulator.0.get_reactivity_balance_residual()
+}
+
+/// Mark the current reactivity as the reference of a maneuver
+#[tauri::command]
+pub fn mark_reactivity_reference(simulator: State<SimulatorState>) {
+    simulator.0.mark_reactivity_reference();
+}
+
+/// Get the net reactivity inserted since the marked reference [$]
+#[tauri::command]
+pub fn get_reactivity_since_reference(simulator: State<SimulatorState>) -> f64 {
+    simulator.0.get_reactivity_since_reference()
+}
+
+/// Get the numerical divergence watch: detections and the suggested time step
+#[tauri::command]
+pub fn get_stability_status(simulator: State<SimulatorState>) -> StabilityStatus {
+    simulator.0.get_stability_status()
+}
+
+/// Let the divergence watch halve the time step itself on detection
+#[tauri::command]
+pub fn set_auto_reduce_time_step(simulator: State<SimulatorState>, enabled: bool) -> StabilityStatus {
+    simulator.0.set_auto_reduce_time_step(enabled);
+    simulator.0.get_stability_status()
+}
+
+/// Get the time step the kinetics actually used in the last step (after sub-stepping)
+#[tauri::command]
+pub fn get_effective_time_step(simulator: State<SimulatorState>) -> EffectiveTimeStep {
+    simulator.0.get_effective_time_step()
+}
+
+/// Set graphite gas-gap conductance relative to nominal (below 1 = degraded gap)
+#[tauri::command]
+pub fn set_graphite_gap_conductance(simulator: State<SimulatorState>, conductance: f64) -> Result<SimulationParameters, String> {
+    simulator.0.set_graphite_gap_conductance(conductance)?;
+    Ok(simulator.0.get_simulation_parameters())
+}
+
+/// Set graphite gas-circuit cooling effectiveness relative to nominal (above 1 = cooler graphite)
+#[tauri::command]
+pub fn set_graphite_cooling_effectiveness(simulator: State<SimulatorState>, effectiveness: f64) -> Result<SimulationParameters, String> {
+    simulator.0.set_graphite_cooling_effectiveness(effectiveness)?;
+    Ok(simulator.0.get_simulation_parameters())
+}
+
+/// Get every runtime-tunable parameter (time step, kinetics, SCRAM, alerts, regulator)
+#[tauri::command]
+pub fn get_simulation_parameters(simulator: State<SimulatorState>) -> SimulationParameters {
+    simulator.0.get_simulation_parameters()
+}
+
+/// Apply a complete parameter set (all-or-nothing)
+#[tauri::command]
+pub fn set_simulation_parameters(
+    simulator: State<SimulatorState>,
+    params: SimulationParameters,
+) -> Result<SimulationParameters, String> {
+    simulator.0.set_simulation_parameters(&params)?;
+    Ok(simulator.0.get_simulation_parameters())
+}
+
+/// Warm restart: shut down at hot standby, keeping xenon, iodine and burnup
+#[tauri::command]
+pub fn restart_hot(simulator: State<SimulatorState>) -> ReactorState {
+    simulator.0.restart_hot();
+    simulator.0.get_state()
+}
+
+/// Physics library resets the last reset skipped (symbols missing in an older DLL)
+#[tauri::command]
+pub fn get_skipped_resets(simulator: State<SimulatorState>) -> Vec<String> {
+    simulator.0.get_skipped_resets()
+}
+
+/// Compare the sum of channel powers with the displayed core power
+#[tauri::command]
+pub fn get_power_balance(simulator: State<SimulatorState>) -> PowerBalance {
+    simulator.0.get_power_balance()
+}
+
+/// Rescale channel powers each step so they sum to the core power
+#[tauri::command]
+pub fn set_power_normalization(simulator: State<SimulatorState>, enabled: bool) -> PowerBalance {
+    simulator.0.set_power_normalization(enabled);
+    simulator.0.get_power_balance()
+}
+
+/// Hold temperatures and void constant to study pure neutron kinetics
+#[tauri::command]
+pub fn set_freeze_thermal(simulator: State<SimulatorState>, frozen: bool) -> ReactorState {
+    simulator.0.set_freeze_thermal(frozen);
+    simulator.0.get_state()
+}
+
+/// Hold iodine and xenon constant to study transients without poison dynamics
+#[tauri::command]
+pub fn set_freeze_xenon(simulator: State<SimulatorState>, frozen: bool) -> ReactorState {
+    simulator.0.set_freeze_xenon(frozen);
+    simulator.0.get_state()
+}
+
 // ============================================================================
 // Automatic Regulator (AR/LAR) Commands
 // ============================================================================
@@ -214,14 +1007,24 @@ pub fn set_auto_regulator_enabled(
 }
 
 /// Set target power for automatic regulator
-/// Target is in percent of nominal power (5-110%)
+/// Target is in percent of nominal power (5-110%); the response says
+/// whether it had to be clamped
 #[tauri::command]
 pub fn set_target_power(
     simulator: State<SimulatorState>,
     target_percent: f64,
-) -> AutoRegulatorSettings {
-    simulator.0.set_target_power(target_percent);
-    simulator.0.get_auto_regulator()
+) -> TargetPowerResponse {
+    let target = simulator.0.set_target_power(target_percent);
+    TargetPowerResponse {
+        auto_regulator: simulator.0.get_auto_regulator(),
+        target,
+    }
+}
+
+/// Allowed range of the regulator target power as (min, max) [% nominal]
+#[tauri::command]
+pub fn get_target_power_range() -> (f64, f64) {
+    (constants::TARGET_POWER_MIN_PERCENT, constants::TARGET_POWER_MAX_PERCENT)
 }
 
 /// Get current automatic regulator settings
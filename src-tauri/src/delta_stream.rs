@@ -0,0 +1,153 @@
+//! Versioned field deltas for network streaming
+//!
+//! A remote client keeps its own copy of the state and asks only for what
+//! changed since the version it last saw. Every field of `ReactorState` and
+//! of each fuel channel remembers the version in which its value last
+//! changed; the version advances once per simulation step.
+//!
+//! Values are compared lazily, when a delta is requested, so stepping costs
+//! nothing extra. A change seen at a version that was already handed out is
+//! filed under a new version so no client can miss it.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::reactor::{FuelChannel, ReactorState};
+
+/// Changed fields of one fuel channel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelFieldDelta {
+    pub id: usize,
+    pub fields: Map<String, Value>,
+}
+
+/// Everything that changed after `since_version`, up to `version`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VersionedDelta {
+    pub version: u64,                    // Pass back as `since_version` next time
+    pub channel_count: usize,            // Drop local channels beyond this
+    pub state: Map<String, Value>,       // Changed `ReactorState` fields
+    pub channels: Vec<ChannelFieldDelta>, // Channels with at least one changed field
+}
+
+/// Last value of each field of one JSON object and the version it changed in
+#[derive(Debug, Clone, Default)]
+struct FieldVersions {
+    fields: Map<String, Value>,
+    changed: BTreeMap<String, u64>,
+}
+
+impl FieldVersions {
+    /// Record the current field values, tagging changed ones with `version`
+    fn observe(&mut self, current: Map<String, Value>, version: u64) {
+        for (key, value) in current {
+            if self.fields.get(&key) != Some(&value) {
+                self.changed.insert(key.clone(), version);
+                self.fields.insert(key, value);
+            }
+        }
+    }
+
+    /// Fields that changed after `since`
+    fn since(&self, since: u64) -> Map<String, Value> {
+        self.changed.iter()
+            .filter(|(_, &version)| version > since)
+            .map(|(key, _)| (key.clone(), self.fields[key].clone()))
+            .collect()
+    }
+}
+
+/// Field versions of the state and all channels
+#[derive(Debug, Clone, Default)]
+pub struct DeltaTracker {
+    version: u64,
+    handed_out: bool, // The current version has been returned to a client
+    state: FieldVersions,
+    channels: Vec<FieldVersions>,
+}
+
+impl DeltaTracker {
+    /// Start a new version (once per simulation step)
+    pub fn advance(&mut self) {
+        self.version += 1;
+        self.handed_out = false;
+    }
+
+    /// Fields of `state` and `channels` that changed after `since_version`
+    /// `since_version` 0 returns everything.
+    pub fn delta_since(&mut self, state: &ReactorState, channels: &[FuelChannel], since_version: u64) -> VersionedDelta {
+        let state_fields = to_fields(state);
+        let channel_fields: Vec<Map<String, Value>> = channels.iter().map(to_fields).collect();
+
+        // Changes made after this version was handed out (e.g. a rod move
+        // between steps) must not be filed under it. Version 0 is reserved
+        // for "nothing seen yet".
+        let resized = channel_fields.len() != self.channels.len();
+        let changed = resized
+            || self.state.fields != state_fields
+            || self.channels.iter().zip(&channel_fields).any(|(c, f)| c.fields != *f);
+        if changed && (self.handed_out || self.version == 0) {
+            self.advance();
+        }
+
+        self.state.observe(state_fields, self.version);
+        self.channels.resize_with(channel_fields.len(), FieldVersions::default);
+        for (tracked, fields) in self.channels.iter_mut().zip(channel_fields) {
+            tracked.observe(fields, self.version);
+        }
+        self.handed_out = true;
+
+        VersionedDelta {
+            version: self.version,
+            channel_count: self.channels.len(),
+            state: self.state.since(since_version),
+            channels: self.channels.iter()
+                .enumerate()
+                .map(|(id, tracked)| ChannelFieldDelta { id, fields: tracked.since(since_version) })
+                .filter(|delta| !delta.fields.is_empty())
+                .collect(),
+        }
+    }
+}
+
+fn to_fields<T: Serialize>(value: &T) -> Map<String, Value> {
+    match serde_json::to_value(value) {
+        Ok(Value::Object(fields)) => fields,
+        _ => Map::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_changed_fields() {
+        let mut tracker = DeltaTracker::default();
+        let mut state = ReactorState::default();
+
+        let full = tracker.delta_since(&state, &[], 0);
+        assert!(full.state.contains_key("power_mw"));
+        assert!(full.state.contains_key("time"));
+
+        tracker.advance();
+        state.power_mw = 123.0;
+        let delta = tracker.delta_since(&state, &[], full.version);
+        assert!(delta.version > full.version);
+        assert_eq!(delta.state.len(), 1);
+        assert_eq!(delta.state["power_mw"], Value::from(123.0));
+
+        // A change after the version was handed out gets a new version
+        state.time = 5.0;
+        let later = tracker.delta_since(&state, &[], delta.version);
+        assert!(later.version > delta.version);
+        assert_eq!(later.state.keys().collect::<Vec<_>>(), vec!["time"]);
+
+        // Nothing changed: same version, empty delta
+        let idle = tracker.delta_since(&state, &[], later.version);
+        assert_eq!(idle.version, later.version);
+        assert!(idle.state.is_empty());
+    }
+}
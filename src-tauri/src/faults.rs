@@ -0,0 +1,158 @@
+//! Seeded random fault injection for operator drills
+//!
+//! An instructor arms the injector with a seed, a set of candidate faults and
+//! a time window (seconds after arming). The fault kind, its target and the
+//! trigger time are all drawn from the seed up front, so the same seed gives
+//! the same drill every time and sessions can be graded fairly.
+
+use serde::{Deserialize, Serialize};
+
+/// Faults the injector can spring
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FaultKind {
+    StuckRod,        // A control rod jams at its current position
+    PumpTrip,        // A main circulation pump trips, cutting flow in one loop
+    ChannelBlockage, // A single channel's flow is choked
+}
+
+/// Flow remaining in a loop after one of its three running pumps trips
+pub const PUMP_TRIP_FLOW_FRACTION: f64 = 2.0 / 3.0;
+
+/// Flow remaining in a blocked channel
+pub const CHANNEL_BLOCKAGE_FLOW_FRACTION: f64 = 0.1;
+
+/// A fault that has been triggered
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FiredFault {
+    pub kind: FaultKind,
+    pub time: f64,             // Simulation time the fault fired [s]
+    pub target: Option<usize>, // Rod id, loop (0/1) or channel id; None if nothing to act on
+    pub description: String,
+}
+
+/// Status of the drill injector as reported to clients
+/// The scheduled time is not reported so the fault stays a surprise.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FaultDrillStatus {
+    pub armed: bool,                // Waiting to fire
+    pub fired: Option<FiredFault>,  // Fault sprung by the last armed drill
+}
+
+/// Pending fault drawn from the seed
+#[derive(Debug, Clone)]
+pub struct ScheduledFault {
+    pub kind: FaultKind,
+    pub trigger_time: f64, // Absolute simulation time [s]
+    pub target_draw: u64,  // Raw draw used to pick the target
+}
+
+/// SplitMix64: tiny, seedable and stable across platforms and releases
+#[derive(Debug, Clone)]
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in [0, 1)
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+/// Holds at most one armed drill and the fault it fired
+#[derive(Debug, Clone, Default)]
+pub struct FaultInjector {
+    scheduled: Option<ScheduledFault>,
+    fired: Option<FiredFault>,
+}
+
+impl FaultInjector {
+    /// Arm a drill at simulation time `now`
+    /// The fault fires at a seeded time in `now + window_s.0 ..= now + window_s.1`.
+    pub fn arm(
+        &mut self,
+        now: f64,
+        seed: u64,
+        fault_set: &[FaultKind],
+        window_s: (f64, f64),
+    ) -> Result<(), String> {
+        if fault_set.is_empty() {
+            return Err("Fault set is empty".to_string());
+        }
+        let (start, end) = window_s;
+        if !(start.is_finite() && end.is_finite() && 0.0 <= start && start <= end) {
+            return Err(format!("Invalid fault window ({}, {})", start, end));
+        }
+
+        let mut rng = SplitMix64(seed);
+        let kind = fault_set[(rng.next_u64() % fault_set.len() as u64) as usize];
+        let trigger_time = now + start + rng.next_f64() * (end - start);
+        let target_draw = rng.next_u64();
+
+        self.scheduled = Some(ScheduledFault { kind, trigger_time, target_draw });
+        self.fired = None;
+        Ok(())
+    }
+
+    /// Take the scheduled fault if it is due at simulation time `now`
+    pub fn take_due(&mut self, now: f64) -> Option<ScheduledFault> {
+        match &self.scheduled {
+            Some(fault) if now >= fault.trigger_time => self.scheduled.take(),
+            _ => None,
+        }
+    }
+
+    pub fn record_fired(&mut self, fault: FiredFault) {
+        self.fired = Some(fault);
+    }
+
+    /// Disarm and forget any fired fault (on reset)
+    pub fn clear(&mut self) {
+        self.scheduled = None;
+        self.fired = None;
+    }
+
+    pub fn status(&self) -> FaultDrillStatus {
+        FaultDrillStatus {
+            armed: self.scheduled.is_some(),
+            fired: self.fired.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_drill() {
+        let faults = [FaultKind::StuckRod, FaultKind::PumpTrip, FaultKind::ChannelBlockage];
+        let draw = |seed| {
+            let mut injector = FaultInjector::default();
+            injector.arm(100.0, seed, &faults, (10.0, 60.0)).unwrap();
+            injector.scheduled.clone().unwrap()
+        };
+
+        let a = draw(42);
+        let b = draw(42);
+        assert_eq!(a.kind, b.kind);
+        assert_eq!(a.trigger_time, b.trigger_time);
+        assert_eq!(a.target_draw, b.target_draw);
+        assert!((110.0..=160.0).contains(&a.trigger_time));
+
+        let mut injector = FaultInjector::default();
+        injector.arm(0.0, 42, &faults, (5.0, 5.0)).unwrap();
+        assert!(injector.take_due(4.9).is_none());
+        assert!(injector.take_due(5.0).is_some());
+        assert!(!injector.status().armed);
+
+        assert!(injector.arm(0.0, 1, &[], (0.0, 1.0)).is_err());
+        assert!(injector.arm(0.0, 1, &faults, (10.0, 5.0)).is_err());
+    }
+}
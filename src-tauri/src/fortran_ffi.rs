@@ -6,8 +6,8 @@
 use std::sync::OnceLock;
 use libloading::{Library, Symbol};
 
-/// Global library handle
-static FORTRAN_LIB: OnceLock<Library> = OnceLock::new();
+/// Global library handle (None if no candidate path could be loaded)
+static FORTRAN_LIB: OnceLock<Option<Library>> = OnceLock::new();
 
 // ============================================================================
 // Type definitions for Fortran function signatures
@@ -60,6 +60,14 @@ type UpdateAxialFlux = unsafe extern "C" fn(
     axial_flux: *mut f64,
 );
 
+/// Axial flux with a top/bottom tilt (point 0 = core bottom)
+type UpdateAxialFluxTilted = unsafe extern "C" fn(
+    n_points: i32,
+    neutron_population: f64,
+    tilt: f64,
+    axial_flux: *mut f64,
+);
+
 type CalculateReactivity = unsafe extern "C" fn(
     k_eff: f64,
     fuel_temp: f64,
@@ -69,6 +77,16 @@ type CalculateReactivity = unsafe extern "C" fn(
     reactivity: *mut f64,
 );
 
+/// Steady-state reactivity (no smoothing or rate limit)
+type CalculateTargetReactivity = unsafe extern "C" fn(
+    fuel_temp: f64,
+    graphite_temp: f64,
+    coolant_void: f64,
+    xenon_135: f64,
+    rod_worth: f64,
+    target_reactivity: *mut f64,
+);
+
 type CalculateTotalReactivity = unsafe extern "C" fn(
     fuel_temp: f64,
     graphite_temp: f64,
@@ -81,6 +99,25 @@ type CalculateTotalReactivity = unsafe extern "C" fn(
     new_reactivity: *mut f64,
 );
 
+/// Xenon poisoning reactivity (point model or per-channel spatial model)
+type CalculateXenonReactivity = unsafe extern "C" fn(
+    xenon_135: f64,
+    reactivity: *mut f64,
+);
+
+/// Samarium poisoning reactivity for a single channel
+type CalculateSamariumReactivity = unsafe extern "C" fn(
+    samarium_149: f64,
+    reactivity: *mut f64,
+);
+
+/// Equilibrium Pm-149/Sm-149 concentrations at a power fraction
+type SamariumEquilibrium = unsafe extern "C" fn(
+    power_fraction: f64,
+    promethium_eq: *mut f64,
+    samarium_eq: *mut f64,
+);
+
 type SolvePointKinetics = unsafe extern "C" fn(
     n_neutrons: f64,
     precursors: f64,
@@ -129,12 +166,26 @@ type SumPrecursors6Group = unsafe extern "C" fn(
     total: *mut f64,
 );
 
+/// Advance 6-group precursors over dt at constant neutron population
+type AdvancePrecursors6Group = unsafe extern "C" fn(
+    n_neutrons: f64,
+    precursors_6: *const f64,
+    dt: f64,
+    precursors_6_new: *mut f64,
+);
+
 /// Calculate reactor period
 type CalculateReactorPeriod = unsafe extern "C" fn(
     reactivity: f64,
     period: *mut f64,
 );
 
+/// Reactivity from a stable reactor period (inhour equation)
+type ReactivityFromPeriod = unsafe extern "C" fn(
+    period: f64,
+    reactivity: *mut f64,
+);
+
 /// Convert reactivity to dollars
 type ReactivityToDollars = unsafe extern "C" fn(
     reactivity: f64,
@@ -175,9 +226,45 @@ type UpdateTemperatures = unsafe extern "C" fn(
     coolant_void_new: *mut f64,
 );
 
+/// Saturation temperature of water at given pressure
+type CalculateSaturationTemp = unsafe extern "C" fn(
+    pressure: f64,
+    t_sat: *mut f64,
+);
+
+/// Set coolant pressure used by the Fortran boiling threshold
+type SetCoolantPressure = unsafe extern "C" fn(
+    pressure: f64,
+);
+
+/// Set graphite gas-gap conductance used by the Fortran thermal models
+type SetGraphiteGapConductance = unsafe extern "C" fn(
+    conductance: f64,
+);
+
+type SetGraphiteCoolingEffectiveness = unsafe extern "C" fn(
+    effectiveness: f64,
+);
+
+type SetReactivitySmoothingTau = unsafe extern "C" fn(
+    tau: f64,
+);
+
+/// Select the fuel conductivity model of the per-channel thermal step
+type SetFuelConductivityModel = unsafe extern "C" fn(model: i32);
+
+/// Departure from nucleate boiling ratio of a channel
+type CalculateDnbr = unsafe extern "C" fn(
+    local_power: f64,
+    flow_rate: f64,
+    coolant_void: f64,
+    dnbr: *mut f64,
+);
+
 type CalculateRodWorth = unsafe extern "C" fn(
     rod_position: f64,
     max_worth: f64,
+    shape: f64,
     worth: *mut f64,
 );
 
@@ -211,22 +298,62 @@ type GetConstants = unsafe extern "C" fn(
     nominal_power: *mut f64,
 );
 
+/// Set prompt neutron lifetime used by all kinetics solvers
+type SetNeutronLifetime = unsafe extern "C" fn(lifetime: f64);
+
+type SetKineticsSolver = unsafe extern "C" fn(solver: i32);
+
 type ResetExplosionState = unsafe extern "C" fn();
 
+type GetExplosionState = unsafe extern "C" fn(
+    peak_power: *mut f64,
+    peak_fuel: *mut f64,
+    energy: *mut f64,
+    start_time: *mut f64,
+    excursion: *mut i32,
+);
+
+type SetExplosionState = unsafe extern "C" fn(
+    peak_power: f64,
+    peak_fuel: f64,
+    energy: f64,
+    start_time: f64,
+    excursion: i32,
+);
+
 /// Reset 6-group precursor state in simulation module
 type ResetPrecursors6GroupState = unsafe extern "C" fn();
 
+/// Restore saved 6-group precursor concentrations
+type SetPrecursors6GroupState = unsafe extern "C" fn(
+    precursors_in: *const f64,
+);
+
 /// Get current 6-group precursor concentrations
 type GetPrecursors6Group = unsafe extern "C" fn(
     precursors_out: *mut f64,
 );
 
+type GetLastSubstepCount = unsafe extern "C" fn(
+    count: *mut i32,
+);
+
 // ============================================================================
 // Library initialization
 // ============================================================================
 
 /// Initialize the Fortran library
 fn get_library() -> &'static Library {
+    load_library()
+        .expect("Failed to load rbmk_physics.dll! Make sure gfortran is installed and the DLL was built.")
+}
+
+/// Whether the Fortran library can be loaded
+pub fn library_available() -> bool {
+    load_library().is_some()
+}
+
+fn load_library() -> Option<&'static Library> {
     FORTRAN_LIB.get_or_init(|| {
         // Try multiple locations for the DLL
         let dll_paths = [
@@ -247,12 +374,11 @@ fn get_library() -> &'static Library {
         for path in dll_paths.iter().flatten() {
             if let Ok(lib) = unsafe { Library::new(path) } {
                 println!("Loaded Fortran library from: {}", path);
-                return lib;
+                return Some(lib);
             }
         }
-        
-        panic!("Failed to load rbmk_physics.dll! Make sure gfortran is installed and the DLL was built.");
-    })
+        None
+    }).as_ref()
 }
 
 // ============================================================================
@@ -401,6 +527,30 @@ pub fn update_axial_flux(n_points: usize, neutron_population: f64) -> Vec<f64> {
     flux
 }
 
+/// Update axial flux distribution with a top/bottom tilt
+///
+/// Point 0 is the bottom of the core. `tilt` > 0 shifts flux to the top
+/// (bottom suppressed by USP rods), `tilt` < 0 shifts it to the bottom.
+pub fn update_axial_flux_tilted(n_points: usize, neutron_population: f64, tilt: f64) -> Vec<f64> {
+    let lib = get_library();
+    let mut flux = vec![0.0f64; n_points];
+    
+    unsafe {
+        let func: Symbol<UpdateAxialFluxTilted> = lib
+            .get(b"update_axial_flux_tilted")
+            .expect("Failed to load update_axial_flux_tilted");
+        
+        func(
+            n_points as i32,
+            neutron_population,
+            tilt,
+            flux.as_mut_ptr(),
+        );
+    }
+    
+    flux
+}
+
 /// Calculate total reactivity with all feedback effects
 pub fn calc_reactivity(
     k_eff: f64,
@@ -430,6 +580,28 @@ pub fn calc_reactivity(
     reactivity
 }
 
+/// Steady-state reactivity the simulation relaxes toward for the given conditions
+pub fn calc_target_reactivity(
+    fuel_temp: f64,
+    graphite_temp: f64,
+    coolant_void: f64,
+    xenon_135: f64,
+    rod_worth: f64,
+) -> f64 {
+    let lib = get_library();
+    let mut reactivity: f64 = 0.0;
+    
+    unsafe {
+        let func: Symbol<CalculateTargetReactivity> = lib
+            .get(b"calculate_target_reactivity")
+            .expect("Failed to load calculate_target_reactivity");
+        
+        func(fuel_temp, graphite_temp, coolant_void, xenon_135, rod_worth, &mut reactivity);
+    }
+    
+    reactivity
+}
+
 /// Calculate total reactivity from all sources for simulation step
 pub fn calc_total_reactivity(
     fuel_temp: f64,
@@ -465,6 +637,72 @@ pub fn calc_total_reactivity(
     new_reactivity
 }
 
+/// Calculate xenon poisoning reactivity used by the point-kinetics model
+pub fn calc_xenon_reactivity(xenon_135: f64) -> f64 {
+    let lib = get_library();
+    let mut reactivity: f64 = 0.0;
+    
+    unsafe {
+        let func: Symbol<CalculateXenonReactivity> = lib
+            .get(b"calculate_xenon_reactivity")
+            .expect("Failed to load calculate_xenon_reactivity");
+        
+        func(xenon_135, &mut reactivity);
+    }
+    
+    reactivity
+}
+
+/// Calculate xenon poisoning reactivity used by the spatial (per-channel) model
+pub fn calc_local_xenon_reactivity(xenon_135: f64) -> f64 {
+    let lib = get_library();
+    let mut reactivity: f64 = 0.0;
+    
+    unsafe {
+        let func: Symbol<CalculateXenonReactivity> = lib
+            .get(b"calculate_local_xenon_reactivity")
+            .expect("Failed to load calculate_local_xenon_reactivity");
+        
+        func(xenon_135, &mut reactivity);
+    }
+    
+    reactivity
+}
+
+/// Calculate samarium poisoning reactivity used by the spatial (per-channel) model
+pub fn calc_local_samarium_reactivity(samarium_149: f64) -> f64 {
+    let lib = get_library();
+    let mut reactivity: f64 = 0.0;
+    
+    unsafe {
+        let func: Symbol<CalculateSamariumReactivity> = lib
+            .get(b"calculate_local_samarium_reactivity")
+            .expect("Failed to load calculate_local_samarium_reactivity");
+        
+        func(samarium_149, &mut reactivity);
+    }
+    
+    reactivity
+}
+
+/// Equilibrium (promethium_149, samarium_149) concentrations [atoms/cm³]
+/// at the given fraction of nominal power
+pub fn samarium_equilibrium(power_fraction: f64) -> (f64, f64) {
+    let lib = get_library();
+    let mut promethium_eq: f64 = 0.0;
+    let mut samarium_eq: f64 = 0.0;
+    
+    unsafe {
+        let func: Symbol<SamariumEquilibrium> = lib
+            .get(b"samarium_equilibrium")
+            .expect("Failed to load samarium_equilibrium");
+        
+        func(power_fraction, &mut promethium_eq, &mut samarium_eq);
+    }
+    
+    (promethium_eq, samarium_eq)
+}
+
 /// Solve point kinetics equations for one time step (simple Euler)
 pub fn solve_kinetics(
     n_neutrons: f64,
@@ -606,6 +844,29 @@ pub fn sum_precursors_6group(precursors_6: &[f64; NUM_DELAYED_GROUPS]) -> f64 {
     total
 }
 
+/// Advance 6-group precursor concentrations over one time step
+///
+/// Each group is integrated exactly with the neutron population held constant:
+/// Cᵢ(t+dt) = Cᵢ·e^(-λᵢ·dt) + βᵢ·n/(λᵢ·Λ)·(1 - e^(-λᵢ·dt))
+pub fn advance_precursors_6group(
+    n_neutrons: f64,
+    precursors_6: &[f64; NUM_DELAYED_GROUPS],
+    dt: f64,
+) -> [f64; NUM_DELAYED_GROUPS] {
+    let lib = get_library();
+    let mut precursors_new: [f64; NUM_DELAYED_GROUPS] = [0.0; NUM_DELAYED_GROUPS];
+    
+    unsafe {
+        let func: Symbol<AdvancePrecursors6Group> = lib
+            .get(b"advance_precursors_6group")
+            .expect("Failed to load advance_precursors_6group");
+        
+        func(n_neutrons, precursors_6.as_ptr(), dt, precursors_new.as_mut_ptr());
+    }
+    
+    precursors_new
+}
+
 /// Calculate reactor period from reactivity
 ///
 /// For delayed supercritical: T ≈ (β - ρ) / (λ_eff · ρ)
@@ -625,6 +886,24 @@ pub fn calculate_reactor_period(reactivity: f64) -> f64 {
     period
 }
 
+/// Reactivity [Δk/k] that sustains a stable period [s]
+///
+/// Solves the 6-group inhour equation: ρ = Λ/T + Σ βᵢ / (1 + λᵢ·T)
+pub fn reactivity_from_period(period: f64) -> f64 {
+    let lib = get_library();
+    let mut reactivity: f64 = 0.0;
+    
+    unsafe {
+        let func: Symbol<ReactivityFromPeriod> = lib
+            .get(b"reactivity_from_period")
+            .expect("Failed to load reactivity_from_period");
+        
+        func(period, &mut reactivity);
+    }
+    
+    reactivity
+}
+
 /// Convert reactivity to dollars (1$ = β_eff ≈ 0.0065)
 pub fn reactivity_to_dollars(reactivity: f64) -> f64 {
     let lib = get_library();
@@ -740,8 +1019,111 @@ pub fn update_temperatures(
     (fuel_temp_new, coolant_temp_new, graphite_temp_new, coolant_void_new)
 }
 
+/// Saturation temperature [K] of water at given pressure [MPa]
+/// (IAPWS-IF97 steam-table relation, ≈559 K at 7 MPa)
+pub fn saturation_temp(pressure_mpa: f64) -> f64 {
+    let lib = get_library();
+    let mut t_sat: f64 = 0.0;
+    
+    unsafe {
+        let func: Symbol<CalculateSaturationTemp> = lib
+            .get(b"calculate_saturation_temp")
+            .expect("Failed to load calculate_saturation_temp");
+        
+        func(pressure_mpa, &mut t_sat);
+    }
+    
+    t_sat
+}
+
+/// Departure from nucleate boiling ratio from channel power [MW], flow [kg/s] and void [%]
+/// Capped at 99 when the channel produces (almost) no heat.
+pub fn calc_dnbr(local_power: f64, flow_rate: f64, coolant_void: f64) -> f64 {
+    let lib = get_library();
+    let mut dnbr: f64 = 0.0;
+    
+    unsafe {
+        let func: Symbol<CalculateDnbr> = lib
+            .get(b"calculate_dnbr")
+            .expect("Failed to load calculate_dnbr");
+        
+        func(local_power, flow_rate, coolant_void, &mut dnbr);
+    }
+    
+    dnbr
+}
+
+/// Set the coolant pressure [MPa] used for the boiling threshold in Fortran
+pub fn set_coolant_pressure(pressure_mpa: f64) {
+    let lib = get_library();
+    
+    unsafe {
+        let func: Symbol<SetCoolantPressure> = lib
+            .get(b"set_coolant_pressure")
+            .expect("Failed to load set_coolant_pressure");
+        
+        func(pressure_mpa);
+    }
+}
+
+/// Set the graphite-to-channel gas-gap conductance (relative, 1.0 = nominal)
+/// used by both the point and spatial thermal models
+pub fn set_graphite_gap_conductance(conductance: f64) {
+    let lib = get_library();
+    
+    unsafe {
+        let func: Symbol<SetGraphiteGapConductance> = lib
+            .get(b"set_graphite_gap_conductance")
+            .expect("Failed to load set_graphite_gap_conductance");
+        
+        func(conductance);
+    }
+}
+
+/// Set the graphite gas-circuit cooling effectiveness (relative, 1.0 = nominal)
+/// used by both the point and spatial thermal models
+pub fn set_graphite_cooling_effectiveness(effectiveness: f64) {
+    let lib = get_library();
+    
+    unsafe {
+        let func: Symbol<SetGraphiteCoolingEffectiveness> = lib
+            .get(b"set_graphite_cooling_effectiveness")
+            .expect("Failed to load set_graphite_cooling_effectiveness");
+        
+        func(effectiveness);
+    }
+}
+
+/// Select the fuel thermal conductivity model (`FUEL_CONDUCTIVITY_*`)
+/// used by the per-channel thermal step of `spatial_simulation_step`
+pub fn set_fuel_conductivity_model(model: i32) {
+    let lib = get_library();
+    
+    unsafe {
+        let func: Symbol<SetFuelConductivityModel> = lib
+            .get(b"set_fuel_conductivity_model")
+            .expect("Failed to load set_fuel_conductivity_model");
+        
+        func(model);
+    }
+}
+
+/// Set the time constant of the reactivity smoothing outside a SCRAM [s]
+/// used by `calculate_total_reactivity`
+pub fn set_reactivity_smoothing_tau(tau: f64) {
+    let lib = get_library();
+    
+    unsafe {
+        let func: Symbol<SetReactivitySmoothingTau> = lib
+            .get(b"set_reactivity_smoothing_tau")
+            .expect("Failed to load set_reactivity_smoothing_tau");
+        
+        func(tau);
+    }
+}
+
 /// Calculate control rod worth based on position
-pub fn calc_rod_worth(rod_position: f64, max_worth: f64) -> f64 {
+pub fn calc_rod_worth(rod_position: f64, max_worth: f64, shape: f64) -> f64 {
     let lib = get_library();
     let mut worth: f64 = 0.0;
     
@@ -750,7 +1132,7 @@ pub fn calc_rod_worth(rod_position: f64, max_worth: f64) -> f64 {
             .get(b"calculate_rod_worth")
             .expect("Failed to load calculate_rod_worth");
         
-        func(rod_position, max_worth, &mut worth);
+        func(rod_position, max_worth, shape, &mut worth);
     }
     
     worth
@@ -850,32 +1232,144 @@ pub fn get_constants() -> (f64, f64, f64) {
     (beta_eff, neutron_lifetime, nominal_power)
 }
 
-/// Reset explosion tracking state in Fortran module
-/// This should be called when resetting the simulation
-pub fn reset_explosion_state() {
+/// Set prompt neutron lifetime [s] in the Fortran kinetics
+pub fn set_neutron_lifetime(lifetime: f64) {
+    let lib = get_library();
+    
+    unsafe {
+        let func: Symbol<SetNeutronLifetime> = lib
+            .get(b"set_neutron_lifetime")
+            .expect("Failed to load set_neutron_lifetime");
+        
+        func(lifetime);
+    }
+}
+
+/// Select the point kinetics solver used by `simulation_step` (`KINETICS_SOLVER_*`)
+pub fn set_kinetics_solver(solver: i32) {
     let lib = get_library();
     
+    unsafe {
+        let func: Symbol<SetKineticsSolver> = lib
+            .get(b"set_kinetics_solver")
+            .expect("Failed to load set_kinetics_solver");
+        
+        func(solver);
+    }
+}
+
+/// Reset explosion tracking state in Fortran module
+/// This should be called when resetting the simulation.
+/// Fails instead of panicking if the library lacks the symbol (older DLL).
+pub fn reset_explosion_state() -> Result<(), String> {
+    let lib = load_library().ok_or("Fortran library not loaded")?;
+    
     unsafe {
         let func: Symbol<ResetExplosionState> = lib
             .get(b"reset_explosion_state")
-            .expect("Failed to load reset_explosion_state");
+            .map_err(|e| format!("reset_explosion_state: {}", e))?;
         
         func();
     }
+    Ok(())
+}
+
+/// Explosion tracking history kept between steps by the safety module
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExplosionTracker {
+    pub peak_power_percent: f64,
+    pub peak_fuel_temp: f64,       // [K]
+    pub energy_deposited: f64,     // During the current excursion [MJ]
+    pub excursion_start_time: f64, // [s], negative outside an excursion
+    pub in_excursion: bool,
+}
+
+impl Default for ExplosionTracker {
+    /// State after `reset_explosion_state`
+    fn default() -> Self {
+        Self {
+            peak_power_percent: 0.0,
+            peak_fuel_temp: 300.0,
+            energy_deposited: 0.0,
+            excursion_start_time: -1.0,
+            in_excursion: false,
+        }
+    }
+}
+
+/// Read the explosion tracking state (for checkpoints)
+/// Fails instead of panicking if the library lacks the symbol (older DLL).
+pub fn get_explosion_state() -> Result<ExplosionTracker, String> {
+    let lib = load_library().ok_or("Fortran library not loaded")?;
+    let mut tracker = ExplosionTracker::default();
+    let mut excursion: i32 = 0;
+    
+    unsafe {
+        let func: Symbol<GetExplosionState> = lib
+            .get(b"get_explosion_state")
+            .map_err(|e| format!("get_explosion_state: {}", e))?;
+        
+        func(
+            &mut tracker.peak_power_percent,
+            &mut tracker.peak_fuel_temp,
+            &mut tracker.energy_deposited,
+            &mut tracker.excursion_start_time,
+            &mut excursion,
+        );
+    }
+    tracker.in_excursion = excursion != 0;
+    Ok(tracker)
+}
+
+/// Continue explosion tracking from a saved state (rewind)
+/// Fails instead of panicking if the library lacks the symbol (older DLL).
+pub fn set_explosion_state(tracker: &ExplosionTracker) -> Result<(), String> {
+    let lib = load_library().ok_or("Fortran library not loaded")?;
+    
+    unsafe {
+        let func: Symbol<SetExplosionState> = lib
+            .get(b"set_explosion_state")
+            .map_err(|e| format!("set_explosion_state: {}", e))?;
+        
+        func(
+            tracker.peak_power_percent,
+            tracker.peak_fuel_temp,
+            tracker.energy_deposited,
+            tracker.excursion_start_time,
+            i32::from(tracker.in_excursion),
+        );
+    }
+    Ok(())
 }
 
 /// Reset 6-group precursor state in Fortran simulation module
-/// This should be called when resetting the simulation to clear internal state
-pub fn reset_precursors_6group_state() {
-    let lib = get_library();
+/// This should be called when resetting the simulation to clear internal state.
+/// Fails instead of panicking if the library lacks the symbol (older DLL).
+pub fn reset_precursors_6group_state() -> Result<(), String> {
+    let lib = load_library().ok_or("Fortran library not loaded")?;
     
     unsafe {
         let func: Symbol<ResetPrecursors6GroupState> = lib
             .get(b"reset_precursors_6group_state")
-            .expect("Failed to load reset_precursors_6group_state");
+            .map_err(|e| format!("reset_precursors_6group_state: {}", e))?;
         
         func();
     }
+    Ok(())
+}
+
+/// Put saved 6-group precursor concentrations back into the Fortran
+/// simulation module, so the next step continues from them
+pub fn set_precursors_6group_state(precursors_6: &[f64; NUM_DELAYED_GROUPS]) {
+    let lib = get_library();
+    
+    unsafe {
+        let func: Symbol<SetPrecursors6GroupState> = lib
+            .get(b"set_precursors_6group_state")
+            .expect("Failed to load set_precursors_6group_state");
+        
+        func(precursors_6.as_ptr());
+    }
 }
 
 /// Get current 6-group precursor concentrations from Fortran simulation module
@@ -895,6 +1389,22 @@ pub fn get_precursors_6group() -> [f64; NUM_DELAYED_GROUPS] {
     precursors
 }
 
+/// Kinetics sub-steps taken by the last RK4 / 6-group solve
+pub fn get_last_substep_count() -> i32 {
+    let lib = get_library();
+    let mut count: i32 = 1;
+    
+    unsafe {
+        let func: Symbol<GetLastSubstepCount> = lib
+            .get(b"get_last_substep_count")
+            .expect("Failed to load get_last_substep_count");
+        
+        func(&mut count);
+    }
+    
+    count
+}
+
 // ============================================================================
 // Alert flag constants
 // ============================================================================
@@ -905,6 +1415,23 @@ pub const ALERT_PROMPT_CRITICAL: i32 = 4;
 pub const ALERT_FUEL_TEMP_HIGH: i32 = 8;
 pub const ALERT_VOID_HIGH: i32 = 16;
 pub const ALERT_SHORT_PERIOD: i32 = 32;
+pub const ALERT_GROWTH_CLAMPED: i32 = 64;
+
+// ============================================================================
+// Kinetics solver codes (see set_kinetics_solver)
+// ============================================================================
+
+pub const KINETICS_SOLVER_SIX_GROUP: i32 = 0;
+pub const KINETICS_SOLVER_EULER: i32 = 1;
+pub const KINETICS_SOLVER_RK4: i32 = 2;
+pub const KINETICS_SOLVER_PROMPT_JUMP: i32 = 3;
+
+// ============================================================================
+// Fuel conductivity model codes (see set_fuel_conductivity_model)
+// ============================================================================
+
+pub const FUEL_CONDUCTIVITY_CONSTANT: i32 = 0;
+pub const FUEL_CONDUCTIVITY_BURNUP: i32 = 1;
 
 // ============================================================================
 // Spatial physics types and functions (2D diffusion)
@@ -931,7 +1458,12 @@ type SpatialSimulationStep = unsafe extern "C" fn(
     coolant_void_in: *const f64,
     iodine_in: *const f64,
     xenon_in: *const f64,
+    promethium_in: *const f64,
+    samarium_in: *const f64,
     local_rod_worth_in: *const f64,
+    enrichment_in: *const f64,
+    burnup_in: *const f64,
+    inlet_temp_in: *const f64,
     // Neighbor connectivity
     neighbor_indices: *const i32,
     num_neighbors: *const i32,
@@ -947,6 +1479,8 @@ type SpatialSimulationStep = unsafe extern "C" fn(
     coolant_void_out: *mut f64,
     iodine_out: *mut f64,
     xenon_out: *mut f64,
+    promethium_out: *mut f64,
+    samarium_out: *mut f64,
     local_power_out: *mut f64,
     local_reactivity_out: *mut f64,
 );
@@ -988,7 +1522,12 @@ pub struct SpatialChannelInput {
     pub coolant_void: f64,
     pub iodine: f64,
     pub xenon: f64,
+    pub promethium: f64,
+    pub samarium: f64,
     pub local_rod_worth: f64,
+    pub enrichment: f64,  // U-235 enrichment [%]
+    pub burnup: f64,      // [MWd/kgU]
+    pub inlet_temp: f64,  // Coolant inlet temperature [K]
     pub x: f64,
     pub y: f64,
     pub neighbors: Vec<i32>,  // Indices of neighbors (-1 for no neighbor)
@@ -1005,6 +1544,8 @@ pub struct SpatialChannelOutput {
     pub coolant_void: f64,
     pub iodine: f64,
     pub xenon: f64,
+    pub promethium: f64,
+    pub samarium: f64,
     pub local_power: f64,
     pub local_reactivity: f64,
 }
@@ -1045,7 +1586,12 @@ pub fn spatial_simulation_step(
     let mut coolant_void_in = Vec::with_capacity(num_channels);
     let mut iodine_in = Vec::with_capacity(num_channels);
     let mut xenon_in = Vec::with_capacity(num_channels);
+    let mut promethium_in = Vec::with_capacity(num_channels);
+    let mut samarium_in = Vec::with_capacity(num_channels);
     let mut local_rod_worth_in = Vec::with_capacity(num_channels);
+    let mut enrichment_in = Vec::with_capacity(num_channels);
+    let mut burnup_in = Vec::with_capacity(num_channels);
+    let mut inlet_temp_in = Vec::with_capacity(num_channels);
     let mut channel_x = Vec::with_capacity(num_channels);
     let mut channel_y = Vec::with_capacity(num_channels);
     let mut neighbor_indices = vec![-1i32; num_channels * MAX_NEIGHBORS];
@@ -1060,7 +1606,12 @@ pub fn spatial_simulation_step(
         coolant_void_in.push(ch.coolant_void);
         iodine_in.push(ch.iodine);
         xenon_in.push(ch.xenon);
+        promethium_in.push(ch.promethium);
+        samarium_in.push(ch.samarium);
         local_rod_worth_in.push(ch.local_rod_worth);
+        enrichment_in.push(ch.enrichment);
+        burnup_in.push(ch.burnup);
+        inlet_temp_in.push(ch.inlet_temp);
         channel_x.push(ch.x);
         channel_y.push(ch.y);
         
@@ -1081,6 +1632,8 @@ pub fn spatial_simulation_step(
     let mut coolant_void_out = vec![0.0f64; num_channels];
     let mut iodine_out = vec![0.0f64; num_channels];
     let mut xenon_out = vec![0.0f64; num_channels];
+    let mut promethium_out = vec![0.0f64; num_channels];
+    let mut samarium_out = vec![0.0f64; num_channels];
     let mut local_power_out = vec![0.0f64; num_channels];
     let mut local_reactivity_out = vec![0.0f64; num_channels];
     
@@ -1102,7 +1655,12 @@ pub fn spatial_simulation_step(
             coolant_void_in.as_ptr(),
             iodine_in.as_ptr(),
             xenon_in.as_ptr(),
+            promethium_in.as_ptr(),
+            samarium_in.as_ptr(),
             local_rod_worth_in.as_ptr(),
+            enrichment_in.as_ptr(),
+            burnup_in.as_ptr(),
+            inlet_temp_in.as_ptr(),
             neighbor_indices.as_ptr(),
             num_neighbors_arr.as_ptr(),
             channel_x.as_ptr(),
@@ -1115,6 +1673,8 @@ pub fn spatial_simulation_step(
             coolant_void_out.as_mut_ptr(),
             iodine_out.as_mut_ptr(),
             xenon_out.as_mut_ptr(),
+            promethium_out.as_mut_ptr(),
+            samarium_out.as_mut_ptr(),
             local_power_out.as_mut_ptr(),
             local_reactivity_out.as_mut_ptr(),
         );
@@ -1132,6 +1692,8 @@ pub fn spatial_simulation_step(
             coolant_void: coolant_void_out[i],
             iodine: iodine_out[i],
             xenon: xenon_out[i],
+            promethium: promethium_out[i],
+            samarium: samarium_out[i],
             local_power: local_power_out[i],
             local_reactivity: local_reactivity_out[i],
         });
@@ -1236,6 +1798,43 @@ mod tests {
         assert!(rho.abs() < 0.1);
     }
     
+    #[test]
+    fn test_temperature_feedback_signs() {
+        // Needs the physics library (absent on pure-Rust builds)
+        if !library_available() {
+            return;
+        }
+        // Typical full-power conditions, rods out
+        let (fuel, graphite, void, xenon) = (900.0, 650.0, 20.0, 0.0);
+        let base = calc_target_reactivity(fuel, graphite, void, xenon, 0.0);
+        
+        // Doppler: hotter fuel is less reactive (α_fuel ≈ -5e-5 /K)
+        let doppler = calc_target_reactivity(fuel + 100.0, graphite, void, xenon, 0.0) - base;
+        assert!(doppler < 0.0);
+        assert!((doppler / 100.0 + 5.0e-5).abs() < 2.5e-5);
+        
+        // Void: positive coefficient in the RBMK (α_void ≈ +1e-4 /%)
+        let void_effect = calc_target_reactivity(fuel, graphite, void + 10.0, xenon, 0.0) - base;
+        assert!(void_effect > 0.0);
+        assert!((void_effect / 10.0 - 1.0e-4).abs() < 0.5e-4);
+        
+        // +10% power heats the fuel ~50 K and the graphite ~25 K and adds a
+        // few % void: the net power coefficient must still be negative
+        let net = calc_target_reactivity(fuel + 50.0, graphite + 25.0, void + 5.0, xenon, 0.0) - base;
+        assert!(net < 0.0);
+    }
+    
+    #[test]
+    fn test_saturation_temp() {
+        // Needs the physics library (absent on pure-Rust builds)
+        if !library_available() {
+            return;
+        }
+        // Steam tables: 100 °C at 0.101 MPa, ~285.8 °C at 7 MPa
+        assert!((saturation_temp(0.101325) - 373.12).abs() < 0.5);
+        assert!((saturation_temp(7.0) - 558.98).abs() < 0.5);
+    }
+    
     #[test]
     fn test_kinetics() {
         let (n, c) = solve_kinetics(1.0, 0.0065, 0.0, 0.1);
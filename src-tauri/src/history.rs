@@ -0,0 +1,157 @@
+//! Per-channel time histories
+//!
+//! The UI keeps the global trend plots itself; this module records the
+//! transient of a few selected fuel channels, sampled once per spatial step
+//! into fixed-size ring buffers, so a single misbehaving channel can be
+//! studied on its own. It also keeps the k_eff history of either stepping
+//! mode, which the UI cannot rebuild from its power trend.
+
+use std::collections::{BTreeMap, VecDeque};
+
+use serde::{Deserialize, Serialize};
+
+use crate::reactor::FuelChannel;
+
+/// Samples kept per tracked channel (oldest dropped first)
+pub const CHANNEL_HISTORY_LENGTH: usize = 1000;
+
+/// Channels that can be tracked at once
+pub const MAX_TRACKED_CHANNELS: usize = 8;
+
+/// Samples kept in the k_eff history (oldest dropped first)
+pub const KEFF_HISTORY_LENGTH: usize = 3600;
+
+/// One sample of a tracked channel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelSample {
+    pub time: f64,          // Simulation time [s]
+    pub local_power: f64,   // Channel thermal power [MW]
+    pub fuel_temp: f64,     // Fuel temperature [K]
+    pub coolant_void: f64,  // Void fraction [%]
+    pub xenon_135: f64,     // Xe-135 concentration [atoms/cm³]
+}
+
+/// One sample of the core multiplication factor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeffSample {
+    pub time: f64,                  // Simulation time [s]
+    pub k_eff: f64,                 // Effective multiplication factor
+    pub average_rod_position: f64,  // Mean rod position (0 = inserted, 1 = withdrawn)
+}
+
+/// Ring buffer of k_eff, one sample per step
+#[derive(Debug, Clone, Default)]
+pub struct KeffHistory {
+    samples: VecDeque<KeffSample>,
+}
+
+impl KeffHistory {
+    pub fn record(&mut self, time: f64, k_eff: f64, average_rod_position: f64) {
+        if self.samples.len() == KEFF_HISTORY_LENGTH {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(KeffSample { time, k_eff, average_rod_position });
+    }
+
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Samples oldest first
+    pub fn samples(&self) -> Vec<KeffSample> {
+        self.samples.iter().cloned().collect()
+    }
+}
+
+/// Ring buffers of the tracked channels, keyed by channel id
+#[derive(Debug, Clone, Default)]
+pub struct ChannelHistoryRecorder {
+    buffers: BTreeMap<usize, VecDeque<ChannelSample>>,
+}
+
+impl ChannelHistoryRecorder {
+    /// Start tracking a channel (no-op if already tracked)
+    pub fn track(&mut self, channel_id: usize) -> Result<(), String> {
+        if self.buffers.contains_key(&channel_id) {
+            return Ok(());
+        }
+        if self.buffers.len() >= MAX_TRACKED_CHANNELS {
+            return Err(format!("Already tracking {} channels (maximum)", MAX_TRACKED_CHANNELS));
+        }
+        self.buffers.insert(channel_id, VecDeque::with_capacity(CHANNEL_HISTORY_LENGTH));
+        Ok(())
+    }
+
+    /// Stop tracking a channel and drop its samples
+    /// Returns false if the channel was not tracked.
+    pub fn untrack(&mut self, channel_id: usize) -> bool {
+        self.buffers.remove(&channel_id).is_some()
+    }
+
+    /// Ids of the tracked channels in ascending order
+    pub fn tracked(&self) -> Vec<usize> {
+        self.buffers.keys().copied().collect()
+    }
+
+    /// Append a sample for every tracked channel
+    pub fn record(&mut self, time: f64, channels: &[FuelChannel]) {
+        for (&channel_id, buffer) in self.buffers.iter_mut() {
+            let Some(channel) = channels.get(channel_id) else { continue };
+            if buffer.len() == CHANNEL_HISTORY_LENGTH {
+                buffer.pop_front();
+            }
+            buffer.push_back(ChannelSample {
+                time,
+                local_power: channel.local_power,
+                fuel_temp: channel.fuel_temp,
+                coolant_void: channel.coolant_void,
+                xenon_135: channel.xenon_135,
+            });
+        }
+    }
+
+    /// Samples of a tracked channel, oldest first (None if not tracked)
+    pub fn history(&self, channel_id: usize) -> Option<Vec<ChannelSample>> {
+        self.buffers.get(&channel_id).map(|buffer| buffer.iter().cloned().collect())
+    }
+
+    /// Drop all samples but keep tracking the same channels (on reset)
+    pub fn clear_samples(&mut self) {
+        self.buffers.values_mut().for_each(VecDeque::clear);
+    }
+
+    /// Stop tracking everything (channel ids change with the layout)
+    pub fn clear(&mut self) {
+        self.buffers.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ring_buffer_keeps_latest_samples() {
+        let channels: Vec<FuelChannel> = crate::reactor::ReactorSimulator::builder()
+            .fallback_grid(9)
+            .build()
+            .unwrap()
+            .get_fuel_channels();
+        let mut recorder = ChannelHistoryRecorder::default();
+        recorder.track(0).unwrap();
+
+        for step in 0..CHANNEL_HISTORY_LENGTH + 5 {
+            recorder.record(step as f64, &channels);
+        }
+        let history = recorder.history(0).unwrap();
+        assert_eq!(history.len(), CHANNEL_HISTORY_LENGTH);
+        assert_eq!(history[0].time, 5.0);
+        assert!(recorder.history(1).is_none());
+
+        for id in 1..MAX_TRACKED_CHANNELS {
+            recorder.track(id).unwrap();
+        }
+        assert!(recorder.track(MAX_TRACKED_CHANNELS).is_err());
+        assert!(recorder.track(0).is_ok());
+    }
+}
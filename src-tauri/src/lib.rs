@@ -3,9 +3,23 @@
 //! This library provides nuclear physics simulation for RBMK-1000 reactor
 //! using Fortran for core calculations and Rust for application logic.
 
+pub mod alerts;
+pub mod backend;
+pub mod blackbox;
+pub mod checkpoint;
+pub mod faults;
 pub mod fortran_ffi;
+pub mod history;
+pub mod pure_rust_backend;
 pub mod reactor;
+pub mod stability;
+pub mod startup;
 pub mod commands;
+pub mod delta_stream;
+pub mod state_stream;
+pub mod timing;
+#[cfg(feature = "server")]
+pub mod server;
 
-pub use reactor::{ReactorSimulator, ReactorState};
+pub use reactor::{ReactorSimulator, ReactorSimulatorBuilder, ReactorState};
 pub use commands::SimulatorState;
@@ -15,6 +15,25 @@ fn main() {
     // Create reactor simulator
     let simulator = Arc::new(ReactorSimulator::new());
     
+    // Headless mode: `rbmk-simulator --server [addr]` serves HTTP/WebSocket instead of the UI
+    #[cfg(feature = "server")]
+    {
+        let args: Vec<String> = std::env::args().collect();
+        if let Some(i) = args.iter().position(|a| a == "--server") {
+            let addr = args
+                .get(i + 1)
+                .map(String::as_str)
+                .unwrap_or("127.0.0.1:8080")
+                .parse()
+                .expect("Invalid server address");
+            tokio::runtime::Runtime::new()
+                .expect("Failed to start async runtime")
+                .block_on(rbmk_simulator_lib::server::serve(simulator, addr))
+                .expect("Server error");
+            return;
+        }
+    }
+    
     // Build and run Tauri application
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
@@ -24,20 +43,135 @@ fn main() {
             simulation_step,
             simulation_run,
             simulation_realtime,
+            get_max_safe_time_speed,
+            simulation_step_diff,
+            get_state_delta,
             scram,
             reset_scram,
+            get_scram_insertion_time,
+            set_scram_insertion_time,
+            get_reactivity_smoothing_tau,
+            set_reactivity_smoothing_tau,
+            get_plenum_mixing_time,
+            set_plenum_mixing_time,
+            get_inlet_plenum_temp,
+            get_scram_readiness,
+            validate_rod_worth,
+            get_rod_worth_summary,
+            get_axial_power_offset,
+            get_radial_profile,
+            get_burnup_stats,
+            get_fresh_channels,
+            get_time_to_trip,
+            get_trip_status,
+            set_trip_bypass,
+            get_active_alerts,
+            get_alert_catalog,
+            arm_random_fault,
+            get_fault_drill_status,
+            set_alert_hold_time,
+            get_blackbox_snapshot,
+            export_blackbox_snapshot,
+            read_blackbox_snapshot,
+            rewind,
+            set_checkpoint_count,
+            get_checkpoint_times,
+            set_rod_stuck,
+            set_rod_travel_limits,
+            get_rod_travel_limits,
+            get_active_interlocks,
+            describe_controls,
             move_control_rod,
             move_rod_group,
             move_rod_group_by_channel_type,
+            predict_critical_position,
+            get_rod_group_sensitivity,
+            auto_flatten,
+            equilibrium_rod_position,
+            get_shutdown_margin,
+            measure_rod_worth_increment,
+            step_response,
+            record_1m_point,
+            get_1m_curve,
+            clear_1m_curve,
             move_control_rod_by_position,
             get_control_rods,
             get_fuel_channels,
+            get_frame,
+            get_channel_neighbors,
+            get_min_dnbr,
+            get_subcooling_margin,
+            query_channels,
+            track_channel,
+            untrack_channel,
+            get_channel_history,
+            get_keff_history,
+            get_detector_signals,
+            set_detector_fault,
+            get_lar_zones,
+            get_saturation_temp,
+            rupture_channel,
+            get_ruptured_channels,
+            get_clamped_channels,
+            set_channel_power_ceiling,
+            get_channel_volume,
+            set_channel_volume,
+            get_physics_constants,
+            get_metadata,
+            set_metadata,
+            get_config_hash,
+            get_grid_index,
+            set_reactivity_display_unit,
+            get_criticality_state,
+            classify_outcome,
+            get_event_times,
+            get_operating_point,
+            get_last_step_timing,
+            get_efpd,
+            get_efph,
+            set_neutron_lifetime,
+            reactivity_from_period,
+            set_graphite_gap_conductance,
+            set_graphite_cooling_effectiveness,
+            set_kinetics_solver,
+            get_kinetics_solver,
+            set_fuel_conductivity_model,
+            get_fuel_conductivity_model,
+            get_effective_time_step,
+            get_reactivity_balance_residual,
+            mark_reactivity_reference,
+            get_reactivity_since_reference,
+            get_stability_status,
+            set_auto_reduce_time_step,
+            get_rod_depletion,
+            set_absorber_depletion_rate,
+            set_rod_worth_shape,
+            get_rod_worth_curve,
+            get_simulation_parameters,
+            set_simulation_parameters,
             set_time_step,
             reset_simulation,
+            restart_hot,
+            get_skipped_resets,
+            set_samarium_equilibrium,
+            fast_forward_xenon,
+            get_xenon_projection,
+            get_delayed_source_breakdown,
+            set_feedwater_rate,
+            set_coolant_inventory,
+            list_layouts,
+            get_active_layout,
+            load_layout_named,
+            get_layout_warnings,
             get_3d_data,
             // Automatic regulator (AR/LAR) commands
             set_auto_regulator_enabled,
+            set_freeze_thermal,
+            set_freeze_xenon,
+            get_power_balance,
+            set_power_normalization,
             set_target_power,
+            get_target_power_range,
             get_auto_regulator,
         ])
         .run(tauri::generate_context!())
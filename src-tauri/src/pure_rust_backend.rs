@@ -0,0 +1,497 @@
+//! Pure-Rust fallback physics
+//!
+//! A reduced-fidelity core model for machines without the Fortran library
+//! (quick demos, CI without gfortran): 6-group point kinetics integrated with
+//! RK4, the lumped first-order thermal model with the same feedback
+//! coefficients, and I-135/Xe-135 dynamics. The constants mirror
+//! `rbmk_constants.f90`.
+//!
+//! `ReactorSimulatorBuilder` picks this backend with a warning when the
+//! library cannot be loaded, and always with the `pure-rust` feature (which
+//! also skips the Fortran build). Only `ReactorSimulator::step` runs on it:
+//! the 2D spatial solver and diagnostics that call Fortran directly still
+//! need the library; without it `ReactorSimulator::step_spatial` falls back
+//! to `step`. Tuning sent straight to Fortran (prompt neutron lifetime,
+//! graphite gap conductance and gas cooling, reactivity smoothing time
+//! constant) does not apply here and its setters fail; the kinetics solver
+//! choice is ignored.
+
+use std::sync::Mutex;
+
+use crate::backend::{PhysicsBackend, PointKineticsInput};
+use crate::fortran_ffi::{self, ExplosionTracker, SimulationStepResult, NUM_DELAYED_GROUPS};
+use crate::reactor::constants::{
+    BETA_EFF, BETA_I, LAMBDA_I, NEUTRON_LIFETIME, NOMINAL_POWER_MW, POPULATION_GROWTH_FLOOR,
+    POPULATION_GROWTH_MARGIN, POPULATION_GROWTH_MAX_EXPONENT, REACTIVITY_CEILING,
+    REACTIVITY_FLOOR,
+};
+
+// Reactivity feedback
+const ALPHA_FUEL: f64 = -5.0e-5;      // Doppler [1/K] (negative feedback only)
+const ALPHA_VOID: f64 = 1.0e-4;       // Void [1/%]
+const ALPHA_GRAPHITE: f64 = 1.0e-5;   // Graphite temperature [1/K]
+const REF_FUEL_TEMP: f64 = 900.0;     // [K]
+const REF_GRAPHITE_TEMP: f64 = 650.0; // [K]
+const BASE_REACTIVITY: f64 = 0.08;    // Excess reactivity of the clean core [Δk/k]
+const XENON_REACTIVITY_PER_ATOM: f64 = -1.5e-16; // [Δk/k per atom/cm³]
+
+// Lumped thermal model
+const COOLANT_TIME_CONST: f64 = 3.0;    // [s]
+const GRAPHITE_TIME_CONST: f64 = 60.0;  // [s]
+const VOID_TIME_CONST: f64 = 2.0;       // [s]
+const NOMINAL_PRESSURE_MPA: f64 = 7.0;  // Tsat ≈ 559 K
+const FUEL_MELTING_POINT: f64 = 2800.0; // [K]
+
+// Xenon chain
+const NOMINAL_FISSION_RATE: f64 = 5.3e11; // [fissions/cm³/s] at 100 % power
+const NOMINAL_FLUX: f64 = 1.0e14;         // [n/cm²/s] at 100 % power
+const GAMMA_IODINE: f64 = 0.061;
+const GAMMA_XE: f64 = 0.003;
+const LAMBDA_IODINE: f64 = 2.87e-5; // [1/s]
+const LAMBDA_XE: f64 = 2.09e-5;     // [1/s]
+const SIGMA_XE: f64 = 2.65e-18;     // [cm²]
+
+/// State carried between steps
+#[derive(Debug, Clone)]
+struct FallbackState {
+    precursors_6: [f64; NUM_DELAYED_GROUPS],
+    precursors_initialized: bool,
+    coolant_pressure_mpa: f64,
+    peak_fuel_temp: f64, // Highest fuel temperature seen, drives the damage index
+    last_substeps: usize, // Kinetics sub-steps of the last step
+}
+
+impl Default for FallbackState {
+    fn default() -> Self {
+        Self {
+            precursors_6: [0.0; NUM_DELAYED_GROUPS],
+            precursors_initialized: false,
+            coolant_pressure_mpa: NOMINAL_PRESSURE_MPA,
+            peak_fuel_temp: 0.0,
+            last_substeps: 1,
+        }
+    }
+}
+
+/// Core-level physics without the Fortran library
+#[derive(Debug, Default)]
+pub struct PureRustBackend {
+    state: Mutex<FallbackState>,
+}
+
+impl PhysicsBackend for PureRustBackend {
+    fn name(&self) -> &str {
+        "pure-rust"
+    }
+
+    fn simulation_step(&self, input: &PointKineticsInput) -> SimulationStepResult {
+        let mut backend_state = self.state.lock().unwrap();
+        let dt = input.dt;
+        let n = input.neutron_population;
+
+        if !backend_state.precursors_initialized || input.precursors < 1e-10 {
+            backend_state.precursors_6 = equilibrium_precursors(n);
+            backend_state.precursors_initialized = true;
+        }
+
+        let reactivity = self.total_reactivity(input);
+        let k_eff = if reactivity.abs() < 0.99 {
+            1.0 / (1.0 - reactivity)
+        } else if reactivity > 0.0 {
+            100.0
+        } else {
+            0.01
+        };
+
+        // Small external source for subcritical startup
+        let source = if n < 1e-4 { 1e-8 } else { 0.0 };
+        let (n_new, precursors_6) = solve_kinetics_6group(n, &backend_state.precursors_6, reactivity, source, dt);
+        let (n_new, growth_clamped) = bound_population_growth(n, n_new, dt);
+        backend_state.precursors_6 = precursors_6;
+        backend_state.last_substeps = kinetics_substeps(reactivity, dt);
+
+        let power_mw = (NOMINAL_POWER_MW * n_new).max(0.0);
+        let power_percent = power_mw / NOMINAL_POWER_MW * 100.0;
+
+        let t_sat = saturation_temp(backend_state.coolant_pressure_mpa);
+        let current = ThermalState {
+            fuel_temp: input.fuel_temp,
+            coolant_temp: input.coolant_temp,
+            graphite_temp: input.graphite_temp,
+            coolant_void: input.coolant_void,
+        };
+        let thermal = update_temperatures(power_percent, &current, dt, t_sat);
+
+        // No fission (and no xenon production or burnout) below 0.1 % power
+        let flux = if power_percent > 0.1 { n_new * NOMINAL_FLUX } else { 0.0 };
+        let (iodine_135, xenon_135) = xenon_step(input.iodine_135, input.xenon_135, flux, dt);
+
+        let dn_dt = (n_new - n) / dt;
+        let period = if n > 1e-10 && dn_dt.abs() > 1e-10 && (n / dn_dt).abs() <= 1e6 {
+            n / dn_dt
+        } else {
+            1e30
+        };
+
+        // Damage index from the peak fuel temperature only
+        backend_state.peak_fuel_temp = backend_state.peak_fuel_temp.max(thermal.fuel_temp);
+        let melt_onset = 0.9 * FUEL_MELTING_POINT;
+        let explosion_severity = if backend_state.peak_fuel_temp > FUEL_MELTING_POINT {
+            2.0
+        } else {
+            ((backend_state.peak_fuel_temp - melt_onset) / (FUEL_MELTING_POINT - melt_onset)).max(0.0)
+        };
+
+        let reactivity_dollars = reactivity / BETA_EFF;
+        let mut alert_flags = alert_flags(power_percent, reactivity_dollars, thermal.fuel_temp, thermal.coolant_void, period);
+        if growth_clamped {
+            alert_flags |= fortran_ffi::ALERT_GROWTH_CLAMPED;
+        }
+
+        SimulationStepResult {
+            neutron_population: n_new,
+            precursors: precursors_6.iter().sum(),
+            fuel_temp: thermal.fuel_temp,
+            coolant_temp: thermal.coolant_temp,
+            graphite_temp: thermal.graphite_temp,
+            coolant_void: thermal.coolant_void,
+            iodine_135,
+            xenon_135,
+            reactivity,
+            k_eff,
+            power_mw,
+            power_percent,
+            period,
+            explosion_severity,
+            alert_flags,
+        }
+    }
+
+    fn total_reactivity(&self, input: &PointKineticsInput) -> f64 {
+        let target = target_reactivity(
+            input.fuel_temp,
+            input.graphite_temp,
+            input.coolant_void,
+            input.xenon_135,
+            input.total_rod_worth,
+        );
+        smooth_reactivity(target, input.smoothed_reactivity, input.dt, input.scram_active)
+    }
+
+    fn precursors_6group(&self) -> [f64; NUM_DELAYED_GROUPS] {
+        self.state.lock().unwrap().precursors_6
+    }
+
+    fn restore_precursors_6group(&self, precursors_6: &[f64; NUM_DELAYED_GROUPS]) {
+        let mut state = self.state.lock().unwrap();
+        state.precursors_6 = *precursors_6;
+        state.precursors_initialized = true;
+    }
+
+    fn last_substep_count(&self) -> usize {
+        self.state.lock().unwrap().last_substeps
+    }
+
+    fn target_reactivity(&self, fuel_temp: f64, graphite_temp: f64, coolant_void: f64, xenon_135: f64, rod_worth: f64) -> f64 {
+        target_reactivity(fuel_temp, graphite_temp, coolant_void, xenon_135, rod_worth)
+    }
+
+    fn xenon_reactivity(&self, xenon_135: f64) -> f64 {
+        XENON_REACTIVITY_PER_ATOM * xenon_135
+    }
+
+    fn xenon_step(&self, iodine_135: f64, xenon_135: f64, flux: f64, dt: f64) -> (f64, f64) {
+        xenon_step(iodine_135, xenon_135, flux, dt)
+    }
+
+    fn update_temperatures(
+        &self,
+        power_percent: f64,
+        fuel_temp: f64,
+        coolant_temp: f64,
+        graphite_temp: f64,
+        coolant_void: f64,
+        dt: f64,
+    ) -> (f64, f64, f64, f64) {
+        let t_sat = saturation_temp(self.state.lock().unwrap().coolant_pressure_mpa);
+        let current = ThermalState { fuel_temp, coolant_temp, graphite_temp, coolant_void };
+        let thermal = update_temperatures(power_percent, &current, dt, t_sat);
+        (thermal.fuel_temp, thermal.coolant_temp, thermal.graphite_temp, thermal.coolant_void)
+    }
+
+    fn kinetics_constants(&self) -> (f64, f64) {
+        (BETA_EFF, NEUTRON_LIFETIME)
+    }
+
+    fn applies_fortran_tuning(&self) -> bool {
+        false
+    }
+
+    fn reactivity_from_period(&self, period: f64) -> f64 {
+        let delayed: f64 = (0..NUM_DELAYED_GROUPS).map(|g| BETA_I[g] / (1.0 + LAMBDA_I[g] * period)).sum();
+        NEUTRON_LIFETIME / period + delayed
+    }
+
+    fn axial_flux(&self, n_points: usize, neutron_population: f64, tilt: f64) -> Vec<f64> {
+        let center = n_points as f64 / 2.0;
+        let tilt = tilt.clamp(-0.9, 0.9);
+        (1..=n_points)
+            .map(|i| {
+                let z = (i as f64 - center) / center; // -1 (bottom) to 1 (top)
+                neutron_population * (1.0 - z * z).max(0.0) * (1.0 + tilt * z)
+            })
+            .collect()
+    }
+
+    fn rod_worth(&self, rod_position: f64, max_worth: f64, shape: f64) -> f64 {
+        let mut position = rod_position.clamp(0.0, 1.0);
+        if shape > 0.0 {
+            position = position.powf(shape);
+        }
+        max_worth * (1.0 - (std::f64::consts::FRAC_PI_2 * position).sin().powi(2))
+    }
+
+    fn set_coolant_pressure(&self, pressure_mpa: f64) {
+        self.state.lock().unwrap().coolant_pressure_mpa = pressure_mpa;
+    }
+
+    fn saturation_temp(&self, pressure_mpa: f64) -> f64 {
+        saturation_temp(pressure_mpa)
+    }
+
+    fn explosion_tracker(&self) -> Option<ExplosionTracker> {
+        // Only the peak fuel temperature feeds the damage index here
+        let peak_fuel_temp = self.state.lock().unwrap().peak_fuel_temp;
+        Some(ExplosionTracker { peak_fuel_temp, ..ExplosionTracker::default() })
+    }
+
+    fn restore_explosion_tracker(&self, tracker: &ExplosionTracker) {
+        self.state.lock().unwrap().peak_fuel_temp = tracker.peak_fuel_temp;
+    }
+
+    fn reset(&self) -> Vec<String> {
+        *self.state.lock().unwrap() = FallbackState::default();
+        Vec::new()
+    }
+}
+
+/// Steady-state reactivity for the given conditions [Δk/k]
+fn target_reactivity(fuel_temp: f64, graphite_temp: f64, coolant_void: f64, xenon_135: f64, rod_worth: f64) -> f64 {
+    let doppler = (ALPHA_FUEL * (fuel_temp - REF_FUEL_TEMP)).min(0.0);
+    let graphite = ALPHA_GRAPHITE * (graphite_temp - REF_GRAPHITE_TEMP);
+    let void = ALPHA_VOID * coolant_void;
+    BASE_REACTIVITY + doppler + graphite + void + XENON_REACTIVITY_PER_ATOM * xenon_135 - rod_worth
+}
+
+/// Relax toward the target with a rate limit, as `calculate_total_reactivity` does
+fn smooth_reactivity(target: f64, previous: f64, dt: f64, scram_active: bool) -> f64 {
+    let (tau, max_rate) = if scram_active { (0.05, 0.05) } else { (0.5, 0.01) };
+    let proposed = previous + (dt / tau).min(1.0) * (target - previous);
+    let limited = previous + (proposed - previous).clamp(-max_rate * dt, max_rate * dt);
+    limited.clamp(REACTIVITY_FLOOR, REACTIVITY_CEILING)
+}
+
+/// Limit single-step population growth as the Fortran `bound_population_growth`
+/// does; returns the population and whether it was clamped
+fn bound_population_growth(n_old: f64, n_new: f64, dt: f64) -> (f64, bool) {
+    let exponent = (POPULATION_GROWTH_MARGIN * REACTIVITY_CEILING * dt / NEUTRON_LIFETIME)
+        .min(POPULATION_GROWTH_MAX_EXPONENT);
+    let n_max = n_old.max(POPULATION_GROWTH_FLOOR) * exponent.exp();
+    if n_new.is_nan() || n_new > n_max {
+        (n_max, true)
+    } else {
+        (n_new, false)
+    }
+}
+
+/// Precursor concentrations in equilibrium with population `n`
+fn equilibrium_precursors(n: f64) -> [f64; NUM_DELAYED_GROUPS] {
+    std::array::from_fn(|g| BETA_I[g] * n / (LAMBDA_I[g] * NEUTRON_LIFETIME))
+}
+
+/// RK4 sub-steps for `dt`: finer near prompt critical and during a SCRAM
+fn kinetics_substeps(reactivity: f64, dt: f64) -> usize {
+    let rho = reactivity.clamp(-0.15, 0.02);
+    let max_substep = if rho < -0.01 {
+        0.005
+    } else if rho.abs() > BETA_EFF {
+        0.001
+    } else {
+        dt
+    };
+    (dt / max_substep).ceil().max(1.0) as usize
+}
+
+/// 6-group point kinetics over `dt` with RK4, sub-stepped near prompt
+/// critical and during a SCRAM
+fn solve_kinetics_6group(
+    n: f64,
+    precursors: &[f64; NUM_DELAYED_GROUPS],
+    reactivity: f64,
+    source: f64,
+    dt: f64,
+) -> (f64, [f64; NUM_DELAYED_GROUPS]) {
+    let rho = reactivity.clamp(-0.15, 0.02);
+    let substeps = kinetics_substeps(reactivity, dt);
+    let h = dt / substeps as f64;
+    // Sum of the group fractions, so equilibrium precursors hold n exactly
+    let beta: f64 = BETA_I.iter().sum();
+
+    let derivatives = |n: f64, c: &[f64; NUM_DELAYED_GROUPS]| {
+        let delayed: f64 = (0..NUM_DELAYED_GROUPS).map(|g| LAMBDA_I[g] * c[g]).sum();
+        let dn = (rho - beta) / NEUTRON_LIFETIME * n + delayed + source;
+        let dc: [f64; NUM_DELAYED_GROUPS] =
+            std::array::from_fn(|g| BETA_I[g] / NEUTRON_LIFETIME * n - LAMBDA_I[g] * c[g]);
+        (dn, dc)
+    };
+    let offset = |c: &[f64; NUM_DELAYED_GROUPS], k: &[f64; NUM_DELAYED_GROUPS], scale: f64| {
+        std::array::from_fn(|g| c[g] + scale * k[g])
+    };
+
+    let mut n = n;
+    let mut c = *precursors;
+    for _ in 0..substeps {
+        let (k1_n, k1_c) = derivatives(n, &c);
+        let (k2_n, k2_c) = derivatives(n + 0.5 * h * k1_n, &offset(&c, &k1_c, 0.5 * h));
+        let (k3_n, k3_c) = derivatives(n + 0.5 * h * k2_n, &offset(&c, &k2_c, 0.5 * h));
+        let (k4_n, k4_c) = derivatives(n + h * k3_n, &offset(&c, &k3_c, h));
+        n += h / 6.0 * (k1_n + 2.0 * k2_n + 2.0 * k3_n + k4_n);
+        for g in 0..NUM_DELAYED_GROUPS {
+            c[g] += h / 6.0 * (k1_c[g] + 2.0 * k2_c[g] + 2.0 * k3_c[g] + k4_c[g]);
+        }
+        n = n.max(0.0);
+    }
+    (n, c)
+}
+
+/// Lumped temperatures and void
+struct ThermalState {
+    fuel_temp: f64,
+    coolant_temp: f64,
+    graphite_temp: f64,
+    coolant_void: f64,
+}
+
+/// First-order relaxation toward power-dependent targets, boiling above `t_sat`
+fn update_temperatures(power_percent: f64, input: &ThermalState, dt: f64, t_sat: f64) -> ThermalState {
+    let power_fraction = (power_percent / 100.0).clamp(0.0, 10.0);
+    let relax = |value: f64, target: f64, tau: f64| value + (dt / tau).min(1.0) * (target - value);
+
+    let coolant_temp = relax(input.coolant_temp, 400.0 + 150.0 * power_fraction, COOLANT_TIME_CONST);
+    let graphite_temp = relax(input.graphite_temp, 400.0 + 250.0 * power_fraction, GRAPHITE_TIME_CONST);
+    // Fuel heats fast during an excursion
+    let fuel_tau = if power_fraction > 1.0 { 0.5 } else { 5.0 };
+    let fuel_temp = relax(input.fuel_temp, 400.0 + 500.0 * power_fraction, fuel_tau);
+
+    let coolant_temp = coolant_temp.clamp(300.0, 1000.0);
+    let void_alpha = (dt / VOID_TIME_CONST).min(1.0);
+    let coolant_void = if coolant_temp > t_sat {
+        let target_void = ((coolant_temp - t_sat) * 2.0).min(80.0);
+        input.coolant_void + void_alpha * (target_void - input.coolant_void)
+    } else {
+        input.coolant_void * (1.0 - void_alpha)
+    };
+
+    ThermalState {
+        fuel_temp: fuel_temp.clamp(300.0, 3000.0),
+        coolant_temp,
+        graphite_temp: graphite_temp.clamp(300.0, 1500.0),
+        coolant_void: coolant_void.clamp(0.0, 80.0),
+    }
+}
+
+/// One explicit Euler step of the I-135 → Xe-135 chain
+fn xenon_step(iodine: f64, xenon: f64, flux: f64, dt: f64) -> (f64, f64) {
+    let fission_rate = NOMINAL_FISSION_RATE * (flux / NOMINAL_FLUX).max(0.0);
+    let d_iodine = GAMMA_IODINE * fission_rate - LAMBDA_IODINE * iodine;
+    let d_xenon = GAMMA_XE * fission_rate + LAMBDA_IODINE * iodine - LAMBDA_XE * xenon - SIGMA_XE * flux * xenon;
+    (
+        (iodine + d_iodine * dt).clamp(0.0, 1e17),
+        (xenon + d_xenon * dt).clamp(0.0, 1e16),
+    )
+}
+
+/// Saturation temperature of water [K] (IAPWS-IF97 region 4 backward equation)
+fn saturation_temp(pressure_mpa: f64) -> f64 {
+    const N: [f64; 10] = [
+        0.116_705_214_527_67e4,
+        -0.724_213_167_032_06e6,
+        -0.170_738_469_400_92e2,
+        0.120_208_247_024_70e5,
+        -0.323_255_503_223_33e7,
+        0.149_151_086_135_30e2,
+        -0.482_326_573_615_91e4,
+        0.405_113_405_420_57e6,
+        -0.238_555_575_678_49,
+        0.650_175_348_447_98e3,
+    ];
+    let beta = pressure_mpa.clamp(611.213e-6, 22.064).powf(0.25);
+    let e = beta * beta + N[2] * beta + N[5];
+    let f = N[0] * beta * beta + N[3] * beta + N[6];
+    let g = N[1] * beta * beta + N[4] * beta + N[7];
+    let d = 2.0 * g / (-f - (f * f - 4.0 * e * g).sqrt());
+    (N[9] + d - ((N[9] + d).powi(2) - 4.0 * (N[8] + N[9] * d)).sqrt()) / 2.0
+}
+
+/// Same limits as the Fortran `check_safety_limits`
+fn alert_flags(power_percent: f64, reactivity_dollars: f64, fuel_temp: f64, coolant_void: f64, period: f64) -> i32 {
+    let mut flags = 0;
+    if power_percent > 110.0 {
+        flags |= fortran_ffi::ALERT_POWER_HIGH;
+    }
+    if reactivity_dollars > 0.5 {
+        flags |= fortran_ffi::ALERT_REACTIVITY_HIGH;
+    }
+    if reactivity_dollars > 1.0 {
+        flags |= fortran_ffi::ALERT_PROMPT_CRITICAL;
+    }
+    if fuel_temp > 1200.0 {
+        flags |= fortran_ffi::ALERT_FUEL_TEMP_HIGH;
+    }
+    if coolant_void > 50.0 {
+        flags |= fortran_ffi::ALERT_VOID_HIGH;
+    }
+    if period > 0.0 && period < 20.0 {
+        flags |= fortran_ffi::ALERT_SHORT_PERIOD;
+    }
+    flags
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_saturation_and_equilibrium() {
+        // IF97 gives 558.98 K at 7 MPa
+        assert!((saturation_temp(7.0) - 558.98).abs() < 0.05);
+
+        // At zero reactivity the equilibrium precursors hold the population
+        let precursors = equilibrium_precursors(1.0);
+        let (n, _) = solve_kinetics_6group(1.0, &precursors, 0.0, 0.0, 0.1);
+        assert!((n - 1.0).abs() < 1e-9);
+
+        // A negative step drops the population promptly, then slowly
+        let (n, _) = solve_kinetics_6group(1.0, &precursors, -0.01, 0.0, 1.0);
+        assert!(n < 0.5 && n > 0.1);
+    }
+
+    #[test]
+    fn test_rod_worth_shape() {
+        let backend = PureRustBackend::default();
+        // Shape 1.0 is the symmetric S-curve, endpoints are fixed for any shape
+        assert!((backend.rod_worth(0.5, 1.0, 1.0) - 0.5).abs() < 1e-12);
+        assert_eq!(backend.rod_worth(0.0, 1.0, 2.0), 1.0);
+        assert!(backend.rod_worth(1.0, 1.0, 2.0).abs() < 1e-12);
+        // A bottom-heavy curve keeps more worth inserted at mid-stroke
+        assert!(backend.rod_worth(0.5, 1.0, 2.0) > backend.rod_worth(0.5, 1.0, 1.0));
+    }
+
+    #[test]
+    fn test_population_growth_bound() {
+        assert_eq!(bound_population_growth(1.0, 1.5, 0.1), (1.5, false));
+        let (n, clamped) = bound_population_growth(1.0, f64::INFINITY, 0.1);
+        assert!(clamped && n.is_finite());
+        assert!(bound_population_growth(1.0, f64::NAN, 0.1).1);
+    }
+}
@@ -1,7 +1,8 @@
 //! RBMK Reactor Simulation State
 //!
 //! This module contains the reactor state and simulation logic.
-//! All physics calculations are delegated to Fortran via FFI.
+//! All physics calculations are delegated to Fortran via FFI; the core-level
+//! model goes through a `PhysicsBackend` so it can be swapped (see `backend`).
 //!
 //! The reactor core consists of 1661 fuel channels (TK cells) arranged
 //! according to the OPB-82 layout configuration. Currently, all channels
@@ -9,10 +10,24 @@
 
 use serde::{Deserialize, Serialize};
 use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
 use std::fs;
-use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::io::{self, Write};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
+use crate::alerts::{self, ActiveAlert, Alert, AlertCode, AlertDefinition, AlertTracker};
+use crate::backend::{self, KineticsSolver, PhysicsBackend, PointKineticsInput};
+use crate::blackbox::{self, BlackboxSnapshot, ReactivityBreakdown, ReactivityTrace};
+use crate::checkpoint::{Checkpoint, CheckpointBuffer};
+use crate::delta_stream::{DeltaTracker, VersionedDelta};
+use crate::faults::{self, FaultDrillStatus, FaultInjector, FaultKind, FiredFault};
 use crate::fortran_ffi;
+use crate::history::{ChannelHistoryRecorder, ChannelSample, KeffHistory, KeffSample};
+use crate::stability::{StabilityMonitor, StabilityStatus};
+use crate::startup::{InverseMCurve, InverseMRecorder};
+use crate::state_stream;
+use crate::timing::{PhaseTimer, StepTiming};
 
 /// Layout configuration structures for loading OPB-82 layout
 #[derive(Debug, Clone, Deserialize)]
@@ -25,6 +40,8 @@ struct LayoutConfig {
 struct LayoutMetadata {
     total_cells: usize,
     grid_size: GridSize,
+    #[serde(default)]
+    equilibrium_burnup: f64, // Starting burnup of TK cells without their own [MWd/kgU]
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -47,6 +64,56 @@ struct CellConfig {
     pixel_y: i32,
     #[allow(dead_code)]
     area: i32,
+    #[serde(default)]
+    fresh: bool,                 // TK cells: freshly loaded fuel, starts at zero burnup
+    #[serde(default)]
+    initial_burnup: Option<f64>, // TK cells: starting burnup [MWd/kgU], overrides the layout's
+}
+
+/// Problem found while loading a layout (the layout still loads)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LayoutWarning {
+    /// Cell coordinates outside the declared `grid_size`
+    OutOfRange {
+        cell_type: String,
+        index: usize,  // Position of the cell in its type list
+        grid_x: i32,
+        grid_y: i32,
+        width: usize,
+        height: usize,
+    },
+}
+
+/// Check every cell's grid coordinates against the declared grid size
+fn validate_layout(config: &LayoutConfig) -> Vec<LayoutWarning> {
+    let width = config.metadata.grid_size.width;
+    let height = config.metadata.grid_size.height;
+    let in_range = |coord: i32, size: usize| coord >= 0 && (coord as usize) < size;
+    
+    // Sorted so warnings come out in the same order on every load
+    let mut cell_types: Vec<&String> = config.cells.keys().collect();
+    cell_types.sort();
+    
+    let mut warnings = Vec::new();
+    for cell_type in cell_types {
+        for (index, cell) in config.cells[cell_type].iter().enumerate() {
+            if !in_range(cell.grid_x, width) || !in_range(cell.grid_y, height) {
+                eprintln!(
+                    "[reactor] Warning: {} cell {} at ({}, {}) is outside the {}x{} grid",
+                    cell_type, index, cell.grid_x, cell.grid_y, width, height
+                );
+                warnings.push(LayoutWarning::OutOfRange {
+                    cell_type: cell_type.clone(),
+                    index,
+                    grid_x: cell.grid_x,
+                    grid_y: cell.grid_y,
+                    width,
+                    height,
+                });
+            }
+        }
+    }
+    warnings
 }
 
 /// Grid spacing in cm (graphite block size)
@@ -56,7 +123,8 @@ const GRID_SPACING_CM: f64 = 25.0;
 const GRID_CENTER: f64 = 24.0;
 
 /// Load fuel channel positions from the OPB-82 layout config
-fn load_fuel_channels_from_config() -> Vec<FuelChannel> {
+/// Also returns the parsed config (None when the fallback grid is used).
+fn load_fuel_channels_from_config() -> (Vec<FuelChannel>, Option<LayoutConfig>) {
     // Try to load from config file
     let config_paths = [
         "config/opb82_layout.json",
@@ -67,19 +135,208 @@ fn load_fuel_channels_from_config() -> Vec<FuelChannel> {
     for path in &config_paths {
         if let Ok(content) = fs::read_to_string(path) {
             if let Ok(config) = serde_json::from_str::<LayoutConfig>(&content) {
-                let mut channels = create_channels_from_config(&config);
-                // Build neighbor connectivity map for 2D diffusion
-                build_neighbor_map(&mut channels);
-                return channels;
+                return (create_channels_from_config(&config), Some(config));
             }
         }
     }
     
     // Fallback: generate default circular grid if config not found
     eprintln!("[reactor] Warning: Could not load layout config, using fallback circular grid");
-    let mut channels = create_fallback_channels();
-    build_neighbor_map(&mut channels);
+    (create_fallback_channels(FALLBACK_GRID_SIZE), None)
+}
+
+/// Layout cell type of in-core detector (instrument) channels
+const DETECTOR_CELL_TYPE: &str = "DKE";
+
+/// Spacing of the generated detector lattice [grid cells]
+const DETECTOR_LATTICE_PITCH: i32 = 7;
+
+/// Fuel channels within this distance contribute to a detector reading [cm]
+const DETECTOR_INTERP_RADIUS_CM: f64 = 2.0 * GRID_SPACING_CM;
+
+/// Detector current with the local flux at nominal [µA]
+const DETECTOR_CURRENT_AT_NOMINAL_UA: f64 = 1.0;
+
+/// Fission rate per MW of thermal power (200 MeV per fission) [fissions/s]
+const FISSIONS_PER_MW_S: f64 = 3.12e16;
+
+/// Location of an in-core neutron detector
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DetectorPosition {
+    pub id: usize,
+    pub grid_x: i32,
+    pub grid_y: i32,
+    pub x: f64,  // Position in core [cm] from center
+    pub y: f64,
+}
+
+/// Reading of one in-core detector
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DetectorSignal {
+    pub id: usize,
+    pub grid_x: i32,
+    pub grid_y: i32,
+    pub local_flux: f64,    // Interpolated neutron flux (1.0 = nominal)
+    pub fission_rate: f64,  // Interpolated channel fission rate [fissions/s]
+    pub current_ua: f64,    // Ion-chamber current [µA]
+}
+
+/// Instrument failure of one in-core detector, for operator training
+/// Only the reported reading is corrupted; the physics is unaffected.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum DetectorFault {
+    #[default]
+    None,
+    Stuck(f64), // Reads this flux (1.0 = nominal) whatever the core does
+    Drift(f64), // Reading drifts away from the true flux [nominal flux/s]
+    Dead,       // Reads zero
+}
+
+/// A detector fault and the simulation time it was set [s]
+#[derive(Debug, Clone, Copy)]
+pub struct ActiveDetectorFault {
+    fault: DetectorFault,
+    since: f64,
+}
+
+impl ActiveDetectorFault {
+    /// Flux the faulty detector reports instead of `true_flux`
+    fn corrupt(&self, true_flux: f64, time: f64) -> f64 {
+        match self.fault {
+            DetectorFault::None => true_flux,
+            DetectorFault::Stuck(value) => value,
+            DetectorFault::Drift(rate) => (true_flux + rate * (time - self.since)).max(0.0),
+            DetectorFault::Dead => 0.0,
+        }
+    }
+}
+
+/// Channel type of local automatic regulator rods
+const LAR_CHANNEL_TYPE: &str = "LAR";
+
+/// Local automatic regulator zone: the fuel channels closer to one LAR rod
+/// than to any other. The rod is driven from the zone's own power error.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LarZone {
+    pub rod_id: usize,
+    pub grid_x: i32,
+    pub grid_y: i32,
+    pub channel_ids: Vec<usize>,  // Member channels
+    pub power_percent: f64,       // Zone power in % of nominal (zone/core average × core power)
+    pub power_error: f64,         // Target minus zone power [%]
+    pub integral_error: f64,      // Accumulated zone error for the PID
+    pub last_error: f64,          // Previous zone error for the derivative
+}
+
+/// Partition the core into LAR zones by nearest LAR rod (empty if the layout has no LAR rods)
+fn create_lar_zones(rods: &[ControlRod], channels: &[FuelChannel]) -> Vec<LarZone> {
+    let mut zones: Vec<LarZone> = rods
+        .iter()
+        .filter(|rod| rod.channel_type == LAR_CHANNEL_TYPE)
+        .map(|rod| LarZone {
+            rod_id: rod.id,
+            grid_x: rod.grid_x,
+            grid_y: rod.grid_y,
+            channel_ids: Vec::new(),
+            power_percent: 0.0,
+            power_error: 0.0,
+            integral_error: 0.0,
+            last_error: 0.0,
+        })
+        .collect();
+    if zones.is_empty() {
+        return zones;
+    }
+    
+    for channel in channels {
+        let dist_sq = |zone: &LarZone| (zone.grid_x - channel.grid_x).pow(2) + (zone.grid_y - channel.grid_y).pow(2);
+        if let Some(zone) = zones.iter_mut().min_by_key(|zone| dist_sq(zone)) {
+            zone.channel_ids.push(channel.id);
+        }
+    }
+    zones
+}
+
+/// Measure each LAR zone's power and advance its PID error terms
+/// (mirrors the global AR bookkeeping at the end of a step)
+fn update_lar_zones(
+    zones: &mut [LarZone],
+    channels: &[FuelChannel],
+    core_power_percent: f64,
+    settings: &AutoRegulatorSettings,
+    regulating: bool,
+    dt: f64,
+) {
+    let core_avg = channels.iter().map(|c| c.local_power).sum::<f64>() / channels.len() as f64;
+    let max_integral = 100.0;
+    
+    for zone in zones.iter_mut() {
+        let zone_power: f64 = zone.channel_ids.iter().filter_map(|&id| channels.get(id)).map(|c| c.local_power).sum();
+        let zone_avg = zone_power / zone.channel_ids.len().max(1) as f64;
+        zone.power_percent = if core_avg > 0.0 { core_power_percent * zone_avg / core_avg } else { 0.0 };
+        zone.power_error = settings.target_power - zone.power_percent;
+        
+        if regulating {
+            if zone.power_error.abs() > settings.deadband {
+                zone.integral_error = (zone.integral_error + zone.power_error * dt).clamp(-max_integral, max_integral);
+            } else {
+                zone.integral_error *= 0.99;
+            }
+            zone.last_error = zone.power_error;
+        }
+    }
+}
+
+/// Detector positions: the layout's `DKE` cells if it has any, otherwise a
+/// regular lattice of fuel channel positions (like the RBMK radial detectors)
+fn create_detectors(config: Option<&LayoutConfig>, channels: &[FuelChannel]) -> Vec<DetectorPosition> {
+    if let Some(cells) = config.and_then(|c| c.cells.get(DETECTOR_CELL_TYPE)) {
+        return cells
+            .iter()
+            .enumerate()
+            .map(|(id, cell)| DetectorPosition {
+                id,
+                grid_x: cell.grid_x,
+                grid_y: cell.grid_y,
+                x: (cell.grid_x as f64 - GRID_CENTER + 0.5) * GRID_SPACING_CM,
+                y: (cell.grid_y as f64 - GRID_CENTER + 0.5) * GRID_SPACING_CM,
+            })
+            .collect();
+    }
+    
+    let on_lattice = |coord: i32| coord.rem_euclid(DETECTOR_LATTICE_PITCH) == DETECTOR_LATTICE_PITCH / 2;
     channels
+        .iter()
+        .filter(|c| on_lattice(c.grid_x) && on_lattice(c.grid_y))
+        .enumerate()
+        .map(|(id, c)| DetectorPosition { id, grid_x: c.grid_x, grid_y: c.grid_y, x: c.x, y: c.y })
+        .collect()
+}
+
+/// File name suffix of a named layout (`<name>_layout.json`)
+const LAYOUT_FILE_SUFFIX: &str = "_layout.json";
+
+/// Name of the layout loaded at startup
+const DEFAULT_LAYOUT_NAME: &str = "opb82";
+
+/// Active layout name for channels supplied through the builder
+const CUSTOM_LAYOUT_NAME: &str = "custom";
+
+/// Directories searched for named layouts when no directory has been listed
+const LAYOUT_DIRS: [&str; 3] = ["config", "../config", "ui/public/config"];
+
+/// Whether a layout name is a plain file name that cannot leave the layout
+/// directory (no separators, drive prefixes or parent references)
+fn is_plain_layout_name(name: &str) -> bool {
+    !name.is_empty() && !name.contains(['/', '\\', ':']) && !name.contains("..")
+}
+
+/// Read and parse a layout config file
+fn read_layout_file(path: &Path) -> Result<LayoutConfig, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read layout {}: {}", path.display(), e))?;
+    serde_json::from_str::<LayoutConfig>(&content)
+        .map_err(|e| format!("Failed to parse layout {}: {}", path.display(), e))
 }
 
 /// Link control rods to fuel channels based on grid position
@@ -183,6 +440,8 @@ mod channel_defaults {
     // Xenon/Iodine (fresh fuel, no history)
     pub const IODINE_135: f64 = 0.0;            // No iodine
     pub const XENON_135: f64 = 0.0;             // No xenon
+    pub const PROMETHIUM_149: f64 = 0.0;        // No promethium
+    pub const SAMARIUM_149: f64 = 0.0;          // No samarium
     
     // Fuel state
     pub const BURNUP_MWD_KGU: f64 = 0.0;        // Fresh fuel
@@ -190,6 +449,30 @@ mod channel_defaults {
     
     // Local reactivity
     pub const LOCAL_REACTIVITY: f64 = 0.0;      // No local contribution
+    
+    // Thermal margin
+    pub const DNBR: f64 = 99.0;                 // No heat flux (matches Fortran DNBR_MAX)
+    
+    // Hot standby (shut down after a trip, coolant circulating at inlet temperature)
+    pub const HOT_STANDBY_FUEL_TEMP_K: f64 = 553.0;
+    pub const HOT_STANDBY_COOLANT_TEMP_K: f64 = 543.0;
+    pub const HOT_STANDBY_GRAPHITE_TEMP_K: f64 = 573.0;
+    
+    // Pressure-tube rupture
+    pub const RUPTURE_VOID_PERCENT: f64 = 100.0;     // Coolant blown out of the channel
+    pub const RUPTURE_LOCAL_REACTIVITY: f64 = 0.01;  // Lost water absorber in the channel [Δk/k]
+    pub const RUPTURE_STEAM_HEAT_FRACTION: f64 = 0.5; // Share of the channel power the steam dumps into the stack
+    // One 25 cm × 25 cm × 7 m graphite column: ~740 kg at ~1.5 kJ/(kg·K)
+    pub const GRAPHITE_COLUMN_HEAT_CAPACITY_J_K: f64 = 1.1e6;
+    
+    // Uncovered by a low coolant inventory
+    pub const UNCOVERED_VOID_PERCENT: f64 = 100.0;   // Channel standing in steam
+    
+    // Default fuel volume per channel: π * (0.68cm)² * 700cm ≈ 1017 cm³
+    // (override with `ReactorSimulator::set_channel_volume` for other geometries)
+    pub const CHANNEL_VOLUME_M3: f64 = std::f64::consts::PI
+        * super::constants::FUEL_RADIUS_CM * super::constants::FUEL_RADIUS_CM
+        * super::constants::CORE_HEIGHT_CM * 1.0e-6;
 }
 
 /// Create fuel channels from loaded config (TK cells only)
@@ -205,6 +488,14 @@ fn create_channels_from_config(config: &LayoutConfig) -> Vec<FuelChannel> {
             let x = (cell.grid_x as f64 - GRID_CENTER + 0.5) * GRID_SPACING_CM;
             let y = (cell.grid_y as f64 - GRID_CENTER + 0.5) * GRID_SPACING_CM;
             
+            // Partially refueled core: fresh cells at zero burnup, the rest
+            // at their own or the layout's equilibrium burnup
+            let burnup = if cell.fresh {
+                channel_defaults::BURNUP_MWD_KGU
+            } else {
+                cell.initial_burnup.unwrap_or(config.metadata.equilibrium_burnup).max(0.0)
+            };
+            
             fuel_channels.push(FuelChannel {
                 // Identification and position
                 id,
@@ -234,9 +525,12 @@ fn create_channels_from_config(config: &LayoutConfig) -> Vec<FuelChannel> {
                 // Xenon/Iodine (fresh fuel)
                 iodine_135: channel_defaults::IODINE_135,
                 xenon_135: channel_defaults::XENON_135,
+                promethium_149: channel_defaults::PROMETHIUM_149,
+                samarium_149: channel_defaults::SAMARIUM_149,
                 
                 // Fuel state
-                burnup: channel_defaults::BURNUP_MWD_KGU,
+                burnup,
+                initial_burnup: burnup,
                 enrichment: channel_defaults::ENRICHMENT_PERCENT,
                 
                 // Control rod (will be assigned later based on layout)
@@ -249,6 +543,11 @@ fn create_channels_from_config(config: &LayoutConfig) -> Vec<FuelChannel> {
                 
                 // Local reactivity
                 local_reactivity: channel_defaults::LOCAL_REACTIVITY,
+                dnbr: channel_defaults::DNBR,
+                
+                ruptured: false,
+                uncovered: false,
+                power_clamped: false,
             });
         }
     }
@@ -312,6 +611,8 @@ fn create_control_rods_from_config(config: &LayoutConfig) -> Vec<ControlRod> {
                     rod_type: rod_type.clone(),
                     worth: *worth,
                     channel_type: type_name.to_string(),  // Store original channel type
+                    stuck: false,
+                    absorber_depletion: 0.0,
                 });
                 id += 1;
             }
@@ -379,16 +680,21 @@ fn create_fallback_control_rods() -> Vec<ControlRod> {
             rod_type,
             worth,
             channel_type,
+            stuck: false,
+            absorber_depletion: 0.0,
         });
     }
     
     control_rods
 }
 
+/// Cells per side of the fallback circular grid
+const FALLBACK_GRID_SIZE: usize = 41;
+
 /// Fallback: create simplified circular grid if config not found
-fn create_fallback_channels() -> Vec<FuelChannel> {
+/// Channels fill the cells of a `grid_size` x `grid_size` grid inside the core radius.
+fn create_fallback_channels(grid_size: usize) -> Vec<FuelChannel> {
     let mut fuel_channels = Vec::new();
-    let grid_size = 41;
     let spacing = 2.0 * constants::CORE_RADIUS_CM / (grid_size as f64);
     
     let mut id = 0;
@@ -427,9 +733,12 @@ fn create_fallback_channels() -> Vec<FuelChannel> {
                     // Xenon/Iodine (fresh fuel)
                     iodine_135: channel_defaults::IODINE_135,
                     xenon_135: channel_defaults::XENON_135,
+                    promethium_149: channel_defaults::PROMETHIUM_149,
+                    samarium_149: channel_defaults::SAMARIUM_149,
                     
                     // Fuel state
                     burnup: channel_defaults::BURNUP_MWD_KGU,
+                    initial_burnup: channel_defaults::BURNUP_MWD_KGU,
                     enrichment: channel_defaults::ENRICHMENT_PERCENT,
                     
                     // Control rod (will be assigned later)
@@ -442,6 +751,11 @@ fn create_fallback_channels() -> Vec<FuelChannel> {
                     
                     // Local reactivity
                     local_reactivity: channel_defaults::LOCAL_REACTIVITY,
+                    dnbr: channel_defaults::DNBR,
+                    
+                    ruptured: false,
+                    uncovered: false,
+                    power_clamped: false,
                 });
                 id += 1;
             }
@@ -457,12 +771,29 @@ pub mod constants {
     pub const NUM_FUEL_CHANNELS: usize = 1661;
     pub const CORE_HEIGHT_CM: f64 = 700.0;
     pub const CORE_RADIUS_CM: f64 = 593.0;
+    /// Radius of the fuel volume per channel that power density refers to [cm]
+    pub const FUEL_RADIUS_CM: f64 = 0.68;
     pub const NUM_CONTROL_RODS: usize = 211;
     pub const BETA_EFF: f64 = 0.0065;
     /// Prompt neutron lifetime for RBMK (graphite-moderated)
     /// RBMK has longer lifetime (~1ms) compared to LWR (~0.1ms)
     pub const NEUTRON_LIFETIME: f64 = 1.0e-3; // seconds
     
+    /// Allowed range for a user-set prompt neutron lifetime [s]
+    /// (fast reactors ~1e-7 s up to heavily moderated systems ~1e-2 s)
+    pub const NEUTRON_LIFETIME_MIN: f64 = 1.0e-7;
+    pub const NEUTRON_LIFETIME_MAX: f64 = 1.0e-2;
+    
+    /// Highest total reactivity of the point model [Δk/k] and the single-step
+    /// population growth bound derived from it (Fortran `bound_population_growth`):
+    /// growth ≤ exp(min(margin · ρ_max · dt / Λ, max exponent)) above the floor
+    pub const REACTIVITY_CEILING: f64 = 0.02;
+    /// Lowest total reactivity of the point model [Δk/k]
+    pub const REACTIVITY_FLOOR: f64 = -0.10;
+    pub const POPULATION_GROWTH_MARGIN: f64 = 2.0;
+    pub const POPULATION_GROWTH_MAX_EXPONENT: f64 = 40.0;
+    pub const POPULATION_GROWTH_FLOOR: f64 = 1.0e-6;
+    
     /// Number of delayed neutron groups
     pub const NUM_DELAYED_GROUPS: usize = 6;
     
@@ -476,6 +807,193 @@ pub mod constants {
         0.000273,  // Group 6
     ];
     
+    /// One-group effective precursor decay constant [1/s] (matches Fortran LAMBDA_DECAY)
+    pub const LAMBDA_EFF: f64 = 0.0767;
+    
+    /// Monitored limits (same thresholds as the power and short-period alerts)
+    pub const POWER_LIMIT_PERCENT: f64 = 110.0;
+    pub const PERIOD_LIMIT_S: f64 = 30.0;
+    
+    /// Number of points in the axial flux profile
+    pub const AXIAL_POINTS: usize = 50;
+    
+    /// Axial tilt per unit of top/bottom absorber insertion imbalance
+    pub const AXIAL_TILT_GAIN: f64 = 0.5;
+    
+    /// Below this power the low-power (startup) regime applies [% nominal]
+    pub const LOW_POWER_THRESHOLD_PERCENT: f64 = 1.0;
+    
+    /// Automatic regulator power setpoint range [% nominal]
+    pub const TARGET_POWER_MIN_PERCENT: f64 = 5.0;
+    pub const TARGET_POWER_MAX_PERCENT: f64 = 110.0;
+    
+    /// Power-flow map: minimum power for sustained operation (700 MW of 3200)
+    /// and the highest power allowed per unit of core flow [% / %]
+    pub const OPERATING_MIN_POWER_PERCENT: f64 = 22.0;
+    pub const MAX_POWER_TO_FLOW_RATIO: f64 = 1.1;
+    
+    /// Core-average thermal flux at nominal power [n/cm²/s]
+    pub const NOMINAL_CORE_FLUX: f64 = 1.0e14;
+    
+    /// Source-range count rate per unit neutron population [counts/s]
+    /// (the shutdown source level of 1e-6 reads ~100 cps)
+    pub const SOURCE_RANGE_CPS_PER_UNIT: f64 = 1.0e8;
+    
+    /// Minimum DNBR below which the boiling-crisis alert fires
+    pub const DNBR_LIMIT: f64 = 1.3;
+    
+    /// RBMK channel power limit; over the channel volume it is the power
+    /// density above which the local hot-spot alert fires [MW]
+    pub const CHANNEL_POWER_LIMIT_MW: f64 = 3.0;
+    
+    /// Accepted per-channel fuel volume range [m³]
+    pub const CHANNEL_VOLUME_MIN_M3: f64 = 1.0e-4;
+    pub const CHANNEL_VOLUME_MAX_M3: f64 = 0.1;
+    
+    /// Default per-channel power ceiling of the spatial solver [MW]
+    /// (~10x the nominal 1.93 MW; only a numerical instability gets there)
+    pub const CHANNEL_POWER_CEILING_MW: f64 = 20.0;
+    
+    /// Realistic band for the summed worth of all control rods [Δk/k]
+    pub const ROD_WORTH_TOTAL_MIN: f64 = 0.10;
+    pub const ROD_WORTH_TOTAL_MAX: f64 = 0.15;
+    
+    /// Reactivity within ± this of zero counts as critical [$]
+    pub const CRITICAL_DEADBAND_DOLLARS: f64 = 0.005;
+    
+    /// Reactivity at which the chain reaction runs on prompt neutrons alone [$]
+    pub const PROMPT_CRITICAL_DOLLARS: f64 = 1.0;
+    
+    /// Largest accepted gap between the reactivity breakdown and the
+    /// reactivity it accounts for (rounding only) [Δk/k]
+    pub const REACTIVITY_BALANCE_TOLERANCE: f64 = 1.0e-9;
+    
+    /// UO2 melting temperature (matches Fortran FUEL_MELTING_POINT) [K]
+    pub const FUEL_MELTING_POINT_K: f64 = 2800.0;
+    
+    /// Water in the circulation circuit and drum separators [kg]
+    pub const COOLANT_INVENTORY_KG: f64 = 1.0e6;
+    
+    /// Heat to evaporate saturated water at ~7 MPa [J/kg]
+    pub const LATENT_HEAT_J_PER_KG: f64 = 1.5e6;
+    
+    /// Inventory fraction below which the drum low-level alarm fires
+    pub const COOLANT_LOW_LEVEL_FRACTION: f64 = 0.6;
+    
+    /// Inventory fraction at which the first channels uncover (all uncovered when empty)
+    pub const COOLANT_UNCOVERY_FRACTION: f64 = 0.3;
+    
+    /// Highest feedwater rate accepted (fraction of the nominal steaming rate)
+    pub const FEEDWATER_MAX_FRACTION: f64 = 1.5;
+    
+    /// Startup rate above which the startup-rate alert fires [decades/min]
+    pub const STARTUP_RATE_LIMIT_DPM: f64 = 1.0;
+    
+    /// Internal time step of the xenon fast-forward [s]
+    /// (explicit Euler stays accurate well below 1/(λ_Xe + σ_Xe·φ) ≈ 1 h)
+    pub const XENON_FAST_FORWARD_DT_S: f64 = 60.0;
+    
+    /// Longest xenon fast-forward accepted in one call [h]
+    pub const XENON_FAST_FORWARD_MAX_HOURS: f64 = 100.0;
+    
+    /// How far ahead the xenon projection looks for the peak [h]
+    /// (the post-shutdown peak comes after 7-10 h, the pit clears in ~2 days)
+    pub const XENON_PROJECTION_HOURS: f64 = 48.0;
+    
+    /// Accepted simulation time step range [s]
+    pub const TIME_STEP_MIN_S: f64 = 0.001;
+    pub const TIME_STEP_MAX_S: f64 = 1.0;
+    
+    /// Physics steps one `simulation_realtime` call runs at most; simulation
+    /// time beyond that is dropped
+    pub const REALTIME_MAX_STEPS_PER_CALL: usize = 1000;
+    
+    /// Spatial steps per wall-clock second the real-time loop can sustain
+    /// (conservative for the full core)
+    pub const REALTIME_STEP_BUDGET_PER_S: f64 = 1000.0;
+    
+    /// Frame interval the UI drives the real-time loop at [s]
+    pub const REALTIME_FRAME_INTERVAL_S: f64 = 1.0 / 60.0;
+    
+    /// Largest share of the reactor period one frame may advance above
+    /// critical; beyond it the excursion outruns the display and the operator
+    pub const REALTIME_PERIOD_FRACTION: f64 = 0.1;
+    
+    /// Graphite gas-gap conductance relative to nominal: accepted range
+    /// (a badly degraded gap up to a helium-filled one)
+    pub const GRAPHITE_GAP_CONDUCTANCE_NOMINAL: f64 = 1.0;
+    pub const GRAPHITE_GAP_CONDUCTANCE_MIN: f64 = 0.05;
+    pub const GRAPHITE_GAP_CONDUCTANCE_MAX: f64 = 2.0;
+    
+    /// Graphite gas-circuit cooling effectiveness relative to nominal:
+    /// accepted range (circuit nearly lost up to twice the nominal flow)
+    pub const GRAPHITE_COOLING_EFFECTIVENESS_NOMINAL: f64 = 1.0;
+    pub const GRAPHITE_COOLING_EFFECTIVENESS_MIN: f64 = 0.1;
+    pub const GRAPHITE_COOLING_EFFECTIVENESS_MAX: f64 = 2.0;
+    
+    /// Time constant of the reactivity smoothing outside a SCRAM [s]:
+    /// default and accepted range. Too large hides real fast transients,
+    /// too small lets step-to-step numerical noise through.
+    pub const REACTIVITY_SMOOTHING_TAU_DEFAULT_S: f64 = 0.5;
+    pub const REACTIVITY_SMOOTHING_TAU_MIN_S: f64 = 0.01;
+    pub const REACTIVITY_SMOOTHING_TAU_MAX_S: f64 = 10.0;
+    
+    /// Common inlet plenum: feedwater temperature, feedwater share of the
+    /// inlet flow at nominal feedwater (the rest is drum water returning at
+    /// saturation; 0.132 gives the nominal 543 K inlet at 7 MPa), and the
+    /// mixing time constant [s] with its accepted range
+    pub const FEEDWATER_TEMP_K: f64 = 438.0;
+    pub const FEEDWATER_INLET_SHARE_NOMINAL: f64 = 0.132;
+    pub const PLENUM_MIXING_TIME_DEFAULT_S: f64 = 10.0;
+    pub const PLENUM_MIXING_TIME_MIN_S: f64 = 0.1;
+    pub const PLENUM_MIXING_TIME_MAX_S: f64 = 600.0;
+    
+    /// Settling of the point thermal model for equilibrium rod positions:
+    /// step length (far beyond every time constant) and repetitions
+    pub const EQUILIBRIUM_THERMAL_DT_S: f64 = 1.0e6;
+    pub const EQUILIBRIUM_THERMAL_ITERATIONS: usize = 10;
+    
+    /// Withdrawal used to probe a rod group's control authority [fraction]
+    pub const ROD_SENSITIVITY_STEP: f64 = 0.01;
+    
+    /// Upper bound on settle steps for one rod-worth increment measurement
+    pub const ROD_WORTH_SETTLE_STEPS_MAX: usize = 10_000;
+    
+    /// Automatic radial flattening: iteration cap, spatial steps run after
+    /// each rod adjustment, rod travel per unit of relative region power
+    /// excess, and the smallest peaking improvement that counts as progress
+    pub const AUTO_FLATTEN_MAX_ITERATIONS: usize = 100;
+    pub const AUTO_FLATTEN_SETTLE_STEPS: usize = 20;
+    pub const AUTO_FLATTEN_GAIN: f64 = 0.2;
+    pub const AUTO_FLATTEN_TOLERANCE: f64 = 1.0e-4;
+    
+    /// Upper bound on steps recorded by one open-loop step response
+    pub const STEP_RESPONSE_MAX_STEPS: usize = 10_000;
+    
+    /// Absorber burnout per second of full insertion at 100 % power
+    /// Default ~3 % per year, so short runs are unaffected; the maximum lets
+    /// a campaign be compressed into minutes.
+    pub const ABSORBER_DEPLETION_RATE_DEFAULT: f64 = 1.0e-9;
+    pub const ABSORBER_DEPLETION_RATE_MAX: f64 = 1.0e-3;
+    
+    /// Rod worth curve shape exponents (see `calculate_rod_worth`): the
+    /// symmetric S-curve, the bottom-heavy curve of shortened USP rods, and
+    /// the accepted range
+    pub const ROD_WORTH_SHAPE_NOMINAL: f64 = 1.0;
+    pub const ROD_WORTH_SHAPE_USP: f64 = 2.0;
+    pub const ROD_WORTH_SHAPE_MIN: f64 = 0.25;
+    pub const ROD_WORTH_SHAPE_MAX: f64 = 4.0;
+    
+    /// Default number of points of a rod worth curve
+    pub const ROD_WORTH_CURVE_POINTS: usize = 21;
+    
+    /// Default full-stroke SCRAM insertion time [s] (RBMK-1000 AZ-5 ~18 s)
+    pub const SCRAM_INSERTION_TIME_S: f64 = 18.0;
+    
+    /// Local reactivity per unit of local rod worth in the spatial solver
+    /// (`rho_local_effect` in rbmk_spatial.f90)
+    pub const LOCAL_ROD_WORTH_REACTIVITY_SCALE: f64 = 1.5;
+    
     /// 6-group decay constants (λᵢ) in s⁻¹ for U-235
     pub const LAMBDA_I: [f64; NUM_DELAYED_GROUPS] = [
         0.0124,    // Group 1, T₁/₂ = 55.9s
@@ -520,8 +1038,16 @@ pub struct FuelChannel {
     pub iodine_135: f64,     // I-135 concentration [atoms/cm³]
     pub xenon_135: f64,      // Xe-135 concentration [atoms/cm³]
     
+    // Promethium/Samarium dynamics (long-term, non-decaying poison)
+    #[serde(default)]
+    pub promethium_149: f64, // Pm-149 concentration [atoms/cm³]
+    #[serde(default)]
+    pub samarium_149: f64,   // Sm-149 concentration [atoms/cm³]
+    
     // Fuel state
     pub burnup: f64,         // Burnup [MWd/kgU]
+    #[serde(default)]
+    pub initial_burnup: f64, // Burnup as loaded, 0 = fresh fuel; restored on reset [MWd/kgU]
     pub enrichment: f64,     // U-235 enrichment [%]
     
     // Local control rod (if present in this cell)
@@ -534,6 +1060,146 @@ pub struct FuelChannel {
     
     // Local reactivity contributions
     pub local_reactivity: f64,  // Local reactivity contribution [Δk/k]
+    
+    // Thermal margin to boiling crisis (departure from nucleate boiling ratio)
+    #[serde(default = "default_dnbr")]
+    pub dnbr: f64,
+    
+    // Pressure-tube rupture (coolant dumped into the graphite stack)
+    #[serde(default)]
+    pub ruptured: bool,
+    
+    // Left without water by a low coolant inventory (no flow, full void)
+    #[serde(default)]
+    pub uncovered: bool,
+    
+    // Local power hit the channel power ceiling last spatial step (solver instability)
+    #[serde(default)]
+    pub power_clamped: bool,
+}
+
+fn default_dnbr() -> f64 {
+    channel_defaults::DNBR
+}
+
+fn full_fraction() -> f64 {
+    1.0
+}
+
+fn default_channel_volume() -> f64 {
+    channel_defaults::CHANNEL_VOLUME_M3
+}
+
+fn validate_channel_volume(volume_m3: f64) -> Result<(), String> {
+    if !(constants::CHANNEL_VOLUME_MIN_M3..=constants::CHANNEL_VOLUME_MAX_M3).contains(&volume_m3) {
+        return Err(format!(
+            "Channel volume {} m³ out of range [{}, {}]",
+            volume_m3, constants::CHANNEL_VOLUME_MIN_M3, constants::CHANNEL_VOLUME_MAX_M3
+        ));
+    }
+    Ok(())
+}
+
+fn nominal_gap_conductance() -> f64 {
+    constants::GRAPHITE_GAP_CONDUCTANCE_NOMINAL
+}
+
+fn nominal_graphite_cooling() -> f64 {
+    constants::GRAPHITE_COOLING_EFFECTIVENESS_NOMINAL
+}
+
+fn default_reactivity_smoothing_tau() -> f64 {
+    constants::REACTIVITY_SMOOTHING_TAU_DEFAULT_S
+}
+
+fn nominal_inlet_temp() -> f64 {
+    channel_defaults::INLET_TEMP_K
+}
+
+fn default_plenum_mixing_time() -> f64 {
+    constants::PLENUM_MIXING_TIME_DEFAULT_S
+}
+
+fn validate_plenum_mixing_time(seconds: f64) -> Result<(), String> {
+    if !(constants::PLENUM_MIXING_TIME_MIN_S..=constants::PLENUM_MIXING_TIME_MAX_S).contains(&seconds) {
+        return Err(format!(
+            "Plenum mixing time {} s out of range [{}, {}]",
+            seconds, constants::PLENUM_MIXING_TIME_MIN_S, constants::PLENUM_MIXING_TIME_MAX_S
+        ));
+    }
+    Ok(())
+}
+
+fn validate_reactivity_smoothing_tau(tau_s: f64) -> Result<(), String> {
+    if !(constants::REACTIVITY_SMOOTHING_TAU_MIN_S..=constants::REACTIVITY_SMOOTHING_TAU_MAX_S).contains(&tau_s) {
+        return Err(format!(
+            "Reactivity smoothing time constant {} s out of range [{}, {}]",
+            tau_s, constants::REACTIVITY_SMOOTHING_TAU_MIN_S, constants::REACTIVITY_SMOOTHING_TAU_MAX_S
+        ));
+    }
+    Ok(())
+}
+
+fn default_absorber_depletion_rate() -> f64 {
+    constants::ABSORBER_DEPLETION_RATE_DEFAULT
+}
+
+/// Send tuning held in Fortran module variables when the library is loaded
+/// The spatial solver reads it whatever the backend, so resets restore
+/// defaults even when the point kinetics run elsewhere.
+fn tune_fortran(apply: impl FnOnce()) {
+    if fortran_ffi::library_available() {
+        apply();
+    }
+}
+
+/// Scale channel powers to sum to `power_mw`
+/// Clamped channels stay at their ceiling and the others take up the
+/// difference. Nothing changes for a non-finite target (a NaN channel).
+fn normalize_channel_powers(channels: &mut [FuelChannel], power_mw: f64) {
+    let (clamped, free): (f64, f64) = channels.iter().fold((0.0, 0.0), |(clamped, free), c| {
+        if c.power_clamped { (clamped + c.local_power, free) } else { (clamped, free + c.local_power) }
+    });
+    if !power_mw.is_finite() || free <= 0.0 || power_mw <= clamped {
+        return;
+    }
+    let scale = (power_mw - clamped) / free;
+    for channel in channels.iter_mut().filter(|c| !c.power_clamped) {
+        channel.local_power *= scale;
+    }
+}
+
+/// Clamp an I-135/Xe-135 concentration to be non-negative
+///
+/// The explicit xenon integration can undershoot below zero after a fast
+/// power drop at a coarse time step, which would feed back as spurious
+/// positive reactivity. Debug builds log each clamp so the dt can be flagged.
+fn non_negative_poison(nuclide: &str, channel_id: Option<usize>, value: f64, dt: f64) -> f64 {
+    if value >= 0.0 {
+        return value;
+    }
+    if cfg!(debug_assertions) {
+        let location = channel_id.map_or("core".to_string(), |id| format!("channel {}", id));
+        eprintln!(
+            "[reactor] Warning: negative {} ({:e}) in {} clamped to 0 - time step {} s may be too large",
+            nuclide, value, location, dt
+        );
+    }
+    0.0
+}
+
+/// Fraction of channels uncovered at a coolant inventory
+fn uncovered_fraction(coolant_inventory_fraction: f64) -> f64 {
+    let uncovery = constants::COOLANT_UNCOVERY_FRACTION;
+    ((uncovery - coolant_inventory_fraction) / uncovery).clamp(0.0, 1.0)
+}
+
+/// Whether a channel is uncovered when `fraction` of the core is
+/// Channels are picked by a golden-ratio sequence over their ids, so the
+/// uncovered set is spread across the core and grows monotonically.
+fn channel_uncovered(channel_id: usize, fraction: f64) -> bool {
+    const GOLDEN_RATIO_CONJUGATE: f64 = 0.618_033_988_749_895;
+    (channel_id as f64 * GOLDEN_RATIO_CONJUGATE).fract() < fraction
 }
 
 /// State of a control rod
@@ -548,6 +1214,110 @@ pub struct ControlRod {
     pub rod_type: RodType,
     pub worth: f64,          // [Δk/k]
     pub channel_type: String, // Original channel type from config (RR, AR, LAR, USP, AZ)
+    #[serde(default)]
+    pub stuck: bool,         // Mechanically stuck: rod does not move (including on SCRAM)
+    #[serde(default)]
+    pub absorber_depletion: f64, // Burnt-out fraction of the absorber (0 = fresh)
+}
+
+impl ControlRod {
+    /// Worth remaining after absorber burnout [Δk/k]
+    pub fn effective_worth(&self) -> f64 {
+        self.worth * (1.0 - self.absorber_depletion)
+    }
+}
+
+/// Absorber burnout of one control rod
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RodDepletion {
+    pub rod_id: usize,
+    pub channel_type: String,
+    pub absorber_depletion: f64, // Burnt-out fraction (0 = fresh)
+    pub nominal_worth: f64,      // [Δk/k]
+    pub effective_worth: f64,    // [Δk/k]
+}
+
+/// Allowed travel range of a rod group (0.0 = fully inserted, 1.0 = fully withdrawn)
+/// Limits apply to operator moves and the automatic regulator, not to SCRAM.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RodTravelLimits {
+    pub min_position: f64,
+    pub max_position: f64,
+}
+
+impl Default for RodTravelLimits {
+    fn default() -> Self {
+        Self { min_position: 0.0, max_position: 1.0 }
+    }
+}
+
+impl RodTravelLimits {
+    pub fn clamp(&self, position: f64) -> f64 {
+        position.clamp(self.min_position, self.max_position)
+    }
+}
+
+/// Travel limits of a rod's channel type (full stroke if none are set)
+fn rod_travel_limits(limits: &HashMap<String, RodTravelLimits>, rod: &ControlRod) -> RodTravelLimits {
+    limits.get(&rod.channel_type).copied().unwrap_or_default()
+}
+
+/// Reason an operator rod move may be refused or overridden
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InterlockKind {
+    ScramActive,    // Rods are being driven in; moves are overridden every step
+    AutoRegulator,  // AR/LAR rods are positioned by the regulator
+    StuckRod,       // Rod is mechanically stuck
+    TravelLimit,    // Channel type has a restricted travel range
+}
+
+/// A control rod as seen by an automated client
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RodControl {
+    pub id: usize,
+    pub rod_type: RodType,
+    pub channel_type: String,
+    pub grid_x: i32,
+    pub grid_y: i32,
+    pub position: f64,     // 0.0 = fully inserted, 1.0 = fully withdrawn
+    pub min_position: f64, // Travel range allowed for its channel type
+    pub max_position: f64,
+    pub stuck: bool,       // Will not move
+}
+
+/// Rods that move together through one command
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RodGroupControl {
+    pub name: String,    // Argument to `command` (rod type or channel type)
+    pub command: String, // Tauri command that moves the group
+    pub rod_ids: Vec<usize>,
+}
+
+/// A settable scalar with its range and current value
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScalarControl {
+    pub name: String,
+    pub command: String, // Tauri command that sets it
+    pub unit: String,
+    pub min: f64,
+    pub max: f64,
+    pub value: f64,
+}
+
+/// Everything a client can manipulate, for automation and screen readers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ControlManifest {
+    pub rods: Vec<RodControl>,
+    pub rod_groups: Vec<RodGroupControl>,
+    pub scalars: Vec<ScalarControl>,
+}
+
+/// An interlock currently in a blocking state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interlock {
+    pub kind: InterlockKind,
+    pub rod_ids: Vec<usize>, // Rods affected
+    pub reason: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -558,6 +1328,64 @@ pub enum RodType {
     Emergency,   // Emergency protection (AZ)
 }
 
+/// Worth curve shape exponent of each rod type (1.0 = symmetric S-curve)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RodWorthShapes {
+    pub manual: f64,
+    pub automatic: f64,
+    pub shortened: f64,
+    pub emergency: f64,
+}
+
+impl Default for RodWorthShapes {
+    fn default() -> Self {
+        Self {
+            manual: constants::ROD_WORTH_SHAPE_NOMINAL,
+            automatic: constants::ROD_WORTH_SHAPE_NOMINAL,
+            shortened: constants::ROD_WORTH_SHAPE_USP,
+            emergency: constants::ROD_WORTH_SHAPE_NOMINAL,
+        }
+    }
+}
+
+impl RodWorthShapes {
+    pub fn get(&self, rod_type: &RodType) -> f64 {
+        match rod_type {
+            RodType::Manual => self.manual,
+            RodType::Automatic => self.automatic,
+            RodType::Shortened => self.shortened,
+            RodType::Emergency => self.emergency,
+        }
+    }
+
+    fn get_mut(&mut self, rod_type: &RodType) -> &mut f64 {
+        match rod_type {
+            RodType::Manual => &mut self.manual,
+            RodType::Automatic => &mut self.automatic,
+            RodType::Shortened => &mut self.shortened,
+            RodType::Emergency => &mut self.emergency,
+        }
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let shapes = [self.manual, self.automatic, self.shortened, self.emergency];
+        match shapes.iter().find(|s| !(constants::ROD_WORTH_SHAPE_MIN..=constants::ROD_WORTH_SHAPE_MAX).contains(*s)) {
+            Some(shape) => Err(format!(
+                "Rod worth shape {} out of range [{}, {}]",
+                shape, constants::ROD_WORTH_SHAPE_MIN, constants::ROD_WORTH_SHAPE_MAX
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+/// One point of a rod worth curve
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RodWorthPoint {
+    pub position: f64,       // 0.0 = fully inserted, 1.0 = fully withdrawn
+    pub worth_fraction: f64, // Inserted worth relative to the full rod worth
+}
+
 /// Automatic power regulator settings (AR/LAR)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AutoRegulatorSettings {
@@ -588,17 +1416,140 @@ impl Default for AutoRegulatorSettings {
     }
 }
 
-/// Complete reactor state
+/// Fuel thermal conductivity model of the per-channel thermal step
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FuelConductivityModel {
+    #[default]
+    Constant,       // Fresh-fuel conductivity at any burnup
+    BurnupDegraded, // Conductivity falls with burnup, aged fuel runs hotter
+}
+
+/// Unit for displaying reactivity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ReactivityUnit {
+    DeltaK,  // Δk/k
+    #[default]
+    Dollars, // $ (units of β_eff)
+    Cents,   // 0.01 $
+    Pcm,     // per cent mille, 1e-5 Δk/k
+}
+
+impl ReactivityUnit {
+    /// Convert a reactivity in Δk/k to this unit
+    pub fn convert(self, reactivity: f64) -> f64 {
+        match self {
+            ReactivityUnit::DeltaK => reactivity,
+            ReactivityUnit::Dollars => reactivity / constants::BETA_EFF,
+            ReactivityUnit::Cents => reactivity / constants::BETA_EFF * 100.0,
+            ReactivityUnit::Pcm => reactivity * 1.0e5,
+        }
+    }
+}
+
+/// Criticality condition of the core
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Criticality {
+    Subcritical,          // ρ < -deadband: power decays
+    Critical,             // |ρ| within the deadband: power steady
+    DelayedSupercritical, // Power rises on the delayed-neutron time scale
+    PromptCritical,       // ρ ≥ 1 $: power rises on prompt neutrons alone
+}
+
+impl Criticality {
+    /// Classify a reactivity in dollars
+    pub fn from_dollars(reactivity_dollars: f64) -> Self {
+        if reactivity_dollars >= constants::PROMPT_CRITICAL_DOLLARS {
+            Criticality::PromptCritical
+        } else if reactivity_dollars > constants::CRITICAL_DEADBAND_DOLLARS {
+            Criticality::DelayedSupercritical
+        } else if reactivity_dollars >= -constants::CRITICAL_DEADBAND_DOLLARS {
+            Criticality::Critical
+        } else {
+            Criticality::Subcritical
+        }
+    }
+}
+
+/// Verdict on the end state of a run, for grading scenarios
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Outcome {
+    Exploded { severity: f64 },       // Steam explosion latched
+    CoreDamaged,                      // Ruptured channels or molten fuel, no explosion
+    SafeShutdown,                     // Subcritical with power below the low-power threshold
+    Scrammed,                         // SCRAM in progress, power not yet down
+    StableOperation { power: f64 },   // Critical at power [% nominal]
+    Transient { power: f64 },         // Power still changing, no SCRAM [% nominal]
+}
+
+/// Setpoint as requested and as applied after clamping to its range
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ReactorState {
-    // Time
+pub struct ClampedSetpoint {
+    pub requested: f64,
+    pub applied: f64,
+    pub clamped: bool, // applied != requested
+    pub min: f64,
+    pub max: f64,
+}
+
+impl ClampedSetpoint {
+    pub fn new(requested: f64, min: f64, max: f64) -> Self {
+        let applied = requested.clamp(min, max);
+        Self { requested, applied, clamped: applied != requested, min, max }
+    }
+}
+
+/// Region of the power-flow map
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OperatingRegion {
+    Shutdown,  // Below the low-power threshold
+    Allowed,   // Inside the operating envelope
+    Unstable,  // Low power: void feedback dominates and flow is unstable
+    OverLimit, // Above the power limit or too little flow for the power
+}
+
+impl OperatingRegion {
+    /// Classify a point of the power-flow map (both in % of nominal)
+    pub fn classify(power_percent: f64, flow_percent: f64) -> Self {
+        if power_percent > constants::POWER_LIMIT_PERCENT
+            || power_percent > flow_percent * constants::MAX_POWER_TO_FLOW_RATIO
+        {
+            OperatingRegion::OverLimit
+        } else if power_percent < constants::LOW_POWER_THRESHOLD_PERCENT {
+            OperatingRegion::Shutdown
+        } else if power_percent < constants::OPERATING_MIN_POWER_PERCENT {
+            OperatingRegion::Unstable
+        } else {
+            OperatingRegion::Allowed
+        }
+    }
+}
+
+/// Current point on the power-flow map
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperatingPoint {
+    pub power_percent: f64, // [% nominal]
+    pub flow_percent: f64,  // Total core flow [% nominal]
+    pub region: OperatingRegion,
+}
+
+/// Complete reactor state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReactorState {
+    // Time
     pub time: f64,           // Simulation time [s]
     pub dt: f64,             // Time step [s]
     
     // Power and neutronics
     pub power_mw: f64,       // Thermal power [MW]
     pub power_percent: f64,  // Power as % of nominal
+    
+    /// Neutron population relative to nominal (1.0 = 100% power)
+    /// This is the kinetics variable of both stepping paths. `step()` takes it
+    /// from point kinetics; `step_spatial()` from the core power, which sums
+    /// the channel fluxes weighted by their power shape.
     pub neutron_population: f64,
+    pub neutron_population_relative: f64, // Same as `neutron_population`, named for clarity
+    pub neutron_population_absolute: f64, // Core-average flux [n/cm²/s]
     pub precursors: f64,     // Total delayed neutron precursors (sum of 6 groups)
     
     /// 6-group delayed neutron precursor concentrations
@@ -614,7 +1565,34 @@ pub struct ReactorState {
     pub k_eff: f64,          // Effective multiplication factor
     pub reactivity: f64,     // Total reactivity [Δk/k]
     pub reactivity_dollars: f64, // Reactivity in dollars
+    #[serde(default)]
+    pub reactivity_cents: f64,   // Reactivity in cents (0.01 $)
+    #[serde(default)]
+    pub reactivity_pcm: f64,     // Reactivity in pcm (1e-5 Δk/k)
+    #[serde(default)]
+    pub reactivity_display_unit: ReactivityUnit, // Unit chosen for display
+    #[serde(default)]
+    pub reactivity_display: f64, // Reactivity in `reactivity_display_unit`
     pub period: f64,         // Reactor period [s]
+    #[serde(default)]
+    pub reactivity_rate: f64, // Rate of change of reactivity [Δk/k per s]
+    
+    // Startup (low-power) regime: power and period alerts are suppressed,
+    // source-range count rate and startup rate are the meaningful indicators
+    #[serde(default)]
+    pub low_power_mode: bool,
+    #[serde(default)]
+    pub count_rate: f64,       // Source-range count rate [counts/s]
+    #[serde(default)]
+    pub startup_rate_dpm: f64, // Startup rate from count-rate change [decades/min]
+    
+    // Lumped drum/circuit water inventory: boil-off drains it, feedwater refills it
+    #[serde(default = "full_fraction")]
+    pub coolant_inventory_fraction: f64, // 1.0 = normal level, 0.0 = empty
+    #[serde(default = "full_fraction")]
+    pub feedwater_fraction: f64,         // Feedwater flow (1.0 = steaming rate at nominal power)
+    #[serde(default = "nominal_inlet_temp")]
+    pub inlet_plenum_temp: f64,          // Common inlet plenum feeding every channel [K]
     
     // Xenon poisoning
     pub iodine_135: f64,     // I-135 concentration [atoms/cm³]
@@ -626,6 +1604,14 @@ pub struct ReactorState {
     pub avg_coolant_temp: f64,   // [K]
     pub avg_graphite_temp: f64,  // [K]
     pub avg_coolant_void: f64,   // [%]
+    /// Debug mode: temperatures and void are held at their current values
+    /// (their reactivity feedback still applies, but no longer changes)
+    #[serde(default)]
+    pub thermal_frozen: bool,
+    /// Iodine and xenon are held at their current values (xenon reactivity
+    /// still applies, but no longer changes)
+    #[serde(default)]
+    pub xenon_frozen: bool,
     
     // Control
     pub scram_active: bool,
@@ -639,16 +1625,152 @@ pub struct ReactorState {
     
     // Alerts
     pub alerts: Vec<String>,
+    /// Structured form of `alerts` (same order, one entry per message)
+    #[serde(default)]
+    pub alert_details: Vec<Alert>,
     
     // Steam explosion state
     pub explosion_occurred: bool,
     pub explosion_time: f64,  // Time when explosion occurred [s]
+    /// Fortran core damage index: rises toward 1.0, explosion at ≥ 1.0
+    /// Frozen at the triggering value once the explosion has occurred.
+    #[serde(default)]
+    pub explosion_severity: f64,
+    
+    // Core age: operating time weighted by power fraction, since fresh fuel
+    #[serde(default)]
+    pub full_power_hours: f64, // Effective full-power hours [EFPH]
     
     // Smoothed reactivity for numerical stability
     #[serde(skip)]
     pub smoothed_reactivity: f64,
 }
 
+impl ReactorState {
+    /// Raise an alert for this step (message plus its structured form)
+    /// Alerts that are meaningless at low power are dropped in the startup regime.
+    fn raise_alert(&mut self, code: AlertCode, message: String) {
+        if self.low_power_mode && code.suppressed_at_low_power() {
+            return;
+        }
+        self.alert_details.push(Alert::new(code, message.clone()));
+        self.alerts.push(message);
+    }
+    
+    /// Put this step's alerts in annunciation order (severity, then code)
+    /// `alerts` is rebuilt so it stays parallel to `alert_details`.
+    fn sort_alerts(&mut self) {
+        alerts::sort_by_priority(&mut self.alert_details);
+        self.alerts = self.alert_details.iter().map(|a| a.message.clone()).collect();
+    }
+    
+    /// Clear the alerts of the previous step
+    fn clear_alerts(&mut self) {
+        self.alerts.clear();
+        self.alert_details.clear();
+    }
+    
+    /// Derive dollars, cents, pcm and the display value from `reactivity`
+    fn update_reactivity_units(&mut self) {
+        self.reactivity_dollars = self.reactivity / constants::BETA_EFF;
+        self.reactivity_cents = self.reactivity_dollars * 100.0;
+        self.reactivity_pcm = self.reactivity * 1.0e5;
+        self.reactivity_display = self.reactivity_display_unit.convert(self.reactivity);
+    }
+    
+    /// Set the neutron population (relative to nominal) in all its forms
+    fn set_neutron_population(&mut self, relative: f64) {
+        self.neutron_population = relative;
+        self.neutron_population_relative = relative;
+        self.neutron_population_absolute = relative * constants::NOMINAL_CORE_FLUX;
+    }
+    
+    /// Core-average thermal flux that drives the xenon chain [n/cm²/s]
+    /// (no fission below 0.1 % power, as in the point kinetics)
+    fn xenon_flux(&self) -> f64 {
+        if self.power_percent > 0.1 {
+            self.neutron_population * constants::NOMINAL_CORE_FLUX
+        } else {
+            0.0
+        }
+    }
+    
+    /// Update the low-power regime flag and count-rate startup indicators
+    /// Call after `neutron_population` and `power_percent` are updated for the step.
+    fn update_startup_indicators(&mut self, previous_population: f64, dt: f64) {
+        self.low_power_mode = self.power_percent < constants::LOW_POWER_THRESHOLD_PERCENT;
+        self.count_rate = self.neutron_population * constants::SOURCE_RANGE_CPS_PER_UNIT;
+        self.startup_rate_dpm = if dt > 0.0 && previous_population > 0.0 && self.neutron_population > 0.0 {
+            60.0 * (self.neutron_population / previous_population).log10() / dt
+        } else {
+            0.0
+        };
+    }
+    
+    /// Update the automatic regulator's PID integral and derivative terms
+    /// after the power of the step is known (both stepping modes)
+    fn update_auto_regulator(&mut self, dt: f64) {
+        if !self.auto_regulator.enabled || self.scram_active {
+            return;
+        }
+        let error = self.auto_regulator.target_power - self.power_percent;
+        
+        // Anti-windup: limit integral accumulation and decay when near target
+        let max_integral = 100.0;
+        let ar = &mut self.auto_regulator;
+        if error.abs() > ar.deadband {
+            ar.integral_error = (ar.integral_error + error * dt).clamp(-max_integral, max_integral);
+        } else {
+            // Slowly decay integral when within deadband to prevent windup
+            ar.integral_error *= 0.99;
+        }
+        ar.last_error = error;
+    }
+    
+    /// Drain the coolant inventory by boil-off at the current power and
+    /// refill it with feedwater, where 1.0 matches steaming at `nominal_power_mw`
+    fn update_coolant_inventory(&mut self, nominal_power_mw: f64, dt: f64) {
+        let boil_off = self.power_mw.max(0.0) * 1.0e6 / constants::LATENT_HEAT_J_PER_KG;
+        let feedwater = self.feedwater_fraction * nominal_power_mw * 1.0e6 / constants::LATENT_HEAT_J_PER_KG;
+        self.coolant_inventory_fraction = (self.coolant_inventory_fraction
+            + (feedwater - boil_off) * dt / constants::COOLANT_INVENTORY_KG)
+            .clamp(0.0, 1.0);
+    }
+    
+    /// Relax the inlet plenum toward the mix of feedwater and returning drum
+    /// water, which comes back at the core coolant temperature but no hotter
+    /// than saturation. The feedwater share scales with the feedwater flow.
+    fn update_inlet_plenum(&mut self, saturation_temp: f64, mixing_time: f64, dt: f64) {
+        let share = (constants::FEEDWATER_INLET_SHARE_NOMINAL * self.feedwater_fraction).min(1.0);
+        let returning = self.avg_coolant_temp.min(saturation_temp);
+        let mixed = share * constants::FEEDWATER_TEMP_K + (1.0 - share) * returning;
+        self.inlet_plenum_temp += (dt / mixing_time).min(1.0) * (mixed - self.inlet_plenum_temp);
+    }
+    
+    /// Advance the clock, accumulating effective full-power time at the current power
+    fn advance_time(&mut self, dt: f64) {
+        self.time += dt;
+        self.full_power_hours += dt * self.power_percent.max(0.0) / 100.0 / 3600.0;
+    }
+    
+    /// Debug-build guard: the scalar `precursors` must equal the sum of `precursors_6`
+    /// The 6-group array is the source of truth; the scalar is derived from it.
+    /// Only `step()` is covered: the spatial solver's channels carry
+    /// single-group precursors that the 6-group set does not track.
+    fn debug_assert_precursors_consistent(&self) {
+        if cfg!(debug_assertions) {
+            let group_sum: f64 = self.precursors_6.iter().sum();
+            let tolerance = 1e-9 * self.precursors.abs().max(1.0);
+            debug_assert!(
+                (group_sum - self.precursors).abs() <= tolerance,
+                "precursor state diverged: sum(precursors_6) = {}, precursors = {}",
+                group_sum,
+                self.precursors
+            );
+        }
+    }
+}
+
 impl Default for ReactorState {
     fn default() -> Self {
         // Create flat flux distribution (reactor is shutdown)
@@ -662,12 +1784,25 @@ impl Default for ReactorState {
             power_mw: 0.0,           // Shutdown - no power
             power_percent: 0.0,      // Shutdown - 0%
             neutron_population: 1e-6, // Very low neutron source (subcritical)
+            neutron_population_relative: 1e-6,
+            neutron_population_absolute: 1e-6 * constants::NOMINAL_CORE_FLUX,
             precursors: 0.0,         // No precursors - fresh start
             precursors_6: [0.0; constants::NUM_DELAYED_GROUPS], // All 6 groups at zero
             k_eff: 0.95,             // Subcritical
             reactivity: -0.05,       // Negative reactivity (subcritical)
             reactivity_dollars: -7.7, // About -7.7$ (deeply subcritical)
+            reactivity_cents: -770.0,
+            reactivity_pcm: -5000.0,
+            reactivity_display_unit: ReactivityUnit::Dollars,
+            reactivity_display: -7.7,
             period: f64::INFINITY,
+            reactivity_rate: 0.0,
+            low_power_mode: true,    // Shutdown is below the low-power threshold
+            count_rate: 1e-6 * constants::SOURCE_RANGE_CPS_PER_UNIT,
+            startup_rate_dpm: 0.0,
+            coolant_inventory_fraction: 1.0,
+            feedwater_fraction: 1.0,
+            inlet_plenum_temp: channel_defaults::INLET_TEMP_K,
             iodine_135: 0.0,         // No iodine - fresh start, no xenon pit
             xenon_135: 0.0,          // No xenon - fresh start, no xenon pit
             xenon_reactivity: 0.0,   // No xenon poisoning
@@ -675,186 +1810,1628 @@ impl Default for ReactorState {
             avg_coolant_temp: 300.0, // Cold - room temperature
             avg_graphite_temp: 300.0, // Cold - room temperature
             avg_coolant_void: 0.0,
+            thermal_frozen: false,
+            xenon_frozen: false,
             scram_active: false,
             scram_time: 0.0,
             auto_regulator: AutoRegulatorSettings::default(),
             axial_flux,
             alerts: Vec::new(),
+            alert_details: Vec::new(),
             explosion_occurred: false,
             explosion_time: 0.0,
+            explosion_severity: 0.0,
+            full_power_hours: 0.0,   // Fresh core
             smoothed_reactivity: -0.05,
         }
     }
 }
 
-/// Reactor simulation engine
-pub struct ReactorSimulator {
-    pub state: Mutex<ReactorState>,
-    pub control_rods: Mutex<Vec<ControlRod>>,
-    pub fuel_channels: Mutex<Vec<FuelChannel>>,
-    pub running: Mutex<bool>,
+/// Signed change of the key scalar state values across one simulation step
+/// Used for physics debugging and "rate of change" readouts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StateDelta {
+    pub time: f64,               // Simulation time after the step [s]
+    pub dt: f64,                 // Simulated time covered by the delta [s]
+    pub power_mw: f64,           // [MW]
+    pub power_percent: f64,      // [% nominal]
+    pub reactivity: f64,         // [Δk/k]
+    pub reactivity_dollars: f64, // [$]
+    pub k_eff: f64,
+    pub period: f64,             // [s] (0 if unchanged, including infinite -> infinite)
+    pub avg_fuel_temp: f64,      // [K]
+    pub avg_coolant_temp: f64,   // [K]
+    pub avg_graphite_temp: f64,  // [K]
+    pub avg_coolant_void: f64,   // [%]
+    pub iodine_135: f64,         // [atoms/cm³]
+    pub xenon_135: f64,          // [atoms/cm³]
 }
 
-impl Default for ReactorSimulator {
-    fn default() -> Self {
-        Self::new()
+impl StateDelta {
+    /// Compute the signed deltas (after - before) between two state snapshots
+    pub fn between(before: &ReactorState, after: &ReactorState) -> Self {
+        // Equal values (including both infinite) give zero instead of NaN
+        fn delta(before: f64, after: f64) -> f64 {
+            if before == after { 0.0 } else { after - before }
+        }
+
+        Self {
+            time: after.time,
+            dt: after.time - before.time,
+            power_mw: delta(before.power_mw, after.power_mw),
+            power_percent: delta(before.power_percent, after.power_percent),
+            reactivity: delta(before.reactivity, after.reactivity),
+            reactivity_dollars: delta(before.reactivity_dollars, after.reactivity_dollars),
+            k_eff: delta(before.k_eff, after.k_eff),
+            period: delta(before.period, after.period),
+            avg_fuel_temp: delta(before.avg_fuel_temp, after.avg_fuel_temp),
+            avg_coolant_temp: delta(before.avg_coolant_temp, after.avg_coolant_temp),
+            avg_graphite_temp: delta(before.avg_graphite_temp, after.avg_graphite_temp),
+            avg_coolant_void: delta(before.avg_coolant_void, after.avg_coolant_void),
+            iodine_135: delta(before.iodine_135, after.iodine_135),
+            xenon_135: delta(before.xenon_135, after.xenon_135),
+        }
     }
 }
 
-impl ReactorSimulator {
-    pub fn new() -> Self {
-        // Load control rods from OPB-82 layout config
-        let control_rods = load_control_rods_from_config();
-        
-        // Load fuel channels from OPB-82 layout config (1661 TK cells)
-        let mut fuel_channels = load_fuel_channels_from_config();
-        
-        // Link control rods to fuel channels for local reactivity effects
-        link_control_rods_to_channels(&mut fuel_channels, &control_rods);
-        
-        Self {
-            state: Mutex::new(ReactorState::default()),
-            control_rods: Mutex::new(control_rods),
-            fuel_channels: Mutex::new(fuel_channels),
-            running: Mutex::new(false),
+/// Core-minimum departure from nucleate boiling ratio
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MinDnbr {
+    pub channel_id: usize,
+    pub dnbr: f64,
+}
+
+/// Thresholds selecting fuel channels; absent bounds do not filter
+/// A channel matches when it satisfies every bound given.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChannelFilter {
+    pub min_power_mw: Option<f64>,
+    pub max_power_mw: Option<f64>,
+    pub min_fuel_temp: Option<f64>,   // [K]
+    pub max_fuel_temp: Option<f64>,   // [K]
+    pub min_coolant_void: Option<f64>, // [%]
+    pub max_coolant_void: Option<f64>, // [%]
+}
+
+impl ChannelFilter {
+    pub fn matches(&self, channel: &FuelChannel) -> bool {
+        let within = |value: f64, min: Option<f64>, max: Option<f64>| {
+            min.is_none_or(|min| value >= min) && max.is_none_or(|max| value <= max)
+        };
+        within(channel.local_power, self.min_power_mw, self.max_power_mw)
+            && within(channel.fuel_temp, self.min_fuel_temp, self.max_fuel_temp)
+            && within(channel.coolant_void, self.min_coolant_void, self.max_coolant_void)
+    }
+}
+
+/// Key values of one fuel channel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChannelSummary {
+    pub id: usize,
+    pub grid_x: i32,
+    pub grid_y: i32,
+    pub local_power: f64,  // [MW]
+    pub fuel_temp: f64,    // [K]
+    pub coolant_temp: f64, // [K]
+    pub coolant_void: f64, // [%]
+    pub dnbr: f64,
+}
+
+/// Core-minimum coolant subcooling (saturation minus coolant temperature)
+/// Negative means the channel coolant is above saturation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubcoolingMargin {
+    pub channel_id: usize,
+    pub coolant_temp: f64,    // [K]
+    pub saturation_temp: f64, // At the channel pressure [K]
+    pub margin: f64,          // saturation_temp - coolant_temp [K]
+}
+
+/// Channel with the lowest DNBR (None for an empty core)
+fn min_dnbr(channels: &[FuelChannel]) -> Option<MinDnbr> {
+    channels
+        .iter()
+        .min_by(|a, b| a.dnbr.total_cmp(&b.dnbr))
+        .map(|c| MinDnbr { channel_id: c.id, dnbr: c.dnbr })
+}
+
+/// Projection for a single monitored limit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LimitProjection {
+    pub limit: String,
+    pub current: f64,
+    pub setpoint: f64,
+    pub seconds_to_trip: Option<f64>, // None = not approaching the limit
+}
+
+/// Automatic protection trips: each SCRAMs the reactor when its limit is
+/// reached, unless bypassed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Trip {
+    PowerHigh,   // Power at or above POWER_LIMIT_PERCENT
+    ShortPeriod, // Positive period at or below PERIOD_LIMIT_S (startup rate trip)
+}
+
+impl Trip {
+    pub const ALL: [Trip; 2] = [Trip::PowerHigh, Trip::ShortPeriod];
+    
+    pub fn setpoint(self) -> f64 {
+        match self {
+            Trip::PowerHigh => constants::POWER_LIMIT_PERCENT,
+            Trip::ShortPeriod => constants::PERIOD_LIMIT_S,
         }
     }
     
-    /// Calculate total control rod worth (how much is inserted)
-    fn calculate_total_rod_worth(&self) -> f64 {
-        let control_rods = self.control_rods.lock().unwrap();
-        control_rods.iter()
-            .map(|rod| {
-                let insertion = 1.0 - rod.position;
-                rod.worth * insertion
-            })
-            .sum()
+    /// Whether the trip condition is present in `state`
+    fn condition_present(self, state: &ReactorState) -> bool {
+        match self {
+            Trip::PowerHigh => state.power_percent >= self.setpoint(),
+            Trip::ShortPeriod => state.period.is_finite() && state.period > 0.0 && state.period <= self.setpoint(),
+        }
+    }
+}
+
+/// Armed/bypassed state of one protection trip
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TripStatus {
+    pub trip: Trip,
+    pub setpoint: f64,
+    pub bypassed: bool,
+    pub condition_present: bool, // Limit reached right now (trips unless bypassed)
+}
+
+/// Time-to-trip projection for all monitored limits, assuming nothing changes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TripProjection {
+    pub limits: Vec<LimitProjection>,
+    pub soonest: Option<LimitProjection>,
+}
+
+/// Look-ahead of the xenon transient with power held where it is
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct XenonProjection {
+    pub hours_to_peak: f64,         // 0 when xenon is already falling
+    pub peak_xenon_135: f64,        // [atoms/cm³]
+    pub current_reactivity: f64,    // Xenon reactivity now [Δk/k]
+    pub peak_reactivity: f64,       // Xenon reactivity at the peak [Δk/k]
+    pub additional_reactivity: f64, // peak - current (negative: the pit still deepens)
+    pub peak_within_horizon: bool,  // false: still rising at the end of the look-ahead
+}
+
+/// Control rod as sent with every UI frame (the full rod only changes on layout load)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrameRod {
+    pub id: usize,
+    pub grid_x: i32,
+    pub grid_y: i32,
+    pub position: f64, // 0.0 = fully inserted, 1.0 = fully withdrawn
+    pub stuck: bool,
+}
+
+/// Fuel channel as sent with every UI frame
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrameChannel {
+    pub id: usize,
+    pub grid_x: i32,
+    pub grid_y: i32,
+    pub local_power: f64,  // [MW]
+    pub fuel_temp: f64,    // [K]
+    pub coolant_void: f64, // [%]
+    pub ruptured: bool,
+}
+
+/// State, rods and channels of the same instant, for one UI frame
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrameData {
+    pub state: ReactorState,
+    pub control_rods: Vec<FrameRod>,
+    pub fuel_channels: Vec<FrameChannel>,
+}
+
+/// Axial power split between the top and bottom halves of the core
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AxialPowerOffset {
+    pub top_fraction: f64,
+    pub bottom_fraction: f64,
+    pub offset: f64,  // (top - bottom) / (top + bottom), -1..1
+}
+
+/// Power statistics of one concentric ring of the core
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RingStat {
+    pub ring: usize,            // 0 = innermost
+    pub inner_radius_cm: f64,
+    pub outer_radius_cm: f64,
+    pub channel_count: usize,
+    pub avg_power_density: f64, // [MW/m³], 0 for an empty ring
+}
+
+/// Burnup distribution over all fuel channels [MWd/kgU]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BurnupStats {
+    pub average: f64,
+    pub min: f64,
+    pub max: f64,
+    pub std_dev: f64,           // Population standard deviation
+    pub peak_to_average: f64,   // max / average, 0 for fresh fuel
+}
+
+/// Consistency of the per-channel powers with the global power
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PowerBalance {
+    pub global_power_mw: f64,      // Displayed core power
+    pub channel_power_sum_mw: f64, // Σ local_power
+    pub density_integral_mw: f64,  // Σ power_density · channel volume
+    pub discrepancy_mw: f64,       // channel_power_sum_mw - global_power_mw
+    pub discrepancy_percent: f64,  // Relative to global power (0 if it is zero)
+    pub normalized: bool,          // Channel powers are rescaled every spatial step
+}
+
+/// Simulation time of the last significant events [s] (None = not since reset)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct EventTimes {
+    pub last_scram: Option<f64>,
+    pub last_alert: Option<f64>,     // A new alert was raised
+    pub last_rod_move: Option<f64>,  // Operator rod move (regulator and SCRAM motion excluded)
+    pub last_explosion: Option<f64>,
+}
+
+/// Time step of the last step after kinetics sub-stepping
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct EffectiveTimeStep {
+    pub dt: f64,           // Requested step [s]
+    pub substeps: usize,   // Kinetics sub-steps (1 = not sub-stepped)
+    pub effective_dt: f64, // dt / substeps [s]
+}
+
+/// Radial peaking factor: hottest channel power over the mean (0 without power)
+fn radial_peaking_factor(channels: &[FuelChannel]) -> f64 {
+    if channels.is_empty() {
+        return 0.0;
+    }
+    let mean = channels.iter().map(|c| c.local_power).sum::<f64>() / channels.len() as f64;
+    if mean <= 0.0 {
+        return 0.0;
+    }
+    channels.iter().map(|c| c.local_power).fold(0.0, f64::max) / mean
+}
+
+/// Share of each delayed group in the delayed neutron source, λᵢ·Cᵢ / Σ λⱼ·Cⱼ
+/// All zero when there are no precursors.
+fn delayed_source_fractions(precursors: &[f64; constants::NUM_DELAYED_GROUPS]) -> [f64; constants::NUM_DELAYED_GROUPS] {
+    let source: [f64; constants::NUM_DELAYED_GROUPS] =
+        std::array::from_fn(|g| constants::LAMBDA_I[g] * precursors[g].max(0.0));
+    let total: f64 = source.iter().sum();
+    if total > 0.0 {
+        source.map(|s| s / total)
+    } else {
+        [0.0; constants::NUM_DELAYED_GROUPS]
+    }
+}
+
+/// Multiplication factor for a reactivity, ρ = (k − 1)/k
+/// Same definition and limits as the Fortran `simulation_step`, so both
+/// stepping modes report a coherent k_eff.
+fn k_eff_from_reactivity(reactivity: f64) -> f64 {
+    if reactivity.abs() < 0.99 {
+        1.0 / (1.0 - reactivity)
+    } else if reactivity > 0.0 {
+        100.0
+    } else {
+        0.01
+    }
+}
+
+/// Debug-build check that a step's reactivity breakdown accounts for its reactivity
+///
+/// At the conditions the step started from, the components must sum to the
+/// backend's unsmoothed target. `smoothing` holds the reactivity the point
+/// kinetics started from and the one they used: relaxing toward the target
+/// they may close the lag to the components' sum but never widen it (unless
+/// they started outside their own clamp range, e.g. after a spatial step).
+fn debug_assert_reactivity_balance(breakdown: &ReactivityBreakdown, smoothing: Option<(f64, f64)>) {
+    if !cfg!(debug_assertions) {
+        return;
+    }
+    let sum = breakdown.component_sum();
+    let tolerance = constants::REACTIVITY_BALANCE_TOLERANCE;
+    debug_assert!(
+        (breakdown.total - sum).abs() <= tolerance,
+        "Reactivity components miss {:e} Δk/k of the target",
+        breakdown.total - sum
+    );
+    if let Some((previous, used)) = smoothing {
+        let clamp_range = constants::REACTIVITY_FLOOR..=constants::REACTIVITY_CEILING;
+        debug_assert!(
+            !clamp_range.contains(&previous) || (used - sum).abs() <= (previous - sum).abs() + tolerance,
+            "Kinetics reactivity moved away from the components' sum {:e}: {:e} -> {:e}",
+            sum,
+            previous,
+            used
+        );
+    }
+}
+
+/// Channel id at each occupied grid cell
+fn build_grid_index(channels: &[FuelChannel]) -> HashMap<(i32, i32), usize> {
+    channels.iter().map(|c| ((c.grid_x, c.grid_y), c.id)).collect()
+}
+
+/// Stable hash of the core geometry: channel grid positions and each rod's
+/// grid position, type and worth (not its insertion, which changes in a run)
+///
+/// FNV-1a rather than `DefaultHasher`, whose output may change between Rust
+/// releases, so hashes from different builds and runs can be compared.
+fn layout_hash(channels: &[FuelChannel], rods: &[ControlRod]) -> u64 {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+    fn feed(hash: u64, bytes: &[u8]) -> u64 {
+        bytes.iter().fold(hash, |h, &b| (h ^ b as u64).wrapping_mul(FNV_PRIME))
     }
     
-    /// Perform one simulation step using Fortran physics
-    pub fn step(&self) {
-        // First, run automatic regulator if enabled (before physics step)
-        // This needs to be done with separate locks to avoid deadlock
-        let (ar_enabled, ar_target, ar_settings, current_power, dt, scram_active) = {
-            let state = self.state.lock().unwrap();
-            (
-                state.auto_regulator.enabled,
-                state.auto_regulator.target_power,
-                state.auto_regulator.clone(),
-                state.power_percent,
-                state.dt,
-                state.scram_active,
-            )
+    let mut hash = feed(FNV_OFFSET_BASIS, &(channels.len() as u64).to_le_bytes());
+    for channel in channels {
+        hash = feed(hash, &(channel.id as u64).to_le_bytes());
+        hash = feed(hash, &channel.grid_x.to_le_bytes());
+        hash = feed(hash, &channel.grid_y.to_le_bytes());
+    }
+    hash = feed(hash, &(rods.len() as u64).to_le_bytes());
+    for rod in rods {
+        let rod_type: u8 = match rod.rod_type {
+            RodType::Manual => 0,
+            RodType::Automatic => 1,
+            RodType::Shortened => 2,
+            RodType::Emergency => 3,
         };
-        
-        // Run automatic regulator (AR/LAR) - PID control for power
-        if ar_enabled && !scram_active {
-            let rod_adjustment = self.calculate_ar_adjustment(&ar_settings, current_power, dt);
-            if rod_adjustment.abs() > 1e-6 {
-                self.adjust_automatic_rods(rod_adjustment);
-            }
+        hash = feed(hash, &(rod.id as u64).to_le_bytes());
+        hash = feed(hash, &rod.grid_x.to_le_bytes());
+        hash = feed(hash, &rod.grid_y.to_le_bytes());
+        hash = feed(hash, &[rod_type]);
+        hash = feed(hash, &(rod.channel_type.len() as u64).to_le_bytes());
+        hash = feed(hash, rod.channel_type.as_bytes());
+        hash = feed(hash, &rod.worth.to_bits().to_le_bytes());
+    }
+    hash
+}
+
+/// Core-average conditions that set the feedback reactivity
+#[derive(Debug, Clone, Copy)]
+struct FeedbackConditions {
+    fuel_temp: f64,     // [K]
+    graphite_temp: f64, // [K]
+    coolant_void: f64,  // [%]
+    xenon_135: f64,     // [atoms/cm³]
+}
+
+/// Fraction of the axial flux in the bottom half of the core (point 0 = bottom)
+fn axial_bottom_fraction(axial_flux: &[f64]) -> f64 {
+    let total: f64 = axial_flux.iter().sum();
+    if total <= 0.0 {
+        return 0.5;
+    }
+    let bottom: f64 = axial_flux[..axial_flux.len() / 2].iter().sum();
+    bottom / total
+}
+
+/// Every runtime-tunable knob of the simulator, for saving and restoring a
+/// complete tuning. Alert thresholds and thermal properties are compile-time
+/// constants (see `constants`) and are not part of it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimulationParameters {
+    pub dt: f64,                    // Time step [s]
+    pub neutron_lifetime: f64,      // Prompt neutron lifetime Λ [s]
+    pub scram_insertion_time: f64,  // Full-stroke SCRAM insertion time [s]
+    pub alert_hold_time: f64,       // Annunciation hold after an alert clears [s]
+    pub feedwater_fraction: f64,    // Feedwater (1.0 = steaming rate at nominal power)
+    #[serde(default = "nominal_gap_conductance")]
+    pub graphite_gap_conductance: f64, // Graphite gas-gap conductance (1.0 = nominal)
+    #[serde(default = "nominal_graphite_cooling")]
+    pub graphite_cooling_effectiveness: f64, // Graphite gas-circuit cooling (1.0 = nominal)
+    #[serde(default = "default_reactivity_smoothing_tau")]
+    pub reactivity_smoothing_tau: f64, // Reactivity smoothing outside a SCRAM [s]
+    #[serde(default = "default_plenum_mixing_time")]
+    pub plenum_mixing_time: f64,       // Inlet plenum mixing time constant [s]
+    #[serde(default = "default_channel_volume")]
+    pub channel_volume_m3: f64,        // Fuel volume per channel for power density [m³]
+    #[serde(default)]
+    pub fuel_conductivity_model: FuelConductivityModel, // Fuel conductivity vs burnup (spatial model)
+    #[serde(default = "default_absorber_depletion_rate")]
+    pub absorber_depletion_rate: f64,  // Rod absorber burnout [1/s at full insertion and power]
+    #[serde(default)]
+    pub rod_worth_shapes: RodWorthShapes, // Worth curve shape per rod type
+    pub reactivity_display_unit: ReactivityUnit,
+    pub ar_kp: f64,                 // Automatic regulator PID gains
+    pub ar_ki: f64,
+    pub ar_kd: f64,
+    pub ar_rod_speed: f64,          // Max automatic rod speed [fraction/s]
+    pub ar_deadband: f64,           // Power error deadband [%]
+}
+
+impl SimulationParameters {
+    /// Check every field against the ranges the individual setters accept
+    pub fn validate(&self) -> Result<(), String> {
+        if !(constants::TIME_STEP_MIN_S..=constants::TIME_STEP_MAX_S).contains(&self.dt) {
+            return Err(format!(
+                "Time step {} s out of range [{}, {}]",
+                self.dt, constants::TIME_STEP_MIN_S, constants::TIME_STEP_MAX_S
+            ));
         }
-        
-        let mut state = self.state.lock().unwrap();
-        
-        state.alerts.clear();
-        let dt = state.dt;
-        
-        // Calculate total control rod worth
-        let total_rod_worth = self.calculate_total_rod_worth();
-        
-        // Handle SCRAM timing
-        if state.scram_active {
-            state.scram_time += dt;
+        if !(constants::NEUTRON_LIFETIME_MIN..=constants::NEUTRON_LIFETIME_MAX).contains(&self.neutron_lifetime) {
+            return Err(format!(
+                "Neutron lifetime {} s out of range [{}, {}]",
+                self.neutron_lifetime, constants::NEUTRON_LIFETIME_MIN, constants::NEUTRON_LIFETIME_MAX
+            ));
         }
-        
-        // Call Fortran simulation step
-        let result = fortran_ffi::simulation_step(
-            dt,
-            state.neutron_population,
-            state.precursors,
-            state.avg_fuel_temp,
-            state.avg_coolant_temp,
-            state.avg_graphite_temp,
-            state.avg_coolant_void,
-            state.iodine_135,
-            state.xenon_135,
-            total_rod_worth,
-            state.smoothed_reactivity,
-            state.scram_active,
-        );
-        
-        // Update state from Fortran results
-        state.neutron_population = result.neutron_population;
-        state.precursors = result.precursors;
-        state.avg_fuel_temp = result.fuel_temp;
-        state.avg_coolant_temp = result.coolant_temp;
-        state.avg_graphite_temp = result.graphite_temp;
-        state.avg_coolant_void = result.coolant_void;
-        state.iodine_135 = result.iodine_135;
-        state.xenon_135 = result.xenon_135;
-        state.smoothed_reactivity = result.reactivity;
-        state.reactivity = result.reactivity;
-        state.k_eff = result.k_eff;
-        state.power_mw = result.power_mw;
-        state.power_percent = result.power_percent;
-        state.period = if result.period > 1.0e20 { f64::INFINITY } else { result.period };
-        state.reactivity_dollars = state.reactivity / constants::BETA_EFF;
-        
-        // Update automatic regulator state (PID integral/derivative terms)
-        if state.auto_regulator.enabled && !state.scram_active {
-            let error = state.auto_regulator.target_power - state.power_percent;
-            
-            // Always accumulate integral for better tracking
-            // Anti-windup: limit integral accumulation and decay when near target
-            let max_integral = 100.0; // Limit integral term
-            
-            if error.abs() > state.auto_regulator.deadband {
-                // Accumulate integral error
-                state.auto_regulator.integral_error =
-                    (state.auto_regulator.integral_error + error * dt).clamp(-max_integral, max_integral);
-            } else {
-                // Slowly decay integral when within deadband to prevent windup
-                state.auto_regulator.integral_error *= 0.99;
-            }
-            
-            state.auto_regulator.last_error = error;
+        if !(self.scram_insertion_time > 0.0 && self.scram_insertion_time <= 60.0) {
+            return Err(format!("SCRAM insertion time {} s out of range (0, 60]", self.scram_insertion_time));
         }
-        
-        // Update axial flux distribution using Fortran
-        state.axial_flux = fortran_ffi::update_axial_flux(50, state.neutron_population);
-        
-        // Process alert flags from Fortran
-        let flags = result.alert_flags;
-        if flags & fortran_ffi::ALERT_POWER_HIGH != 0 {
-            state.alerts.push("WARNING: Power exceeds 110% nominal!".to_string());
+        if !(self.alert_hold_time.is_finite() && self.alert_hold_time >= 0.0) {
+            return Err(format!("Alert hold time {} s must be a non-negative number", self.alert_hold_time));
         }
-        if flags & fortran_ffi::ALERT_REACTIVITY_HIGH != 0 {
-            state.alerts.push("WARNING: Reactivity exceeds 0.5$!".to_string());
+        if !(0.0..=constants::FEEDWATER_MAX_FRACTION).contains(&self.feedwater_fraction) {
+            return Err(format!(
+                "Feedwater fraction {} out of range [0, {}]",
+                self.feedwater_fraction, constants::FEEDWATER_MAX_FRACTION,
+            ));
         }
-        if flags & fortran_ffi::ALERT_PROMPT_CRITICAL != 0 {
-            state.alerts.push("CRITICAL: Prompt critical condition!".to_string());
+        if !(constants::GRAPHITE_GAP_CONDUCTANCE_MIN..=constants::GRAPHITE_GAP_CONDUCTANCE_MAX).contains(&self.graphite_gap_conductance) {
+            return Err(format!(
+                "Graphite gap conductance {} out of range [{}, {}]",
+                self.graphite_gap_conductance, constants::GRAPHITE_GAP_CONDUCTANCE_MIN, constants::GRAPHITE_GAP_CONDUCTANCE_MAX
+            ));
         }
-        if flags & fortran_ffi::ALERT_FUEL_TEMP_HIGH != 0 {
-            state.alerts.push("WARNING: Fuel temperature exceeds limit!".to_string());
+        if !(constants::GRAPHITE_COOLING_EFFECTIVENESS_MIN..=constants::GRAPHITE_COOLING_EFFECTIVENESS_MAX).contains(&self.graphite_cooling_effectiveness) {
+            return Err(format!(
+                "Graphite cooling effectiveness {} out of range [{}, {}]",
+                self.graphite_cooling_effectiveness,
+                constants::GRAPHITE_COOLING_EFFECTIVENESS_MIN,
+                constants::GRAPHITE_COOLING_EFFECTIVENESS_MAX
+            ));
         }
-        if flags & fortran_ffi::ALERT_VOID_HIGH != 0 {
-            state.alerts.push("WARNING: High void fraction - positive reactivity feedback!".to_string());
+        validate_reactivity_smoothing_tau(self.reactivity_smoothing_tau)?;
+        validate_plenum_mixing_time(self.plenum_mixing_time)?;
+        validate_channel_volume(self.channel_volume_m3)?;
+        if !(0.0..=constants::ABSORBER_DEPLETION_RATE_MAX).contains(&self.absorber_depletion_rate) {
+            return Err(format!(
+                "Absorber depletion rate {} out of range [0, {}]",
+                self.absorber_depletion_rate, constants::ABSORBER_DEPLETION_RATE_MAX
+            ));
         }
-        if flags & fortran_ffi::ALERT_SHORT_PERIOD != 0 {
-            let period = state.period;
-            state.alerts.push(format!("WARNING: Short reactor period: {:.1}s", period));
+        self.rod_worth_shapes.validate()?;
+        let gains = [("kp", self.ar_kp), ("ki", self.ar_ki), ("kd", self.ar_kd), ("deadband", self.ar_deadband)];
+        if let Some((name, value)) = gains.iter().find(|(_, v)| !(v.is_finite() && *v >= 0.0)) {
+            return Err(format!("Regulator {} {} must be a non-negative number", name, value));
+        }
+        if !(self.ar_rod_speed.is_finite() && self.ar_rod_speed > 0.0) {
+            return Err(format!("Regulator rod speed {} must be positive", self.ar_rod_speed));
+        }
+        Ok(())
+    }
+}
+
+/// Plant a scenario or snapshot is set up for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PlantModel {
+    #[default]
+    #[serde(rename = "RBMK-1000")]
+    Rbmk1000, // 3200 MW thermal
+    #[serde(rename = "RBMK-1500")]
+    Rbmk1500, // 4800 MW thermal
+}
+
+impl PlantModel {
+    /// Plant whose rating is closest to `nominal_power_mw`
+    pub fn from_nominal_power(nominal_power_mw: f64) -> Self {
+        if nominal_power_mw >= 4000.0 {
+            PlantModel::Rbmk1500
+        } else {
+            PlantModel::Rbmk1000
+        }
+    }
+}
+
+/// Name and description of a simulation, carried into its snapshots so a
+/// scenario library can show them instead of file names
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SimulationMetadata {
+    pub name: String,
+    pub description: String,
+    pub created_at: u64, // Unix time [ms]
+    pub plant_model: PlantModel,
+}
+
+/// Current wall-clock time in Unix milliseconds (0 if the clock is before 1970)
+fn unix_time_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+/// Kinetics constants currently used by the Fortran physics
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhysicsConstants {
+    pub beta_eff: f64,            // Delayed neutron fraction
+    pub neutron_lifetime: f64,    // Prompt neutron lifetime Λ [s]
+    pub nominal_power_mw: f64,
+    /// e-folding period at ρ = 1.1β (10 cents above prompt critical), Λ / (0.1·β) [s]
+    /// Shows how strongly the lifetime controls the speed of a prompt excursion
+    pub prompt_period_at_110_cents: f64,
+}
+
+/// Fuel channel occupying one grid cell
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GridCell {
+    pub grid_x: i32,
+    pub grid_y: i32,
+    pub channel_id: usize,
+}
+
+/// A rod that would not contribute to a SCRAM
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StuckRodInfo {
+    pub id: usize,
+    pub channel_type: String,
+    pub grid_x: i32,
+    pub grid_y: i32,
+    pub position: f64,       // Current (frozen) position
+    pub withheld_worth: f64, // Worth that would not be inserted [Δk/k]
+}
+
+/// Pre-shutdown check: how effective a SCRAM would be right now
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScramReadiness {
+    pub total_rods: usize,
+    pub rods_insertable: usize,       // Rods that would drive in on SCRAM
+    pub insertable_worth: f64,        // Reactivity a SCRAM would add [Δk/k]
+    pub insertable_worth_dollars: f64,
+    pub withheld_worth: f64,          // Worth held out by stuck rods [Δk/k]
+    pub stuck_rods: Vec<StuckRodInfo>,
+}
+
+/// Summed worth of one rod group
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RodGroupWorth {
+    pub channel_type: String, // RR, AR, LAR, USP, AZ
+    pub rod_count: usize,
+    pub worth: f64,           // [Δk/k]
+}
+
+/// Inserted against available worth of one rod group
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RodGroupInsertion {
+    pub channel_type: String,   // RR, AR, LAR, USP, AZ
+    pub rod_count: usize,
+    pub available_worth: f64,   // All rods of the group fully inserted [Δk/k]
+    pub inserted_worth: f64,    // At the current positions [Δk/k]
+    pub inserted_fraction: f64, // inserted / available (0 when nothing is available)
+}
+
+/// Inserted against available rod worth of the whole core
+/// Unlike the ORM this is in Δk/k, not equivalent manual rods.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RodWorthSummary {
+    pub available_worth: f64,   // All rods fully inserted [Δk/k]
+    pub inserted_worth: f64,    // At the current positions [Δk/k]
+    pub inserted_fraction: f64,
+    pub remaining_worth: f64,   // Still available for shutdown [Δk/k]
+    pub by_type: Vec<RodGroupInsertion>, // Sorted by channel type
+}
+
+/// Sanity check of the configured rod worths
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RodWorthReport {
+    pub total_worth: f64,          // Sum over all rods [Δk/k]
+    pub total_worth_dollars: f64,
+    pub by_type: Vec<RodGroupWorth>, // Sorted by channel type
+    pub out_of_range: bool,        // Total outside ROD_WORTH_TOTAL_MIN..=MAX
+    pub warning: Option<String>,
+}
+
+/// How fuel channels are coupled for 2D diffusion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NeighborMode {
+    Grid,      // 4-connected by grid position (default)
+    Provided,  // Keep the `neighbors` lists supplied with the channels
+    Isolated,  // No coupling (every channel evolves independently)
+}
+
+/// Where the builder gets its fuel channels from
+enum ChannelSource {
+    Layout,
+    FallbackGrid(usize),
+    Provided(Vec<FuelChannel>),
+}
+
+/// Builds a `ReactorSimulator` without depending on layout files
+///
+/// By default the result is identical to `ReactorSimulator::new()`: the
+/// OPB-82 layout from disk, Fortran physics (the pure-Rust fallback if the
+/// library is missing) and 3200 MW nominal power.
+/// Supplying channels (or a fallback grid) without rods uses the fallback
+/// rod arrangement, so nothing is read from disk.
+pub struct ReactorSimulatorBuilder {
+    channels: ChannelSource,
+    control_rods: Option<Vec<ControlRod>>,
+    nominal_power_mw: f64,
+    neighbor_mode: NeighborMode,
+    backend: Option<Box<dyn PhysicsBackend>>,
+}
+
+impl Default for ReactorSimulatorBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReactorSimulatorBuilder {
+    pub fn new() -> Self {
+        Self {
+            channels: ChannelSource::Layout,
+            control_rods: None,
+            nominal_power_mw: constants::NOMINAL_POWER_MW,
+            neighbor_mode: NeighborMode::Grid,
+            backend: None,
+        }
+    }
+    
+    /// Use these fuel channels (ids should match their index)
+    pub fn channels(mut self, channels: Vec<FuelChannel>) -> Self {
+        self.channels = ChannelSource::Provided(channels);
+        self
+    }
+    
+    /// Use the fallback circular grid with `grid_size` cells per side
+    pub fn fallback_grid(mut self, grid_size: usize) -> Self {
+        self.channels = ChannelSource::FallbackGrid(grid_size);
+        self
+    }
+    
+    /// Use these control rods (ids should match their index)
+    pub fn control_rods(mut self, control_rods: Vec<ControlRod>) -> Self {
+        self.control_rods = Some(control_rods);
+        self
+    }
+    
+    /// Rated thermal power that `power_mw` is expressed against [MW]
+    pub fn nominal_power_mw(mut self, nominal_power_mw: f64) -> Self {
+        self.nominal_power_mw = nominal_power_mw;
+        self
+    }
+    
+    pub fn neighbor_mode(mut self, neighbor_mode: NeighborMode) -> Self {
+        self.neighbor_mode = neighbor_mode;
+        self
+    }
+    
+    /// Physics backend for the core-level model (default: `backend::default_backend`)
+    pub fn backend(mut self, backend: Box<dyn PhysicsBackend>) -> Self {
+        self.backend = Some(backend);
+        self
+    }
+    
+    pub fn build(self) -> Result<ReactorSimulator, String> {
+        if !(self.nominal_power_mw.is_finite() && self.nominal_power_mw > 0.0) {
+            return Err(format!("Nominal power must be positive, got {} MW", self.nominal_power_mw));
+        }
+        
+        let from_layout = matches!(self.channels, ChannelSource::Layout);
+        let (mut fuel_channels, config) = match self.channels {
+            ChannelSource::Layout => load_fuel_channels_from_config(),
+            ChannelSource::FallbackGrid(grid_size) => (create_fallback_channels(grid_size), None),
+            ChannelSource::Provided(channels) => (channels, None),
+        };
+        if fuel_channels.is_empty() {
+            return Err("Simulator needs at least one fuel channel".to_string());
+        }
+        let layout_warnings = config.as_ref().map(validate_layout).unwrap_or_default();
+        let detectors = create_detectors(config.as_ref(), &fuel_channels);
+        
+        let control_rods = match self.control_rods {
+            Some(rods) => rods,
+            None if from_layout => load_control_rods_from_config(),
+            None => create_fallback_control_rods(),
+        };
+        let lar_zones = create_lar_zones(&control_rods, &fuel_channels);
+        
+        match self.neighbor_mode {
+            NeighborMode::Grid => build_neighbor_map(&mut fuel_channels),
+            NeighborMode::Provided => {
+                let num_channels = fuel_channels.len();
+                for channel in &fuel_channels {
+                    if channel.neighbors.len() > fortran_ffi::MAX_NEIGHBORS {
+                        return Err(format!(
+                            "Channel {} has {} neighbors (max {})",
+                            channel.id, channel.neighbors.len(), fortran_ffi::MAX_NEIGHBORS
+                        ));
+                    }
+                    if let Some(&bad) = channel.neighbors.iter().find(|&&n| n >= num_channels) {
+                        return Err(format!("Channel {} has invalid neighbor {}", channel.id, bad));
+                    }
+                }
+            }
+            NeighborMode::Isolated => {
+                for channel in fuel_channels.iter_mut() {
+                    channel.neighbors.clear();
+                }
+            }
+        }
+        
+        // Link control rods to fuel channels for local reactivity effects
+        link_control_rods_to_channels(&mut fuel_channels, &control_rods);
+        
+        let active_layout = if from_layout { DEFAULT_LAYOUT_NAME } else { CUSTOM_LAYOUT_NAME };
+        let config_hash = layout_hash(&fuel_channels, &control_rods);
+        let grid_index = build_grid_index(&fuel_channels);
+        
+        Ok(ReactorSimulator {
+            state: Mutex::new(ReactorState::default()),
+            control_rods: Mutex::new(control_rods),
+            fuel_channels: Mutex::new(fuel_channels),
+            running: Mutex::new(false),
+            layout_dir: Mutex::new(None),
+            active_layout: Mutex::new(active_layout.to_string()),
+            layout_warnings: Mutex::new(layout_warnings),
+            detectors: Mutex::new(detectors),
+            lar_zones: Mutex::new(lar_zones),
+            config_hash: Mutex::new(config_hash),
+            grid_index: Mutex::new(grid_index),
+            rod_travel_limits: Mutex::new(HashMap::new()),
+            normalize_channel_power: Mutex::new(false),
+            channel_power_ceiling_mw: Mutex::new(constants::CHANNEL_POWER_CEILING_MW),
+            channel_volume_m3: Mutex::new(channel_defaults::CHANNEL_VOLUME_M3),
+            graphite_gap_conductance: Mutex::new(constants::GRAPHITE_GAP_CONDUCTANCE_NOMINAL),
+            graphite_cooling_effectiveness: Mutex::new(constants::GRAPHITE_COOLING_EFFECTIVENESS_NOMINAL),
+            reactivity_smoothing_tau: Mutex::new(constants::REACTIVITY_SMOOTHING_TAU_DEFAULT_S),
+            plenum_mixing_time: Mutex::new(constants::PLENUM_MIXING_TIME_DEFAULT_S),
+            fuel_conductivity_model: Mutex::new(FuelConductivityModel::default()),
+            absorber_depletion_rate: Mutex::new(constants::ABSORBER_DEPLETION_RATE_DEFAULT),
+            rod_worth_shapes: Mutex::new(RodWorthShapes::default()),
+            skipped_resets: Mutex::new(Vec::new()),
+            delta_tracker: Mutex::new(DeltaTracker::default()),
+            inverse_m: Mutex::new(InverseMRecorder::default()),
+            kinetics_solver: Mutex::new(KineticsSolver::default()),
+            scram_insertion_time: Mutex::new(constants::SCRAM_INSERTION_TIME_S),
+            alert_tracker: Mutex::new(AlertTracker::default()),
+            fault_injector: Mutex::new(FaultInjector::default()),
+            detector_faults: Mutex::new(HashMap::new()),
+            event_times: Mutex::new(EventTimes::default()),
+            trip_bypasses: Mutex::new(HashSet::new()),
+            channel_history: Mutex::new(ChannelHistoryRecorder::default()),
+            reactivity_trace: Mutex::new(ReactivityTrace::default()),
+            keff_history: Mutex::new(KeffHistory::default()),
+            last_time_step: Mutex::new(None),
+            stability_monitor: Mutex::new(StabilityMonitor::default()),
+            checkpoints: Mutex::new(CheckpointBuffer::default()),
+            reactivity_reference: Mutex::new(None),
+            last_step_timing: Mutex::new(None),
+            metadata: Mutex::new(SimulationMetadata {
+                name: String::new(),
+                description: String::new(),
+                created_at: unix_time_ms(),
+                plant_model: PlantModel::from_nominal_power(self.nominal_power_mw),
+            }),
+            nominal_power_mw: self.nominal_power_mw,
+            backend: self.backend.unwrap_or_else(backend::default_backend),
+        })
+    }
+}
+
+/// Reactor simulation engine
+pub struct ReactorSimulator {
+    pub state: Mutex<ReactorState>,
+    pub control_rods: Mutex<Vec<ControlRod>>,
+    pub fuel_channels: Mutex<Vec<FuelChannel>>,
+    pub running: Mutex<bool>,
+    layout_dir: Mutex<Option<PathBuf>>,  // Directory last enumerated by list_layouts
+    active_layout: Mutex<String>,        // Name of the currently loaded layout
+    layout_warnings: Mutex<Vec<LayoutWarning>>, // Problems found loading the active layout
+    detectors: Mutex<Vec<DetectorPosition>>, // In-core detector locations
+    lar_zones: Mutex<Vec<LarZone>>,      // Local regulator zones (empty without LAR rods)
+    config_hash: Mutex<u64>,             // Geometry hash of the loaded layout (see `layout_hash`)
+    grid_index: Mutex<HashMap<(i32, i32), usize>>, // Channel id by grid position, rebuilt with the layout
+    rod_travel_limits: Mutex<HashMap<String, RodTravelLimits>>, // Per channel type; absent = full stroke
+    normalize_channel_power: Mutex<bool>, // Rescale channel powers to sum to the global power
+    channel_power_ceiling_mw: Mutex<f64>, // Spatial solver clamps local power here [MW]
+    channel_volume_m3: Mutex<f64>,        // Fuel volume per channel for power density [m³]
+    graphite_gap_conductance: Mutex<f64>, // Last value sent to the Fortran thermal models
+    graphite_cooling_effectiveness: Mutex<f64>, // Likewise, for the graphite gas circuit
+    reactivity_smoothing_tau: Mutex<f64>, // Likewise, reactivity smoothing outside a SCRAM [s]
+    plenum_mixing_time: Mutex<f64>,       // Inlet plenum mixing time constant [s]
+    fuel_conductivity_model: Mutex<FuelConductivityModel>, // Last model sent to the spatial thermal step
+    absorber_depletion_rate: Mutex<f64>,  // Rod absorber burnout [1/s at full insertion and power]
+    rod_worth_shapes: Mutex<RodWorthShapes>, // Worth curve shape exponent per rod type
+    skipped_resets: Mutex<Vec<String>>,   // Backend resets the last reset could not run
+    delta_tracker: Mutex<DeltaTracker>,   // Field versions for delta streaming to clients
+    inverse_m: Mutex<InverseMRecorder>,   // Approach-to-criticality (1/M) plot points
+    kinetics_solver: Mutex<KineticsSolver>, // Point kinetics scheme used by `step()`
+    scram_insertion_time: Mutex<f64>,    // Full-stroke SCRAM insertion time [s]
+    alert_tracker: Mutex<AlertTracker>,  // Alerts latched across steps for annunciation
+    fault_injector: Mutex<FaultInjector>, // Seeded drill fault, fired during stepping
+    detector_faults: Mutex<HashMap<usize, ActiveDetectorFault>>, // By detector id; absent = healthy
+    event_times: Mutex<EventTimes>, // SCRAM and rod move times (alerts, explosion derived)
+    trip_bypasses: Mutex<HashSet<Trip>>, // Protection trips bypassed by the operator (kept across resets)
+    channel_history: Mutex<ChannelHistoryRecorder>, // Time series of tracked channels
+    reactivity_trace: Mutex<ReactivityTrace>, // Last minute of reactivity for black box snapshots
+    keff_history: Mutex<KeffHistory>,     // k_eff vs rod position, one sample per step
+    last_time_step: Mutex<Option<EffectiveTimeStep>>, // None until the first step
+    stability_monitor: Mutex<StabilityMonitor>, // Watches power for growing step-to-step oscillation
+    checkpoints: Mutex<CheckpointBuffer>, // Recent full copies of the run for `rewind`
+    reactivity_reference: Mutex<Option<f64>>, // Reactivity at the marked reference point [$]
+    last_step_timing: Mutex<Option<StepTiming>>, // Phase timing of the last spatial step (feature `profiling`)
+    metadata: Mutex<SimulationMetadata>, // Scenario identity for snapshots
+    nominal_power_mw: f64,               // Rated thermal power [MW]
+    backend: Box<dyn PhysicsBackend>,    // Core-level physics model
+}
+
+impl Default for ReactorSimulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReactorSimulator {
+    /// Simulator with the OPB-82 layout (1661 TK cells) and Fortran physics
+    /// Falls back to a circular grid if the layout config is not found.
+    pub fn new() -> Self {
+        ReactorSimulatorBuilder::new()
+            .build()
+            .expect("Default simulator configuration is valid")
+    }
+    
+    /// Builder for a customized simulator
+    pub fn builder() -> ReactorSimulatorBuilder {
+        ReactorSimulatorBuilder::new()
+    }
+    
+    /// Name of the core physics backend in use
+    pub fn backend_name(&self) -> &str {
+        self.backend.name()
+    }
+    
+    /// List the named layouts (`<name>_layout.json`) in a directory, sorted by name
+    /// The directory is remembered so `load_layout_named` can find them.
+    pub fn list_layouts(&self, dir: &str) -> Result<Vec<String>, String> {
+        let entries = fs::read_dir(dir)
+            .map_err(|e| format!("Failed to read layout directory {}: {}", dir, e))?;
+        
+        let mut names: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let file_name = entry.file_name().into_string().ok()?;
+                file_name.strip_suffix(LAYOUT_FILE_SUFFIX).map(|name| name.to_string())
+            })
+            .filter(|name| !name.is_empty())
+            .collect();
+        names.sort();
+        
+        *self.layout_dir.lock().unwrap() = Some(PathBuf::from(dir));
+        Ok(names)
+    }
+    
+    /// Name of the currently loaded layout
+    pub fn get_active_layout(&self) -> String {
+        self.active_layout.lock().unwrap().clone()
+    }
+    
+    /// Problems found while loading the active layout (e.g. cells outside the grid)
+    pub fn get_layout_warnings(&self) -> Vec<LayoutWarning> {
+        self.layout_warnings.lock().unwrap().clone()
+    }
+    
+    /// Switch to a named layout (`<name>_layout.json`)
+    /// Looks in the directory last passed to `list_layouts`, then the default config directories.
+    /// Rebuilds fuel channels, control rods and the neighbor map, then resets to cold shutdown.
+    pub fn load_layout_named(&self, name: &str) -> Result<(), String> {
+        if !is_plain_layout_name(name) {
+            return Err(format!("Invalid layout name '{}'", name));
+        }
+        let file_name = format!("{}{}", name, LAYOUT_FILE_SUFFIX);
+        let listed_dir = self.layout_dir.lock().unwrap().clone();
+        let path = listed_dir
+            .into_iter()
+            .chain(LAYOUT_DIRS.iter().map(PathBuf::from))
+            .map(|dir| dir.join(&file_name))
+            .find(|path| path.is_file())
+            .ok_or_else(|| format!("Layout '{}' not found", name))?;
+        
+        let config = read_layout_file(&path)?;
+        let layout_warnings = validate_layout(&config);
+        let control_rods = create_control_rods_from_config(&config);
+        let mut fuel_channels = create_channels_from_config(&config);
+        if fuel_channels.is_empty() {
+            return Err(format!("Layout '{}' contains no fuel channels", name));
+        }
+        build_neighbor_map(&mut fuel_channels);
+        link_control_rods_to_channels(&mut fuel_channels, &control_rods);
+        let detectors = create_detectors(Some(&config), &fuel_channels);
+        let lar_zones = create_lar_zones(&control_rods, &fuel_channels);
+        let config_hash = layout_hash(&fuel_channels, &control_rods);
+        let grid_index = build_grid_index(&fuel_channels);
+        
+        *self.control_rods.lock().unwrap() = control_rods;
+        *self.detectors.lock().unwrap() = detectors;
+        *self.lar_zones.lock().unwrap() = lar_zones;
+        *self.config_hash.lock().unwrap() = config_hash;
+        *self.grid_index.lock().unwrap() = grid_index;
+        *self.fuel_channels.lock().unwrap() = fuel_channels;
+        *self.active_layout.lock().unwrap() = name.to_string();
+        *self.layout_warnings.lock().unwrap() = layout_warnings;
+        self.channel_history.lock().unwrap().clear();
+        self.checkpoints.lock().unwrap().clear();
+        println!("[reactor] Switched to layout '{}' ({})", name, path.display());
+        
+        self.reset();
+        Ok(())
+    }
+    
+    /// Calculate total control rod worth (how much is inserted)
+    ///
+    /// Inserted worth follows the backend S-curve (`calculate_rod_worth`)
+    /// shaped for the rod type: slow while the tips enter, fastest
+    /// mid-travel, flattening at the bottom. Normal moves and SCRAM travel
+    /// use the same curve, so there is no jump when a SCRAM starts.
+    ///
+    /// USP rods enter from below, so their worth is weighted by the flux in the
+    /// bottom half of the core (weight 1.0 for a symmetric axial profile).
+    /// Each rod contributes its effective worth after absorber burnout.
+    fn calculate_total_rod_worth(&self, axial_flux: &[f64]) -> f64 {
+        self.rod_worths(axial_flux).iter().map(|&(inserted, _)| inserted).sum()
+    }
+    
+    /// Inserted and fully-inserted worth of every rod, in rod order [Δk/k]
+    fn rod_worths(&self, axial_flux: &[f64]) -> Vec<(f64, f64)> {
+        let usp_weight = 2.0 * axial_bottom_fraction(axial_flux);
+        let shapes = *self.rod_worth_shapes.lock().unwrap();
+        let control_rods = self.control_rods.lock().unwrap();
+        control_rods.iter()
+            .map(|rod| self.rod_worth_at(rod, rod.position, usp_weight, &shapes))
+            .collect()
+    }
+    
+    /// Inserted and fully-inserted worth of one rod held at `position` [Δk/k]
+    fn rod_worth_at(&self, rod: &ControlRod, position: f64, usp_weight: f64, shapes: &RodWorthShapes) -> (f64, f64) {
+        let weight = if rod.rod_type == RodType::Shortened { usp_weight } else { 1.0 };
+        let worth = rod.effective_worth();
+        let inserted = self.backend.rod_worth(position, worth, shapes.get(&rod.rod_type));
+        (weight * inserted, weight * worth)
+    }
+    
+    /// Steady-state reactivity with every rod moved to `position(rod)` under
+    /// the given feedback conditions [Δk/k]
+    ///
+    /// Works on a snapshot of the rods, so the backend is never called with
+    /// the rods lock held.
+    fn reactivity_with_rods_at(
+        &self,
+        rods: &[ControlRod],
+        feedback: &FeedbackConditions,
+        axial_flux: &[f64],
+        position: impl Fn(&ControlRod) -> f64,
+    ) -> f64 {
+        let usp_weight = 2.0 * axial_bottom_fraction(axial_flux);
+        let shapes = *self.rod_worth_shapes.lock().unwrap();
+        let rod_worth: f64 = rods.iter()
+            .map(|rod| self.rod_worth_at(rod, position(rod), usp_weight, &shapes).0)
+            .sum();
+        let FeedbackConditions { fuel_temp, graphite_temp, coolant_void, xenon_135 } = *feedback;
+        self.backend.target_reactivity(fuel_temp, graphite_temp, coolant_void, xenon_135, rod_worth)
+    }
+    
+    /// Inserted rod worth against the worth of all rods fully inserted,
+    /// in total and per rod type, as the kinetics sees it [Δk/k]
+    pub fn get_rod_worth_summary(&self) -> RodWorthSummary {
+        let axial_flux = self.state.lock().unwrap().axial_flux.clone();
+        let worths = self.rod_worths(&axial_flux);
+        let rods = self.control_rods.lock().unwrap();
+        let mut groups: BTreeMap<&str, RodGroupInsertion> = BTreeMap::new();
+        for (rod, &(inserted, available)) in rods.iter().zip(&worths) {
+            let group = groups.entry(rod.channel_type.as_str()).or_insert_with(|| RodGroupInsertion {
+                channel_type: rod.channel_type.clone(),
+                rod_count: 0,
+                available_worth: 0.0,
+                inserted_worth: 0.0,
+                inserted_fraction: 0.0,
+            });
+            group.rod_count += 1;
+            group.available_worth += available;
+            group.inserted_worth += inserted;
+        }
+        let fraction = |inserted: f64, available: f64| if available > 0.0 { inserted / available } else { 0.0 };
+        let by_type: Vec<RodGroupInsertion> = groups.into_values()
+            .map(|group| RodGroupInsertion {
+                inserted_fraction: fraction(group.inserted_worth, group.available_worth),
+                ..group
+            })
+            .collect();
+        
+        let available_worth: f64 = by_type.iter().map(|g| g.available_worth).sum();
+        let inserted_worth: f64 = by_type.iter().map(|g| g.inserted_worth).sum();
+        RodWorthSummary {
+            available_worth,
+            inserted_worth,
+            inserted_fraction: fraction(inserted_worth, available_worth),
+            remaining_worth: available_worth - inserted_worth,
+            by_type,
+        }
+    }
+    
+    /// Axial flux tilt from the top/bottom absorber imbalance
+    /// Top-entry rods (RR, AR/LAR, AZ) suppress the top half, USP rods the bottom half.
+    fn axial_tilt(&self) -> f64 {
+        let rods = self.control_rods.lock().unwrap();
+        let insertion = |usp: bool| {
+            let (inserted, total) = rods.iter()
+                .filter(|r| (r.rod_type == RodType::Shortened) == usp)
+                .fold((0.0, 0.0), |(inserted, total), r| {
+                    (inserted + r.worth * (1.0 - r.position), total + r.worth)
+                });
+            if total > 0.0 { inserted / total } else { 0.0 }
+        };
+        (constants::AXIAL_TILT_GAIN * (insertion(true) - insertion(false))).clamp(-0.9, 0.9)
+    }
+    
+    /// Get the top vs bottom split of the axial power profile
+    pub fn get_axial_power_offset(&self) -> AxialPowerOffset {
+        let state = self.state.lock().unwrap();
+        let bottom_fraction = axial_bottom_fraction(&state.axial_flux);
+        let top_fraction = 1.0 - bottom_fraction;
+        AxialPowerOffset {
+            top_fraction,
+            bottom_fraction,
+            offset: top_fraction - bottom_fraction,
+        }
+    }
+    
+    /// Radial power profile: channels binned into `num_rings` equal-width rings
+    /// from the core center out to the outermost channel
+    pub fn get_radial_profile(&self, num_rings: usize) -> Result<Vec<RingStat>, String> {
+        if num_rings == 0 {
+            return Err("Radial profile needs at least one ring".to_string());
+        }
+        let channels = self.fuel_channels.lock().unwrap();
+        let radius = |c: &FuelChannel| c.x.hypot(c.y);
+        let max_radius = channels.iter().map(radius).fold(0.0, f64::max);
+        let ring_width = if max_radius > 0.0 { max_radius / num_rings as f64 } else { 1.0 };
+        
+        let mut power_sums = vec![0.0; num_rings];
+        let mut counts = vec![0usize; num_rings];
+        for channel in channels.iter() {
+            let ring = ((radius(channel) / ring_width) as usize).min(num_rings - 1);
+            power_sums[ring] += channel.power_density;
+            counts[ring] += 1;
+        }
+        
+        Ok((0..num_rings)
+            .map(|ring| RingStat {
+                ring,
+                inner_radius_cm: ring as f64 * ring_width,
+                outer_radius_cm: (ring + 1) as f64 * ring_width,
+                channel_count: counts[ring],
+                avg_power_density: if counts[ring] > 0 { power_sums[ring] / counts[ring] as f64 } else { 0.0 },
+            })
+            .collect())
+    }
+    
+    /// IDs of the channels loaded with fresh (zero-burnup) fuel
+    pub fn get_fresh_channels(&self) -> Vec<usize> {
+        self.fuel_channels.lock().unwrap().iter()
+            .filter(|c| c.initial_burnup <= 0.0)
+            .map(|c| c.id)
+            .collect()
+    }
+    
+    /// Core-average burnup and how unevenly it is spread over the channels
+    pub fn get_burnup_stats(&self) -> BurnupStats {
+        let channels = self.fuel_channels.lock().unwrap();
+        if channels.is_empty() {
+            return BurnupStats::default();
+        }
+        let count = channels.len() as f64;
+        let average = channels.iter().map(|c| c.burnup).sum::<f64>() / count;
+        let variance = channels.iter().map(|c| (c.burnup - average).powi(2)).sum::<f64>() / count;
+        let min = channels.iter().map(|c| c.burnup).fold(f64::INFINITY, f64::min);
+        let max = channels.iter().map(|c| c.burnup).fold(f64::NEG_INFINITY, f64::max);
+        BurnupStats {
+            average,
+            min,
+            max,
+            std_dev: variance.sqrt(),
+            peak_to_average: if average > 0.0 { max / average } else { 0.0 },
+        }
+    }
+    
+    /// Compare the sum of channel powers (and integrated power density) with
+    /// the global power
+    pub fn get_power_balance(&self) -> PowerBalance {
+        let global_power_mw = self.state.lock().unwrap().power_mw;
+        let channels = self.fuel_channels.lock().unwrap();
+        let channel_power_sum_mw: f64 = channels.iter().map(|c| c.local_power).sum();
+        let channel_volume = *self.channel_volume_m3.lock().unwrap();
+        let density_integral_mw: f64 = channels.iter()
+            .map(|c| c.power_density * channel_volume)
+            .sum();
+        let discrepancy_mw = channel_power_sum_mw - global_power_mw;
+        PowerBalance {
+            global_power_mw,
+            channel_power_sum_mw,
+            density_integral_mw,
+            discrepancy_mw,
+            discrepancy_percent: if global_power_mw > 0.0 { discrepancy_mw / global_power_mw * 100.0 } else { 0.0 },
+            normalized: *self.normalize_channel_power.lock().unwrap(),
+        }
+    }
+    
+    /// Rescale channel powers every spatial step so they sum to the core
+    /// power the spatial solver produced, which clamped channels otherwise
+    /// lose. Power density, outlet temperature and DNBR follow the rescaled
+    /// powers, and the global power is their sum.
+    pub fn set_power_normalization(&self, enabled: bool) {
+        *self.normalize_channel_power.lock().unwrap() = enabled;
+    }
+    
+    /// Drive all free rods toward full insertion at the SCRAM insertion speed
+    fn advance_scram_rods(&self, dt: f64) {
+        let insertion_time = *self.scram_insertion_time.lock().unwrap();
+        let travel = dt / insertion_time;
+        let mut rods = self.control_rods.lock().unwrap();
+        for rod in rods.iter_mut().filter(|r| !r.stuck) {
+            rod.position = (rod.position - travel).max(0.0);
+        }
+    }
+    
+    /// Burn out rod absorber in proportion to insertion depth and power
+    fn deplete_absorbers(&self, power_percent: f64, dt: f64) {
+        let rate = *self.absorber_depletion_rate.lock().unwrap();
+        let exposure = rate * power_percent.max(0.0) / 100.0 * dt;
+        if exposure <= 0.0 {
+            return;
+        }
+        let mut rods = self.control_rods.lock().unwrap();
+        for rod in rods.iter_mut() {
+            rod.absorber_depletion = (rod.absorber_depletion + exposure * (1.0 - rod.position)).min(1.0);
+        }
+    }
+    
+    /// Absorber burnout and remaining worth of every rod
+    pub fn get_rod_depletion(&self) -> Vec<RodDepletion> {
+        self.control_rods.lock().unwrap().iter()
+            .map(|rod| RodDepletion {
+                rod_id: rod.id,
+                channel_type: rod.channel_type.clone(),
+                absorber_depletion: rod.absorber_depletion,
+                nominal_worth: rod.worth,
+                effective_worth: rod.effective_worth(),
+            })
+            .collect()
+    }
+    
+    /// Set the absorber burnout rate [1/s at full insertion and 100 % power]
+    pub fn set_absorber_depletion_rate(&self, rate: f64) -> Result<(), String> {
+        if !(0.0..=constants::ABSORBER_DEPLETION_RATE_MAX).contains(&rate) {
+            return Err(format!(
+                "Absorber depletion rate {} out of range [0, {}]",
+                rate, constants::ABSORBER_DEPLETION_RATE_MAX
+            ));
+        }
+        *self.absorber_depletion_rate.lock().unwrap() = rate;
+        Ok(())
+    }
+    
+    /// Set the worth curve shape exponent of one rod type
+    pub fn set_rod_worth_shape(&self, rod_type: &RodType, shape: f64) -> Result<(), String> {
+        let mut shapes = *self.rod_worth_shapes.lock().unwrap();
+        *shapes.get_mut(rod_type) = shape;
+        shapes.validate()?;
+        *self.rod_worth_shapes.lock().unwrap() = shapes;
+        Ok(())
+    }
+    
+    /// Inserted worth fraction along the stroke for a rod type, withdrawn end last
+    pub fn get_rod_worth_curve(&self, rod_type: &RodType, points: usize) -> Vec<RodWorthPoint> {
+        let shape = self.rod_worth_shapes.lock().unwrap().get(rod_type);
+        let points = points.max(2);
+        (0..points)
+            .map(|i| {
+                let position = i as f64 / (points - 1) as f64;
+                RodWorthPoint { position, worth_fraction: self.backend.rod_worth(position, 1.0, shape) }
+            })
+            .collect()
+    }
+    
+    /// Get the full-stroke SCRAM insertion time [s]
+    pub fn get_scram_insertion_time(&self) -> f64 {
+        *self.scram_insertion_time.lock().unwrap()
+    }
+    
+    /// Set the full-stroke SCRAM insertion time [s]
+    pub fn set_scram_insertion_time(&self, seconds: f64) -> Result<(), String> {
+        if !(seconds.is_finite() && seconds > 0.0 && seconds <= 60.0) {
+            return Err(format!("SCRAM insertion time {} s out of range (0, 60]", seconds));
+        }
+        *self.scram_insertion_time.lock().unwrap() = seconds;
+        Ok(())
+    }
+    
+    /// Average coolant pressure over all fuel channels [MPa]
+    fn average_channel_pressure(&self) -> f64 {
+        let channels = self.fuel_channels.lock().unwrap();
+        if channels.is_empty() {
+            return channel_defaults::PRESSURE_MPA;
+        }
+        channels.iter().map(|c| c.pressure).sum::<f64>() / channels.len() as f64
+    }
+    
+    /// Coolant saturation temperature [K] at the current (core-average) pressure
+    /// This is the boiling threshold used by the void model
+    pub fn get_saturation_temp(&self) -> f64 {
+        self.backend.saturation_temp(self.average_channel_pressure())
+    }
+    
+    /// Perform one simulation step using Fortran physics
+    pub fn step(&self) {
+        self.delta_tracker.lock().unwrap().advance();
+        
+        // First, run automatic regulator if enabled (before physics step)
+        // This needs to be done with separate locks to avoid deadlock
+        let (ar_enabled, ar_settings, current_power, dt, scram_active) = {
+            let state = self.state.lock().unwrap();
+            (
+                state.auto_regulator.enabled,
+                state.auto_regulator.clone(),
+                state.power_percent,
+                state.dt,
+                state.scram_active,
+            )
+        };
+        
+        // Armed drill faults fire before the physics of the step
+        self.inject_due_fault();
+        
+        // Run automatic regulator (AR/LAR) - PID control for power
+        // Point kinetics has no zone power, so LAR rods follow the global loop
+        if ar_enabled && !scram_active {
+            let rod_adjustment = self.calculate_ar_adjustment(&ar_settings, current_power, dt);
+            if rod_adjustment.abs() > 1e-6 {
+                self.adjust_automatic_rods(rod_adjustment, true);
+            }
+        }
+        
+        // Rods travel into the core during a SCRAM
+        if scram_active {
+            self.advance_scram_rods(dt);
+        }
+        self.deplete_absorbers(current_power, dt);
+        
+        // Boiling threshold follows the current coolant pressure
+        self.backend.set_coolant_pressure(self.average_channel_pressure());
+        let saturation_temp = self.get_saturation_temp();
+        let plenum_mixing_time = self.get_plenum_mixing_time();
+        
+        let mut state = self.state.lock().unwrap();
+        
+        state.clear_alerts();
+        let dt = state.dt;
+        
+        // Calculate total control rod worth
+        let total_rod_worth = self.calculate_total_rod_worth(&state.axial_flux);
+        
+        // Handle SCRAM timing
+        if state.scram_active {
+            state.scram_time += dt;
+        }
+        
+        // Debug builds check the reactivity breakdown against this step's inputs
+        let balance = cfg!(debug_assertions).then(|| self.reactivity_breakdown_at(
+            state.avg_fuel_temp,
+            state.avg_graphite_temp,
+            state.avg_coolant_void,
+            state.xenon_135,
+            total_rod_worth,
+        ));
+        let previous_reactivity = state.smoothed_reactivity;
+        
+        // Core-level physics step (Fortran unless another backend was injected)
+        let result = self.backend.simulation_step(&PointKineticsInput {
+            dt,
+            neutron_population: state.neutron_population,
+            precursors: state.precursors,
+            fuel_temp: state.avg_fuel_temp,
+            coolant_temp: state.avg_coolant_temp,
+            graphite_temp: state.avg_graphite_temp,
+            coolant_void: state.avg_coolant_void,
+            iodine_135: state.iodine_135,
+            xenon_135: state.xenon_135,
+            total_rod_worth,
+            smoothed_reactivity: state.smoothed_reactivity,
+            scram_active: state.scram_active,
+            kinetics_solver: *self.kinetics_solver.lock().unwrap(),
+        });
+        
+        // Update state from Fortran results
+        let previous_population = state.neutron_population;
+        state.set_neutron_population(result.neutron_population);
+        // 6-group precursors live in the backend; the scalar is their sum
+        state.precursors_6 = self.backend.precursors_6group();
+        state.precursors = result.precursors;
+        state.debug_assert_precursors_consistent();
+        if !state.thermal_frozen {
+            state.avg_fuel_temp = result.fuel_temp;
+            state.avg_coolant_temp = result.coolant_temp;
+            state.avg_graphite_temp = result.graphite_temp;
+            state.avg_coolant_void = result.coolant_void;
+        }
+        if !state.xenon_frozen {
+            state.iodine_135 = non_negative_poison("I-135", None, result.iodine_135, dt);
+            state.xenon_135 = non_negative_poison("Xe-135", None, result.xenon_135, dt);
+        }
+        state.xenon_reactivity = self.backend.xenon_reactivity(state.xenon_135);
+        if let Some(breakdown) = &balance {
+            debug_assert_reactivity_balance(breakdown, Some((previous_reactivity, result.reactivity)));
+        }
+        state.smoothed_reactivity = result.reactivity;
+        state.reactivity_rate = if dt > 0.0 { (result.reactivity - state.reactivity) / dt } else { 0.0 };
+        state.reactivity = result.reactivity;
+        state.k_eff = result.k_eff;
+        state.power_percent = result.power_percent;
+        state.power_mw = result.power_percent / 100.0 * self.nominal_power_mw;
+        state.period = if result.period > 1.0e20 { f64::INFINITY } else { result.period };
+        state.update_reactivity_units();
+        state.update_startup_indicators(previous_population, dt);
+        
+        state.update_auto_regulator(dt);
+        
+        // Update axial flux distribution using Fortran
+        state.axial_flux = self.backend.axial_flux(
+            constants::AXIAL_POINTS,
+            state.neutron_population,
+            self.axial_tilt(),
+        );
+        
+        // Process alert flags from Fortran
+        let flags = result.alert_flags;
+        if flags & fortran_ffi::ALERT_POWER_HIGH != 0 {
+            state.raise_alert(AlertCode::PowerHigh, "WARNING: Power exceeds 110% nominal!".to_string());
+        }
+        if flags & fortran_ffi::ALERT_REACTIVITY_HIGH != 0 {
+            state.raise_alert(AlertCode::ReactivityHigh, "WARNING: Reactivity exceeds 0.5$!".to_string());
+        }
+        if flags & fortran_ffi::ALERT_PROMPT_CRITICAL != 0 {
+            state.raise_alert(AlertCode::PromptCritical, "CRITICAL: Prompt critical condition!".to_string());
+        }
+        if flags & fortran_ffi::ALERT_FUEL_TEMP_HIGH != 0 {
+            state.raise_alert(AlertCode::FuelTempHigh, "WARNING: Fuel temperature exceeds limit!".to_string());
+        }
+        if flags & fortran_ffi::ALERT_VOID_HIGH != 0 {
+            state.raise_alert(AlertCode::VoidHigh, "WARNING: High void fraction - positive reactivity feedback!".to_string());
+        }
+        if flags & fortran_ffi::ALERT_SHORT_PERIOD != 0 {
+            let period = state.period;
+            state.raise_alert(AlertCode::ShortPeriod, format!("WARNING: Short reactor period: {:.1}s", period));
+        }
+        if flags & fortran_ffi::ALERT_GROWTH_CLAMPED != 0 {
+            state.raise_alert(
+                AlertCode::PowerExcursionClamped,
+                "CRITICAL: Power excursion exceeds the solver step bound - growth clamped!".to_string(),
+            );
+        }
+        self.check_stability(&mut state);
+        if state.low_power_mode && state.startup_rate_dpm > constants::STARTUP_RATE_LIMIT_DPM {
+            let rate = state.startup_rate_dpm;
+            state.raise_alert(AlertCode::StartupRateHigh, format!("WARNING: High startup rate: {:.2} DPM", rate));
+        }
+        state.update_coolant_inventory(self.nominal_power_mw, dt);
+        if state.coolant_inventory_fraction < constants::COOLANT_LOW_LEVEL_FRACTION {
+            let level = state.coolant_inventory_fraction * 100.0;
+            state.raise_alert(AlertCode::CoolantLevelLow, format!("WARNING: Low coolant inventory: {:.0}%", level));
+        }
+        state.update_inlet_plenum(saturation_temp, plenum_mixing_time, dt);
+        self.set_inlet_temp(state.inlet_plenum_temp);
+        
+        // Check for explosion (from Fortran)
+        if !state.explosion_occurred {
+            state.explosion_severity = result.explosion_severity;
         }
-        
-        // Check for explosion (from Fortran)
         if !state.explosion_occurred && result.explosion_severity >= 1.0 {
             state.explosion_occurred = true;
             state.explosion_time = state.time;
-            state.alerts.push("*** STEAM EXPLOSION - CORE DESTRUCTION ***".to_string());
+            state.raise_alert(AlertCode::SteamExplosion, "*** STEAM EXPLOSION - CORE DESTRUCTION ***".to_string());
         }
         
+        state.sort_alerts();
+        self.alert_tracker.lock().unwrap().update(state.time, &state.alert_details);
+        
         // Update time
-        state.time += dt;
+        state.advance_time(dt);
+        self.reactivity_trace.lock().unwrap().record(state.time, state.reactivity);
+        self.keff_history.lock().unwrap().record(state.time, state.k_eff, self.average_rod_position());
+        let substeps = self.backend.last_substep_count();
+        *self.last_time_step.lock().unwrap() = Some(EffectiveTimeStep {
+            dt,
+            substeps,
+            effective_dt: dt / substeps as f64,
+        });
+        drop(state);
+        
+        self.check_protection_trips();
+        self.record_checkpoint();
+    }
+    
+    /// SCRAM if an armed protection trip has reached its limit
+    fn check_protection_trips(&self) {
+        let tripped = {
+            let state = self.state.lock().unwrap();
+            if state.scram_active {
+                return;
+            }
+            let bypasses = self.trip_bypasses.lock().unwrap();
+            Trip::ALL.into_iter().find(|trip| !bypasses.contains(trip) && trip.condition_present(&state))
+        };
+        if let Some(trip) = tripped {
+            println!("[reactor] Automatic SCRAM: {:?} trip", trip);
+            self.scram();
+        }
+    }
+    
+    /// Keep a full copy of the run once per `CHECKPOINT_INTERVAL_S`
+    fn record_checkpoint(&self) {
+        let time = self.state.lock().unwrap().time;
+        if !self.checkpoints.lock().unwrap().due(time) {
+            return;
+        }
+        // Taken one at a time before state -> rods -> channels: regulate_lar_zones
+        // locks the zones ahead of the rods
+        let explosion_tracker = self.backend.explosion_tracker();
+        let lar_zones = self.lar_zones.lock().unwrap().clone();
+        let event_times = *self.event_times.lock().unwrap();
+        let fault_injector = self.fault_injector.lock().unwrap().clone();
+        let detector_faults = self.detector_faults.lock().unwrap().clone();
+        let checkpoint = {
+            let state = self.state.lock().unwrap();
+            let rods = self.control_rods.lock().unwrap();
+            let channels = self.fuel_channels.lock().unwrap();
+            Checkpoint {
+                state: state.clone(),
+                control_rods: rods.clone(),
+                fuel_channels: channels.clone(),
+                lar_zones,
+                event_times,
+                fault_injector,
+                detector_faults,
+                explosion_tracker,
+            }
+        };
+        self.checkpoints.lock().unwrap().push(checkpoint);
+    }
+    
+    /// Step back at least `seconds` by restoring the latest checkpoint taken
+    /// at or before `now - seconds`. False (nothing changes) when no kept
+    /// checkpoint is that old. State, rods, channels, LAR zones, event
+    /// times, drill and detector faults, the kinetics precursors and the
+    /// explosion tracking are restored; histories and alerts keep what they saw.
+    pub fn rewind(&self, seconds: f64) -> bool {
+        if !(seconds.is_finite() && seconds >= 0.0) {
+            return false;
+        }
+        let target = self.state.lock().unwrap().time - seconds;
+        let Some(checkpoint) = self.checkpoints.lock().unwrap().rewind_to(target) else {
+            return false;
+        };
+        
+        {
+            let mut state = self.state.lock().unwrap();
+            let mut rods = self.control_rods.lock().unwrap();
+            let mut channels = self.fuel_channels.lock().unwrap();
+            *state = checkpoint.state;
+            *rods = checkpoint.control_rods;
+            *channels = checkpoint.fuel_channels;
+            self.backend.restore_precursors_6group(&state.precursors_6);
+        }
+        *self.lar_zones.lock().unwrap() = checkpoint.lar_zones;
+        *self.event_times.lock().unwrap() = checkpoint.event_times;
+        *self.fault_injector.lock().unwrap() = checkpoint.fault_injector;
+        *self.detector_faults.lock().unwrap() = checkpoint.detector_faults;
+        if let Some(tracker) = &checkpoint.explosion_tracker {
+            self.backend.restore_explosion_tracker(tracker);
+        }
+        self.stability_monitor.lock().unwrap().clear();
+        println!("[reactor] Rewound {:.1} s", seconds);
+        true
+    }
+    
+    /// Keep the last `count` checkpoints (one per `CHECKPOINT_INTERVAL_S`)
+    pub fn set_checkpoint_count(&self, count: usize) -> Result<(), String> {
+        self.checkpoints.lock().unwrap().set_capacity(count)
+    }
+    
+    /// Simulation times `rewind` can return to, oldest first [s]
+    pub fn get_checkpoint_times(&self) -> Vec<f64> {
+        self.checkpoints.lock().unwrap().times()
+    }
+    
+    /// Armed or bypassed state of every protection trip
+    pub fn get_trip_status(&self) -> Vec<TripStatus> {
+        let state = self.state.lock().unwrap();
+        let bypasses = self.trip_bypasses.lock().unwrap();
+        Trip::ALL.into_iter()
+            .map(|trip| TripStatus {
+                trip,
+                setpoint: trip.setpoint(),
+                bypassed: bypasses.contains(&trip),
+                condition_present: trip.condition_present(&state),
+            })
+            .collect()
+    }
+    
+    /// Bypass a protection trip (it no longer SCRAMs the reactor) or re-arm it
+    pub fn set_trip_bypass(&self, trip: Trip, bypassed: bool) {
+        let mut bypasses = self.trip_bypasses.lock().unwrap();
+        if bypassed {
+            bypasses.insert(trip);
+        } else {
+            bypasses.remove(&trip);
+        }
+    }
+    
+    /// Feed the step's power to the divergence watch; on detection alert
+    /// with a smaller time step, or switch to it when auto-reduce is on
+    fn check_stability(&self, state: &mut ReactorState) {
+        let mut monitor = self.stability_monitor.lock().unwrap();
+        if !monitor.record(state.time, state.power_percent, state.dt) {
+            return;
+        }
+        let suggested_dt = monitor.suggested_dt().unwrap_or(state.dt);
+        if monitor.auto_reduce_dt() && suggested_dt < state.dt {
+            state.dt = suggested_dt;
+            state.raise_alert(
+                AlertCode::NumericalDivergence,
+                format!("WARNING: Power oscillation is numerical - time step reduced to {:.3} s", suggested_dt),
+            );
+        } else {
+            state.raise_alert(
+                AlertCode::NumericalDivergence,
+                format!("WARNING: Power oscillation is numerical - reduce the time step to {:.3} s", suggested_dt),
+            );
+        }
+    }
+    
+    /// Whether the run looks numerically unstable, and the time step to use if so
+    pub fn get_stability_status(&self) -> StabilityStatus {
+        self.stability_monitor.lock().unwrap().status()
+    }
+    
+    /// Let the divergence watch halve the time step itself on detection
+    pub fn set_auto_reduce_time_step(&self, enabled: bool) {
+        self.stability_monitor.lock().unwrap().set_auto_reduce_dt(enabled);
+    }
+    
+    /// Remember the current reactivity as the start of a maneuver
+    pub fn mark_reactivity_reference(&self) {
+        let reactivity = self.state.lock().unwrap().reactivity_dollars;
+        *self.reactivity_reference.lock().unwrap() = Some(reactivity);
+    }
+    
+    /// Net reactivity inserted since `mark_reactivity_reference` [$]
+    /// 0 when no reference is marked; reset clears the mark.
+    pub fn get_reactivity_since_reference(&self) -> f64 {
+        let reactivity = self.state.lock().unwrap().reactivity_dollars;
+        self.reactivity_reference.lock().unwrap().map_or(0.0, |reference| reactivity - reference)
+    }
+    
+    /// Reactivity the kinetics used in the last step minus the sum of the
+    /// breakdown components at the current conditions [Δk/k]
+    ///
+    /// That is the smoothed point-kinetics reactivity after `step()` and the
+    /// mean channel reactivity after `step_spatial()`. While the smoothing
+    /// catches up with a change the residual is the remaining lag; one that
+    /// persists in a settled core is a term the breakdown misses (the spatial
+    /// model adds local rod and void effects it does not see). Debug builds
+    /// also assert the balance every step; release builds only compute it
+    /// on request.
+    pub fn get_reactivity_balance_residual(&self) -> f64 {
+        let used = self.state.lock().unwrap().reactivity;
+        used - self.get_reactivity_breakdown().component_sum()
     }
     
     /// Calculate automatic regulator (AR) rod adjustment using PID control
@@ -909,55 +3486,700 @@ impl ReactorSimulator {
         output.clamp(-max_movement, max_movement)
     }
     
-    /// Adjust automatic (AR/LAR) rod positions
+    /// Adjust automatic (AR, and LAR if `include_lar`) rod positions
     /// positive delta = withdraw rods (increase power)
     /// negative delta = insert rods (decrease power)
-    fn adjust_automatic_rods(&self, delta: f64) {
+    fn adjust_automatic_rods(&self, delta: f64, include_lar: bool) {
+        let limits = self.rod_travel_limits.lock().unwrap();
         let mut rods = self.control_rods.lock().unwrap();
         for rod in rods.iter_mut() {
-            if rod.rod_type == RodType::Automatic {
+            if rod.rod_type == RodType::Automatic && !rod.stuck
+                && (include_lar || rod.channel_type != LAR_CHANNEL_TYPE)
+            {
                 // Withdraw to increase power, insert to decrease
-                rod.position = (rod.position + delta).clamp(0.0, 1.0);
+                rod.position = rod_travel_limits(&limits, rod).clamp(rod.position + delta);
+            }
+        }
+    }
+    
+    /// Drive each LAR rod from its own zone's power error (measured last step)
+    /// Returns false if there are no LAR zones.
+    fn regulate_lar_zones(&self, settings: &AutoRegulatorSettings, dt: f64) -> bool {
+        let zones = self.lar_zones.lock().unwrap();
+        if zones.is_empty() {
+            return false;
+        }
+        
+        let limits = self.rod_travel_limits.lock().unwrap();
+        let mut rods = self.control_rods.lock().unwrap();
+        for zone in zones.iter() {
+            let zone_settings = AutoRegulatorSettings {
+                integral_error: zone.integral_error,
+                last_error: zone.last_error,
+                ..settings.clone()
+            };
+            let delta = self.calculate_ar_adjustment(&zone_settings, zone.power_percent, dt);
+            if let Some(rod) = rods.get_mut(zone.rod_id).filter(|rod| !rod.stuck) {
+                rod.position = rod_travel_limits(&limits, rod).clamp(rod.position + delta);
             }
         }
+        true
     }
     
     /// Initiate emergency SCRAM
     pub fn scram(&self) {
-        // Physically insert all control rods
-        let total_rod_worth: f64 = {
-            let mut rods = self.control_rods.lock().unwrap();
-            let mut worth = 0.0;
-            for rod in rods.iter_mut() {
-                rod.position = 0.0;
-                worth += rod.worth;
-            }
-            worth
-        };
+        // Rods are released here and travel in over the following steps
+        // (see advance_scram_rods); stuck rods stay where they are
+        let axial_flux = self.state.lock().unwrap().axial_flux.clone();
+        let total_rod_worth = self.calculate_total_rod_worth(&axial_flux);
         
         // Update state
         let mut state = self.state.lock().unwrap();
         if !state.scram_active {
             state.scram_active = true;
             state.scram_time = 0.0;
-            state.alerts.push("SCRAM INITIATED!".to_string());
+            self.event_times.lock().unwrap().last_scram = Some(state.time);
+            state.raise_alert(AlertCode::ScramInitiated, "SCRAM INITIATED!".to_string());
+            state.sort_alerts();
+            self.alert_tracker.lock().unwrap().update(state.time, &state.alert_details);
             
-            // Calculate new reactivity using Fortran
-            let new_reactivity = fortran_ffi::calc_total_reactivity(
+            // Calculate new reactivity with the core-level backend
+            let new_reactivity = self.backend.total_reactivity(&PointKineticsInput {
+                dt: state.dt,
+                neutron_population: state.neutron_population,
+                precursors: state.precursors,
+                fuel_temp: state.avg_fuel_temp,
+                coolant_temp: state.avg_coolant_temp,
+                graphite_temp: state.avg_graphite_temp,
+                coolant_void: state.avg_coolant_void,
+                iodine_135: state.iodine_135,
+                xenon_135: state.xenon_135,
+                total_rod_worth,
+                smoothed_reactivity: state.smoothed_reactivity,
+                scram_active: true,
+                kinetics_solver: *self.kinetics_solver.lock().unwrap(),
+            });
+            
+            state.smoothed_reactivity = new_reactivity;
+            state.reactivity = new_reactivity;
+            state.update_reactivity_units();
+        }
+    }
+    
+    /// Mark a control rod as mechanically stuck (or free it)
+    /// A stuck rod ignores all move commands, the AR loop and SCRAM.
+    /// Returns false for an unknown rod_id.
+    pub fn set_rod_stuck(&self, rod_id: usize, stuck: bool) -> bool {
+        let mut rods = self.control_rods.lock().unwrap();
+        if let Some(rod) = rods.get_mut(rod_id) {
+            rod.stuck = stuck;
+            true
+        } else {
+            false
+        }
+    }
+    
+    /// Restrict the travel of rods of a channel type (RR, AR, LAR, USP, AZ)
+    /// Rods outside the new range are moved into it; stuck rods stay put.
+    /// Use (0.0, 1.0) to restore full stroke. SCRAM ignores these limits.
+    pub fn set_rod_travel_limits(&self, channel_type: &str, min_position: f64, max_position: f64) -> Result<(), String> {
+        if !(min_position.is_finite() && max_position.is_finite()
+            && 0.0 <= min_position && min_position <= max_position && max_position <= 1.0)
+        {
+            return Err(format!(
+                "Invalid travel limits ({}, {}): need 0 <= min <= max <= 1",
+                min_position, max_position
+            ));
+        }
+        let limits = RodTravelLimits { min_position, max_position };
+        
+        let mut limits_by_type = self.rod_travel_limits.lock().unwrap();
+        let moved_rods: Vec<(usize, f64)> = {
+            let mut rods = self.control_rods.lock().unwrap();
+            if !rods.iter().any(|rod| rod.channel_type == channel_type) {
+                return Err(format!("No control rods of channel type {}", channel_type));
+            }
+            rods.iter_mut()
+                .filter(|rod| rod.channel_type == channel_type && !rod.stuck)
+                .map(|rod| {
+                    rod.position = limits.clamp(rod.position);
+                    (rod.id, rod.position)
+                })
+                .collect()
+        };
+        
+        let mut channels = self.fuel_channels.lock().unwrap();
+        for channel in channels.iter_mut() {
+            if let Some(rod_id) = channel.control_rod_id {
+                if let Some(&(_, position)) = moved_rods.iter().find(|(id, _)| *id == rod_id) {
+                    channel.local_rod_position = position;
+                }
+            }
+        }
+        
+        limits_by_type.insert(channel_type.to_string(), limits);
+        Ok(())
+    }
+    
+    /// Catalog of the controllable elements: every rod, the rod groups and
+    /// the settable scalars, with current values
+    pub fn describe_controls(&self) -> ControlManifest {
+        let scalar = |name: &str, command: &str, unit: &str, min: f64, max: f64, value: f64| ScalarControl {
+            name: name.to_string(),
+            command: command.to_string(),
+            unit: unit.to_string(),
+            min,
+            max,
+            value,
+        };
+        let scalars = {
+            let state = self.state.lock().unwrap();
+            vec![
+                scalar("target_power", "set_target_power", "%",
+                    constants::TARGET_POWER_MIN_PERCENT, constants::TARGET_POWER_MAX_PERCENT,
+                    state.auto_regulator.target_power),
+                scalar("feedwater_fraction", "set_feedwater_rate", "fraction of nominal",
+                    0.0, constants::FEEDWATER_MAX_FRACTION, state.feedwater_fraction),
+                scalar("coolant_inventory", "set_coolant_inventory", "fraction of normal",
+                    0.0, 1.0, state.coolant_inventory_fraction),
+                scalar("time_step", "set_time_step", "s",
+                    constants::TIME_STEP_MIN_S, constants::TIME_STEP_MAX_S, state.dt),
+            ]
+        };
+        
+        let limits = self.rod_travel_limits.lock().unwrap().clone();
+        let rods = self.control_rods.lock().unwrap();
+        let rod_controls = rods.iter()
+            .map(|rod| {
+                let travel = rod_travel_limits(&limits, rod);
+                RodControl {
+                    id: rod.id,
+                    rod_type: rod.rod_type.clone(),
+                    channel_type: rod.channel_type.clone(),
+                    grid_x: rod.grid_x,
+                    grid_y: rod.grid_y,
+                    position: rod.position,
+                    min_position: travel.min_position,
+                    max_position: travel.max_position,
+                    stuck: rod.stuck,
+                }
+            })
+            .collect();
+        
+        let mut rod_groups = Vec::new();
+        let rod_types = [
+            ("manual", RodType::Manual),
+            ("automatic", RodType::Automatic),
+            ("shortened", RodType::Shortened),
+            ("emergency", RodType::Emergency),
+        ];
+        for (name, rod_type) in rod_types {
+            let rod_ids: Vec<usize> = rods.iter().filter(|r| r.rod_type == rod_type).map(|r| r.id).collect();
+            if !rod_ids.is_empty() {
+                rod_groups.push(RodGroupControl { name: name.to_string(), command: "move_rod_group".to_string(), rod_ids });
+            }
+        }
+        let mut by_channel_type: BTreeMap<&str, Vec<usize>> = BTreeMap::new();
+        for rod in rods.iter() {
+            by_channel_type.entry(rod.channel_type.as_str()).or_default().push(rod.id);
+        }
+        rod_groups.extend(by_channel_type.into_iter().map(|(name, rod_ids)| RodGroupControl {
+            name: name.to_string(),
+            command: "move_rod_group_by_channel_type".to_string(),
+            rod_ids,
+        }));
+        
+        ControlManifest { rods: rod_controls, rod_groups, scalars }
+    }
+    
+    /// Every interlock currently blocking (or overriding) operator rod moves
+    pub fn get_active_interlocks(&self) -> Vec<Interlock> {
+        let (scram_active, ar_enabled) = {
+            let state = self.state.lock().unwrap();
+            (state.scram_active, state.auto_regulator.enabled)
+        };
+        let limits = self.rod_travel_limits.lock().unwrap().clone();
+        let rods = self.control_rods.lock().unwrap();
+        let mut interlocks = Vec::new();
+        
+        if scram_active {
+            interlocks.push(Interlock {
+                kind: InterlockKind::ScramActive,
+                rod_ids: rods.iter().filter(|r| !r.stuck).map(|r| r.id).collect(),
+                reason: "SCRAM in progress: all free rods are being driven in until reset".to_string(),
+            });
+        } else if ar_enabled {
+            interlocks.push(Interlock {
+                kind: InterlockKind::AutoRegulator,
+                rod_ids: rods.iter().filter(|r| r.rod_type == RodType::Automatic && !r.stuck).map(|r| r.id).collect(),
+                reason: "Automatic regulator enabled: AR/LAR rods follow the power setpoint".to_string(),
+            });
+        }
+        
+        for rod in rods.iter().filter(|r| r.stuck) {
+            interlocks.push(Interlock {
+                kind: InterlockKind::StuckRod,
+                rod_ids: vec![rod.id],
+                reason: format!(
+                    "Rod {} ({}) at ({}, {}) is stuck at {:.1}%",
+                    rod.id, rod.channel_type, rod.grid_x, rod.grid_y, rod.position * 100.0
+                ),
+            });
+        }
+        
+        let mut limited: Vec<(&String, &RodTravelLimits)> = limits.iter()
+            .filter(|(_, l)| **l != RodTravelLimits::default())
+            .collect();
+        limited.sort_by(|a, b| a.0.cmp(b.0));
+        for (channel_type, l) in limited {
+            interlocks.push(Interlock {
+                kind: InterlockKind::TravelLimit,
+                rod_ids: rods.iter().filter(|r| &r.channel_type == channel_type).map(|r| r.id).collect(),
+                reason: format!(
+                    "{} rods limited to {:.1}%..{:.1}% travel",
+                    channel_type, l.min_position * 100.0, l.max_position * 100.0
+                ),
+            });
+        }
+        
+        interlocks
+    }
+    
+    /// Travel limits of each channel type that has them
+    pub fn get_rod_travel_limits(&self) -> HashMap<String, RodTravelLimits> {
+        self.rod_travel_limits.lock().unwrap().clone()
+    }
+    
+    /// Estimate the position of a rod group (by channel type) at which the
+    /// reactor goes critical under the current temperatures, void and xenon
+    ///
+    /// Other rods stay where they are. Bisects on the backend steady-state
+    /// reactivity, which rises monotonically as the group is withdrawn.
+    /// Fails if the type has no movable rods or criticality is out of reach.
+    pub fn predict_critical_position(&self, channel_type: &str) -> Result<f64, String> {
+        let (feedback, axial_flux) = self.current_feedback();
+        self.critical_group_position(channel_type, &feedback, &axial_flux)
+    }
+    
+    /// Feedback conditions and axial flux of the current state
+    fn current_feedback(&self) -> (FeedbackConditions, Vec<f64>) {
+        let state = self.state.lock().unwrap();
+        let feedback = FeedbackConditions {
+            fuel_temp: state.avg_fuel_temp,
+            graphite_temp: state.avg_graphite_temp,
+            coolant_void: state.avg_coolant_void,
+            xenon_135: state.xenon_135,
+        };
+        (feedback, state.axial_flux.clone())
+    }
+    
+    /// Rod group position (by channel type) that holds `target_power` [% nominal]
+    /// steady: the position where the reactivity, with temperatures and void
+    /// settled at that power, is zero
+    ///
+    /// The settled temperatures come from the point thermal model; xenon
+    /// stays at its current value (it takes hours to settle). Other rods stay
+    /// where they are. Fails like `predict_critical_position`.
+    pub fn equilibrium_rod_position(&self, target_power: f64, channel_type: &str) -> Result<f64, String> {
+        if !(target_power > 0.0 && target_power <= constants::TARGET_POWER_MAX_PERCENT) {
+            return Err(format!(
+                "Target power {}% out of range (0, {}]",
+                target_power, constants::TARGET_POWER_MAX_PERCENT
+            ));
+        }
+        let (mut fuel_temp, mut coolant_temp, mut graphite_temp, mut coolant_void, xenon_135, axial_flux) = {
+            let state = self.state.lock().unwrap();
+            (
                 state.avg_fuel_temp,
+                state.avg_coolant_temp,
                 state.avg_graphite_temp,
                 state.avg_coolant_void,
                 state.xenon_135,
-                total_rod_worth,
-                state.smoothed_reactivity,
-                state.dt,
-                true,
+                state.axial_flux.clone(),
+            )
+        };
+        // A step much longer than every thermal time constant lands on the
+        // targets; repeating it lets the void follow the settled coolant
+        for _ in 0..constants::EQUILIBRIUM_THERMAL_ITERATIONS {
+            (fuel_temp, coolant_temp, graphite_temp, coolant_void) = self.backend.update_temperatures(
+                target_power, fuel_temp, coolant_temp, graphite_temp, coolant_void, constants::EQUILIBRIUM_THERMAL_DT_S,
             );
+        }
+        let feedback = FeedbackConditions { fuel_temp, graphite_temp, coolant_void, xenon_135 };
+        self.critical_group_position(channel_type, &feedback, &axial_flux)
+    }
+    
+    /// Bisect for the group position with zero steady-state reactivity
+    fn critical_group_position(&self, channel_type: &str, feedback: &FeedbackConditions, axial_flux: &[f64]) -> Result<f64, String> {
+        let rods = self.control_rods.lock().unwrap().clone();
+        if !rods.iter().any(|r| r.channel_type == channel_type && !r.stuck) {
+            return Err(format!("No movable rods of type '{}'", channel_type));
+        }
+        
+        // Steady-state reactivity with the group at `position`
+        let reactivity_at = |position: f64| {
+            self.reactivity_with_rods_at(&rods, feedback, axial_flux, |rod| {
+                if rod.channel_type == channel_type && !rod.stuck { position } else { rod.position }
+            })
+        };
+        
+        if reactivity_at(0.0) >= 0.0 {
+            return Err(format!("Reactor is critical with '{}' rods fully inserted", channel_type));
+        }
+        if reactivity_at(1.0) < 0.0 {
+            return Err(format!("Reactor stays subcritical with '{}' rods fully withdrawn", channel_type));
+        }
+        
+        let (mut low, mut high) = (0.0, 1.0);
+        for _ in 0..40 {
+            let mid = 0.5 * (low + high);
+            if reactivity_at(mid) < 0.0 {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        Ok(0.5 * (low + high))
+    }
+    
+    /// Control authority of each rod group (by channel type): steady-state
+    /// reactivity per percent of withdrawal [$/%] under current conditions
+    ///
+    /// Every movable rod of the group is withdrawn by `ROD_SENSITIVITY_STEP`
+    /// (up to its travel limit) without touching the simulation. Groups that
+    /// are fully withdrawn or all stuck report 0.
+    pub fn get_rod_group_sensitivity(&self) -> HashMap<String, f64> {
+        let (feedback, axial_flux) = self.current_feedback();
+        let limits = self.rod_travel_limits.lock().unwrap().clone();
+        let rods = self.control_rods.lock().unwrap().clone();
+        
+        // Steady-state reactivity with `group` withdrawn by `step`
+        let reactivity_with = |group: &str, step: f64| {
+            self.reactivity_with_rods_at(&rods, &feedback, &axial_flux, |rod| {
+                if rod.channel_type == group && !rod.stuck {
+                    (rod.position + step).min(rod_travel_limits(&limits, rod).max_position.max(rod.position))
+                } else {
+                    rod.position
+                }
+            })
+        };
+        
+        let baseline = reactivity_with("", 0.0); // No group moved
+        let percent = constants::ROD_SENSITIVITY_STEP * 100.0;
+        let mut sensitivity = HashMap::new();
+        for rod in rods.iter() {
+            if !sensitivity.contains_key(&rod.channel_type) {
+                let change = reactivity_with(&rod.channel_type, constants::ROD_SENSITIVITY_STEP) - baseline;
+                sensitivity.insert(rod.channel_type.clone(), change / constants::BETA_EFF / percent);
+            }
+        }
+        sensitivity
+    }
+    
+    /// Shutdown margin [$]: how far subcritical the core is with every rod
+    /// fully inserted except the single highest-worth rod (worst stuck rod)
+    ///
+    /// Uses the steady-state reactivity at the current temperatures, void and
+    /// xenon. Rods that are already stuck stay where they are. Positive means
+    /// the core can be held shut down; negative means it would be critical.
+    pub fn get_shutdown_margin(&self) -> f64 {
+        let (feedback, axial_flux) = self.current_feedback();
+        let usp_weight = 2.0 * axial_bottom_fraction(&axial_flux);
+        let rods = self.control_rods.lock().unwrap().clone();
+        
+        let shapes = *self.rod_worth_shapes.lock().unwrap();
+        let full_worth = |rod: &ControlRod| self.rod_worth_at(rod, 0.0, usp_weight, &shapes).1;
+        let stuck_out = rods.iter()
+            .filter(|rod| !rod.stuck)
+            .max_by(|a, b| full_worth(a).total_cmp(&full_worth(b)))
+            .map(|rod| rod.id);
+        
+        let reactivity = self.reactivity_with_rods_at(&rods, &feedback, &axial_flux, |rod| {
+            if Some(rod.id) == stuck_out {
+                1.0
+            } else if rod.stuck {
+                rod.position
+            } else {
+                0.0
+            }
+        });
+        -reactivity / constants::BETA_EFF
+    }
+    
+    /// Move a rod by `delta_position`, run `settle_steps` spatial steps and
+    /// return the change in total reactivity [$]
+    ///
+    /// The change includes any feedback that builds up while settling, as a
+    /// rod-worth measurement in the plant would. Fails for an unknown or
+    /// stuck rod, or a settle count outside 1..=ROD_WORTH_SETTLE_STEPS_MAX.
+    pub fn measure_rod_worth_increment(&self, rod_id: usize, delta_position: f64, settle_steps: usize) -> Result<f64, String> {
+        if !(1..=constants::ROD_WORTH_SETTLE_STEPS_MAX).contains(&settle_steps) {
+            return Err(format!(
+                "Settle steps {} out of range [1, {}]",
+                settle_steps, constants::ROD_WORTH_SETTLE_STEPS_MAX
+            ));
+        }
+        if !delta_position.is_finite() {
+            return Err(format!("Invalid rod increment {}", delta_position));
+        }
+        let position = match self.control_rods.lock().unwrap().get(rod_id) {
+            Some(rod) if rod.stuck => return Err(format!("Rod {} is stuck", rod_id)),
+            Some(rod) => rod.position,
+            None => return Err(format!("Invalid rod_id {}", rod_id)),
+        };
+        
+        let reactivity_before = self.state.lock().unwrap().reactivity_dollars;
+        self.move_rod(rod_id, position + delta_position);
+        for _ in 0..settle_steps {
+            self.step_spatial();
+        }
+        let reactivity_after = self.state.lock().unwrap().reactivity_dollars;
+        Ok(reactivity_after - reactivity_before)
+    }
+    
+    /// Flatten the radial power distribution by biasing the manual (RR) rods,
+    /// returning the radial peaking factor (max / mean channel power) achieved
+    ///
+    /// Each iteration gives every fuel channel to its nearest free manual
+    /// rod, inserts rods over regions hotter than the core average and
+    /// withdraws them over colder ones (net bias removed, so total insertion
+    /// and core power are held), then runs `AUTO_FLATTEN_SETTLE_STEPS`
+    /// spatial steps. Stops once the peaking factor stops improving and
+    /// puts the best rod pattern back. Fails during a SCRAM, without power
+    /// or without free manual rods.
+    pub fn auto_flatten(&self, max_iterations: usize) -> Result<f64, String> {
+        if !(1..=constants::AUTO_FLATTEN_MAX_ITERATIONS).contains(&max_iterations) {
+            return Err(format!(
+                "Iteration count {} out of range [1, {}]",
+                max_iterations, constants::AUTO_FLATTEN_MAX_ITERATIONS
+            ));
+        }
+        if self.state.lock().unwrap().scram_active {
+            return Err("Cannot reshape the core during a SCRAM".to_string());
+        }
+        let rods: Vec<(usize, i32, i32)> = self.control_rods.lock().unwrap()
+            .iter()
+            .filter(|rod| rod.rod_type == RodType::Manual && !rod.stuck)
+            .map(|rod| (rod.id, rod.grid_x, rod.grid_y))
+            .collect();
+        if rods.is_empty() {
+            return Err("No free manual rods to bias".to_string());
+        }
+        
+        let peaking = || radial_peaking_factor(&self.fuel_channels.lock().unwrap());
+        let positions = || {
+            let all = self.control_rods.lock().unwrap();
+            rods.iter().map(|&(id, _, _)| all[id].position).collect::<Vec<f64>>()
+        };
+        let settle = || {
+            for _ in 0..constants::AUTO_FLATTEN_SETTLE_STEPS {
+                self.step_spatial();
+            }
+        };
+        
+        let mut best_peaking = peaking();
+        if best_peaking <= 0.0 {
+            return Err("Core has no power distribution to flatten".to_string());
+        }
+        let mut best_positions = positions();
+        
+        for _ in 0..max_iterations {
+            // Power of each rod's region relative to the core average, minus one
+            let excess: Vec<f64> = {
+                let channels = self.fuel_channels.lock().unwrap();
+                let mean = channels.iter().map(|c| c.local_power).sum::<f64>() / channels.len() as f64;
+                let mut sums = vec![0.0; rods.len()];
+                let mut counts = vec![0usize; rods.len()];
+                for channel in channels.iter() {
+                    let dist_sq = |&(_, x, y): &(usize, i32, i32)| (x - channel.grid_x).pow(2) + (y - channel.grid_y).pow(2);
+                    if let Some(nearest) = (0..rods.len()).min_by_key(|&k| dist_sq(&rods[k])) {
+                        sums[nearest] += channel.local_power;
+                        counts[nearest] += 1;
+                    }
+                }
+                (0..rods.len())
+                    .map(|k| if counts[k] > 0 && mean > 0.0 { sums[k] / counts[k] as f64 / mean - 1.0 } else { 0.0 })
+                    .collect()
+            };
+            let mean_excess = excess.iter().sum::<f64>() / excess.len() as f64;
+            {
+                let limits = self.rod_travel_limits.lock().unwrap();
+                let mut all = self.control_rods.lock().unwrap();
+                for (k, &(id, _, _)) in rods.iter().enumerate() {
+                    let rod = &mut all[id];
+                    let target = rod.position - constants::AUTO_FLATTEN_GAIN * (excess[k] - mean_excess);
+                    rod.position = rod_travel_limits(&limits, rod).clamp(target);
+                }
+            }
+            settle();
             
-            state.smoothed_reactivity = new_reactivity;
-            state.reactivity = new_reactivity;
-            state.reactivity_dollars = new_reactivity / constants::BETA_EFF;
+            let achieved = peaking();
+            if achieved < best_peaking - constants::AUTO_FLATTEN_TOLERANCE {
+                best_peaking = achieved;
+                best_positions = positions();
+            } else {
+                // No better: go back to the best pattern found
+                {
+                    let mut all = self.control_rods.lock().unwrap();
+                    for (&(id, _, _), &position) in rods.iter().zip(&best_positions) {
+                        all[id].position = position;
+                    }
+                }
+                settle();
+                break;
+            }
+        }
+        
+        self.record_rod_move();
+        Ok(peaking())
+    }
+    
+    /// Open-loop power response to a rod step: moves every free rod of
+    /// `channel_type` by `delta_position`, then records (time, power_percent)
+    /// after each of `steps` spatial steps
+    ///
+    /// The automatic regulator is held off while recording and restored
+    /// afterwards; the rods stay at their new positions.
+    pub fn step_response(&self, channel_type: &str, delta_position: f64, steps: usize) -> Result<Vec<(f64, f64)>, String> {
+        if !(1..=constants::STEP_RESPONSE_MAX_STEPS).contains(&steps) {
+            return Err(format!(
+                "Step count {} out of range [1, {}]",
+                steps, constants::STEP_RESPONSE_MAX_STEPS
+            ));
+        }
+        if !delta_position.is_finite() {
+            return Err(format!("Invalid rod step {}", delta_position));
+        }
+        let rods: Vec<(usize, f64)> = self.control_rods.lock().unwrap()
+            .iter()
+            .filter(|rod| rod.channel_type == channel_type && !rod.stuck)
+            .map(|rod| (rod.id, rod.position))
+            .collect();
+        if rods.is_empty() {
+            return Err(format!("No movable control rods of channel type {}", channel_type));
+        }
+        
+        let ar_enabled = self.state.lock().unwrap().auto_regulator.enabled;
+        self.set_auto_regulator_enabled(false);
+        
+        for (rod_id, position) in rods {
+            self.move_rod(rod_id, position + delta_position);
+        }
+        let response = (0..steps)
+            .map(|_| {
+                self.step_spatial();
+                let state = self.state.lock().unwrap();
+                (state.time, state.power_percent)
+            })
+            .collect();
+        
+        self.set_auto_regulator_enabled(ar_enabled);
+        Ok(response)
+    }
+    
+    /// Add a point to the 1/M plot: the current rod bank position (mean
+    /// withdrawal of all rods except the emergency AZ rods) and count rate
+    /// The first point recorded is the reference for M. Cleared on reset.
+    pub fn record_1m_point(&self) -> Result<InverseMCurve, String> {
+        let count_rate = self.state.lock().unwrap().count_rate;
+        let rod_position = {
+            let rods = self.control_rods.lock().unwrap();
+            let bank: Vec<f64> = rods.iter()
+                .filter(|rod| rod.rod_type != RodType::Emergency)
+                .map(|rod| rod.position)
+                .collect();
+            if bank.is_empty() { 0.0 } else { bank.iter().sum::<f64>() / bank.len() as f64 }
+        };
+        let mut recorder = self.inverse_m.lock().unwrap();
+        recorder.record(rod_position, count_rate)?;
+        Ok(recorder.curve())
+    }
+    
+    /// The recorded 1/M points and the extrapolated critical bank position
+    pub fn get_1m_curve(&self) -> InverseMCurve {
+        self.inverse_m.lock().unwrap().curve()
+    }
+    
+    /// Start a new 1/M plot
+    pub fn clear_1m_curve(&self) {
+        self.inverse_m.lock().unwrap().clear();
+    }
+    
+    /// Check that the total rod worth lies in the realistic RBMK band
+    ///
+    /// Too little worth and the reactor cannot be shut down; too much and a
+    /// single rod movement becomes an uncontrollable reactivity step.
+    pub fn validate_rod_worth(&self) -> RodWorthReport {
+        let rods = self.control_rods.lock().unwrap();
+        let mut groups: BTreeMap<&str, RodGroupWorth> = BTreeMap::new();
+        for rod in rods.iter() {
+            let group = groups.entry(rod.channel_type.as_str()).or_insert_with(|| RodGroupWorth {
+                channel_type: rod.channel_type.clone(),
+                rod_count: 0,
+                worth: 0.0,
+            });
+            group.rod_count += 1;
+            group.worth += rod.worth;
+        }
+        
+        let total_worth: f64 = rods.iter().map(|rod| rod.worth).sum();
+        let range = constants::ROD_WORTH_TOTAL_MIN..=constants::ROD_WORTH_TOTAL_MAX;
+        let warning = if total_worth < *range.start() {
+            Some(format!(
+                "Total rod worth {:.4} Δk/k is below {:.2}: the reactor may not be controllable or shut down",
+                total_worth, range.start(),
+            ))
+        } else if total_worth > *range.end() {
+            Some(format!(
+                "Total rod worth {:.4} Δk/k exceeds {:.2}: single rod movements will be too strong",
+                total_worth, range.end(),
+            ))
+        } else {
+            None
+        };
+        
+        RodWorthReport {
+            total_worth,
+            total_worth_dollars: total_worth / constants::BETA_EFF,
+            by_type: groups.into_values().collect(),
+            out_of_range: warning.is_some(),
+            warning,
+        }
+    }
+    
+    /// Check which rods would insert on SCRAM and how much worth they carry
+    pub fn get_scram_readiness(&self) -> ScramReadiness {
+        let rods = self.control_rods.lock().unwrap();
+        let mut readiness = ScramReadiness {
+            total_rods: rods.len(),
+            rods_insertable: 0,
+            insertable_worth: 0.0,
+            insertable_worth_dollars: 0.0,
+            withheld_worth: 0.0,
+            stuck_rods: Vec::new(),
+        };
+        
+        for rod in rods.iter() {
+            // Worth still available between current position and full insertion
+            let remaining_worth = rod.effective_worth() * rod.position;
+            if rod.stuck {
+                readiness.withheld_worth += remaining_worth;
+                readiness.stuck_rods.push(StuckRodInfo {
+                    id: rod.id,
+                    channel_type: rod.channel_type.clone(),
+                    grid_x: rod.grid_x,
+                    grid_y: rod.grid_y,
+                    position: rod.position,
+                    withheld_worth: remaining_worth,
+                });
+            } else {
+                readiness.rods_insertable += 1;
+                readiness.insertable_worth += remaining_worth;
+            }
         }
+        
+        readiness.insertable_worth_dollars = readiness.insertable_worth / constants::BETA_EFF;
+        readiness
     }
     
     /// Reset SCRAM
@@ -967,40 +4189,62 @@ impl ReactorSimulator {
         state.scram_time = 0.0;
     }
     
-    /// Move a control rod
-    pub fn move_rod(&self, rod_id: usize, new_position: f64) {
-        let mut rods = self.control_rods.lock().unwrap();
-        if let Some(rod) = rods.get_mut(rod_id) {
-            rod.position = new_position.clamp(0.0, 1.0);
+    /// Move a control rod (within its type's travel limits)
+    /// Returns true if the rod exists, false for an unknown rod_id
+    /// (a stuck rod exists but keeps its position)
+    pub fn move_rod(&self, rod_id: usize, new_position: f64) -> bool {
+        let moved = {
+            let limits = self.rod_travel_limits.lock().unwrap();
+            let mut rods = self.control_rods.lock().unwrap();
+            match rods.get_mut(rod_id) {
+                Some(rod) if !rod.stuck => {
+                    rod.position = rod_travel_limits(&limits, rod).clamp(new_position);
+                    Some(true)
+                }
+                Some(_) => Some(false),
+                None => None,
+            }
+        };
+        if moved == Some(true) {
+            self.record_rod_move();
         }
+        moved.is_some()
+    }
+    
+    /// Note an operator rod move for `get_event_times`
+    fn record_rod_move(&self) {
+        let time = self.state.lock().unwrap().time;
+        self.event_times.lock().unwrap().last_rod_move = Some(time);
     }
     
     /// Move all rods of a specific type
+    /// Rods of one type may span several channel types, so each rod is
+    /// clamped to its own travel limits.
     pub fn move_rod_group(&self, rod_type: RodType, new_position: f64) {
-        let clamped_position = new_position.clamp(0.0, 1.0);
-        
-        // Collect rod IDs being moved
-        let moved_rod_ids: Vec<usize> = {
+        // Collect IDs and final positions of the rods being moved
+        let moved_rods: Vec<(usize, f64)> = {
+            let limits = self.rod_travel_limits.lock().unwrap();
             let mut rods = self.control_rods.lock().unwrap();
-            let mut ids = Vec::new();
+            let mut moved = Vec::new();
             for rod in rods.iter_mut() {
-                if rod.rod_type == rod_type {
-                    rod.position = clamped_position;
-                    ids.push(rod.id);
+                if rod.rod_type == rod_type && !rod.stuck {
+                    rod.position = rod_travel_limits(&limits, rod).clamp(new_position);
+                    moved.push((rod.id, rod.position));
                 }
             }
-            ids
+            moved
         };
         
         // Update fuel channels that are linked to these control rods
-        if !moved_rod_ids.is_empty() {
+        if !moved_rods.is_empty() {
+            self.record_rod_move();
             let mut channels = self.fuel_channels.lock().unwrap();
             for channel in channels.iter_mut() {
                 if channel.has_control_rod {
                     // Check if this channel is linked to one of the moved rods
                     if let Some(rod_id) = channel.control_rod_id {
-                        if moved_rod_ids.contains(&rod_id) {
-                            channel.local_rod_position = clamped_position;
+                        if let Some(&(_, position)) = moved_rods.iter().find(|(id, _)| *id == rod_id) {
+                            channel.local_rod_position = position;
                         }
                     }
                 }
@@ -1011,14 +4255,15 @@ impl ReactorSimulator {
     /// Move all rods of a specific channel type (RR, AR, LAR, USP, AZ)
     /// This allows separate control of AR and LAR rods which both have RodType::Automatic
     pub fn move_rod_group_by_channel_type(&self, channel_type: &str, new_position: f64) {
-        let clamped_position = new_position.clamp(0.0, 1.0);
+        let limits = self.rod_travel_limits.lock().unwrap().get(channel_type).copied().unwrap_or_default();
+        let clamped_position = limits.clamp(new_position);
         
         // Collect rod IDs being moved
         let moved_rod_ids: Vec<usize> = {
             let mut rods = self.control_rods.lock().unwrap();
             let mut ids = Vec::new();
             for rod in rods.iter_mut() {
-                if rod.channel_type == channel_type {
+                if rod.channel_type == channel_type && !rod.stuck {
                     rod.position = clamped_position;
                     ids.push(rod.id);
                 }
@@ -1029,6 +4274,7 @@ impl ReactorSimulator {
         // Update fuel channels that are linked to these control rods
         let mut updated_channels = 0;
         if !moved_rod_ids.is_empty() {
+            self.record_rod_move();
             let mut channels = self.fuel_channels.lock().unwrap();
             for channel in channels.iter_mut() {
                 if channel.has_control_rod {
@@ -1051,14 +4297,20 @@ impl ReactorSimulator {
     /// This allows individual rod control from the CYS panel
     /// Returns true if a rod was found and moved, false otherwise
     pub fn move_rod_by_grid_position(&self, grid_x: i32, grid_y: i32, new_position: f64) -> bool {
-        let clamped_position = new_position.clamp(0.0, 1.0);
+        let mut clamped_position = new_position;
         
         // First, find and update the control rod, get its ID
         let rod_id: Option<usize> = {
+            let limits = self.rod_travel_limits.lock().unwrap();
             let mut rods = self.control_rods.lock().unwrap();
             let mut found_id = None;
             for rod in rods.iter_mut() {
                 if rod.grid_x == grid_x && rod.grid_y == grid_y {
+                    if rod.stuck {
+                        println!("[reactor] Rod {} at ({}, {}) is stuck and cannot move", rod.id, grid_x, grid_y);
+                        return false;
+                    }
+                    clamped_position = rod_travel_limits(&limits, rod).clamp(new_position);
                     rod.position = clamped_position;
                     found_id = Some(rod.id);
                     break;
@@ -1068,6 +4320,7 @@ impl ReactorSimulator {
         };
         
         if let Some(moved_rod_id) = rod_id {
+            self.record_rod_move();
             // Update all fuel channels that are linked to this rod
             let mut updated_channels = 0;
             {
@@ -1092,6 +4345,20 @@ impl ReactorSimulator {
         false
     }
     
+    /// Freeze (or release) temperatures and void at their current values
+    /// With thermal feedback frozen, `step()` and `step_spatial()` show pure
+    /// neutron kinetics. Cleared by `reset()` and `restart_hot()`.
+    pub fn set_freeze_thermal(&self, frozen: bool) {
+        self.state.lock().unwrap().thermal_frozen = frozen;
+    }
+    
+    /// Freeze (or release) iodine and xenon at their current values
+    /// Keeps the slow poison dynamics out of long transients; the constant
+    /// xenon reactivity still applies. Cleared by `reset()` and `restart_hot()`.
+    pub fn set_freeze_xenon(&self, frozen: bool) {
+        self.state.lock().unwrap().xenon_frozen = frozen;
+    }
+    
     /// Enable or disable automatic regulator (AR/LAR)
     pub fn set_auto_regulator_enabled(&self, enabled: bool) {
         let mut state = self.state.lock().unwrap();
@@ -1105,12 +4372,19 @@ impl ReactorSimulator {
     }
     
     /// Set target power for automatic regulator
-    pub fn set_target_power(&self, target_percent: f64) {
+    /// The target is clamped to the safe operating range
+    /// (`TARGET_POWER_MIN_PERCENT`..`TARGET_POWER_MAX_PERCENT`); the result
+    /// reports the value applied and whether clamping occurred.
+    pub fn set_target_power(&self, target_percent: f64) -> ClampedSetpoint {
+        let setpoint = ClampedSetpoint::new(
+            target_percent,
+            constants::TARGET_POWER_MIN_PERCENT,
+            constants::TARGET_POWER_MAX_PERCENT,
+        );
         let mut state = self.state.lock().unwrap();
         let old_target = state.auto_regulator.target_power;
         
-        // Clamp target power to safe operating range (5% - 110%)
-        let new_target = target_percent.clamp(5.0, 110.0);
+        let new_target = setpoint.applied;
         state.auto_regulator.target_power = new_target;
         
         // When target changes significantly, pre-seed the integral error
@@ -1125,6 +4399,7 @@ impl ReactorSimulator {
             // Small change - just reset integral to avoid overshoot
             state.auto_regulator.integral_error = 0.0;
         }
+        setpoint
     }
     
     /// Get automatic regulator settings
@@ -1137,16 +4412,138 @@ impl ReactorSimulator {
         self.state.lock().unwrap().clone()
     }
     
+    /// Append the current scalar state as one fixed-layout binary record
+    ///
+    /// Intended to be called once per step for compact high-rate telemetry.
+    /// See `state_stream` for the record layout and `read_state_stream` to parse it back.
+    pub fn stream_state_binary(&self, writer: &mut dyn Write) -> io::Result<()> {
+        let state = self.state.lock().unwrap();
+        state_stream::write_record(writer, &state)
+    }
+    
     /// Get control rod positions
     pub fn get_control_rods(&self) -> Vec<ControlRod> {
         self.control_rods.lock().unwrap().clone()
     }
     
+    /// Get the reading of every in-core detector
+    ///
+    /// Each reading is the inverse-distance weighted flux and power of the
+    /// fuel channels within `DETECTOR_INTERP_RADIUS_CM` (nearest channel if none),
+    /// as reported by the instrument: see `set_detector_fault`.
+    pub fn get_detector_signals(&self) -> Vec<DetectorSignal> {
+        let time = self.state.lock().unwrap().time;
+        let faults = self.detector_faults.lock().unwrap().clone();
+        let detectors = self.detectors.lock().unwrap();
+        let channels = self.fuel_channels.lock().unwrap();
+        
+        detectors.iter().map(|det| {
+            let dist_sq = |c: &FuelChannel| (c.x - det.x).powi(2) + (c.y - det.y).powi(2);
+            let softening = (0.5 * GRID_SPACING_CM).powi(2);
+            
+            let (mut weight_sum, mut flux, mut power) = (0.0, 0.0, 0.0);
+            for ch in channels.iter().filter(|c| dist_sq(c) <= DETECTOR_INTERP_RADIUS_CM.powi(2)) {
+                let weight = 1.0 / (dist_sq(ch) + softening);
+                weight_sum += weight;
+                flux += weight * ch.neutron_flux;
+                power += weight * ch.local_power;
+            }
+            if weight_sum > 0.0 {
+                flux /= weight_sum;
+                power /= weight_sum;
+            } else if let Some(nearest) = channels.iter().min_by(|a, b| dist_sq(a).total_cmp(&dist_sq(b))) {
+                flux = nearest.neutron_flux;
+                power = nearest.local_power;
+            }
+            
+            // A faulty detector misreports flux; the fission rate follows it
+            if let Some(fault) = faults.get(&det.id) {
+                let reported = fault.corrupt(flux, time);
+                power = if flux > 0.0 { power * reported / flux } else { 0.0 };
+                flux = reported;
+            }
+            
+            DetectorSignal {
+                id: det.id,
+                grid_x: det.grid_x,
+                grid_y: det.grid_y,
+                local_flux: flux,
+                fission_rate: power * FISSIONS_PER_MW_S,
+                current_ua: flux * DETECTOR_CURRENT_AT_NOMINAL_UA,
+            }
+        }).collect()
+    }
+    
+    /// Make a detector misreport (stuck, drifting or dead), or repair it
+    /// with `DetectorFault::None`. Drift accumulates from the time it is set.
+    /// Faults are cleared on reset.
+    pub fn set_detector_fault(&self, detector_id: usize, fault: DetectorFault) -> Result<(), String> {
+        if let DetectorFault::Stuck(value) | DetectorFault::Drift(value) = fault {
+            if !value.is_finite() {
+                return Err(format!("Detector fault value {} must be a finite number", value));
+            }
+        }
+        if let DetectorFault::Stuck(value) = fault {
+            if value < 0.0 {
+                return Err(format!("Stuck detector reading {} must not be negative", value));
+            }
+        }
+        if !self.detectors.lock().unwrap().iter().any(|d| d.id == detector_id) {
+            return Err(format!("Unknown detector {}", detector_id));
+        }
+        let since = self.state.lock().unwrap().time;
+        let mut faults = self.detector_faults.lock().unwrap();
+        if fault == DetectorFault::None {
+            faults.remove(&detector_id);
+        } else {
+            faults.insert(detector_id, ActiveDetectorFault { fault, since });
+        }
+        Ok(())
+    }
+    
     /// Get fuel channel data
     pub fn get_fuel_channels(&self) -> Vec<FuelChannel> {
         self.fuel_channels.lock().unwrap().clone()
     }
     
+    /// Get state, rods and channels in one consistent read for a UI frame
+    /// All three locks are held together (state, rods, channels), so no
+    /// rod move or step can land between them.
+    pub fn get_frame(&self) -> FrameData {
+        let state = self.state.lock().unwrap();
+        let rods = self.control_rods.lock().unwrap();
+        let channels = self.fuel_channels.lock().unwrap();
+        FrameData {
+            state: state.clone(),
+            control_rods: rods.iter()
+                .map(|r| FrameRod {
+                    id: r.id,
+                    grid_x: r.grid_x,
+                    grid_y: r.grid_y,
+                    position: r.position,
+                    stuck: r.stuck,
+                })
+                .collect(),
+            fuel_channels: channels.iter()
+                .map(|c| FrameChannel {
+                    id: c.id,
+                    grid_x: c.grid_x,
+                    grid_y: c.grid_y,
+                    local_power: c.local_power,
+                    fuel_temp: c.fuel_temp,
+                    coolant_void: c.coolant_void,
+                    ruptured: c.ruptured,
+                })
+                .collect(),
+        }
+    }
+    
+    /// Whether `step_spatial()` can run the 2D solver (it needs the Fortran
+    /// library whatever the backend of `step()`)
+    pub fn spatial_solver_available(&self) -> bool {
+        fortran_ffi::library_available()
+    }
+    
     /// Perform one spatial simulation step using 2D diffusion physics
     ///
     /// This method uses the Fortran spatial physics module to calculate:
@@ -1157,13 +4554,22 @@ impl ReactorSimulator {
     ///
     /// Each of the 1661 fuel channels is calculated independently with
     /// coupling to its neighbors through the diffusion equation.
+    ///
+    /// Without the Fortran library there is no spatial solver, and the step
+    /// falls back to the point model of `step()`.
     pub fn step_spatial(&self) {
+        if !self.spatial_solver_available() {
+            self.step();
+            return;
+        }
+        let mut timer = PhaseTimer::start();
+        self.delta_tracker.lock().unwrap().advance();
+        
         // First, run automatic regulator if enabled (before physics step)
-        let (ar_enabled, ar_target, ar_settings, current_power, dt, scram_active) = {
+        let (ar_enabled, ar_settings, current_power, dt, scram_active) = {
             let state = self.state.lock().unwrap();
             (
                 state.auto_regulator.enabled,
-                state.auto_regulator.target_power,
                 state.auto_regulator.clone(),
                 state.power_percent,
                 state.dt,
@@ -1171,16 +4577,33 @@ impl ReactorSimulator {
             )
         };
         
-        // Run automatic regulator (AR/LAR) - PID control for power
+        // Armed drill faults fire before the physics of the step
+        self.inject_due_fault();
+        
+        // Run automatic regulator - AR on core power, each LAR on its zone's power
         if ar_enabled && !scram_active {
+            let has_lar_zones = self.regulate_lar_zones(&ar_settings, dt);
             let rod_adjustment = self.calculate_ar_adjustment(&ar_settings, current_power, dt);
             if rod_adjustment.abs() > 1e-6 {
-                self.adjust_automatic_rods(rod_adjustment);
+                self.adjust_automatic_rods(rod_adjustment, !has_lar_zones);
             }
         }
         
+        // Rods travel into the core during a SCRAM
+        if scram_active {
+            self.advance_scram_rods(dt);
+        }
+        self.deplete_absorbers(current_power, dt);
+        
         // Calculate total control rod worth
-        let total_rod_worth = self.calculate_total_rod_worth();
+        let axial_flux = self.state.lock().unwrap().axial_flux.clone();
+        let total_rod_worth = self.calculate_total_rod_worth(&axial_flux);
+        
+        // Boiling threshold follows the current coolant pressure
+        self.backend.set_coolant_pressure(self.average_channel_pressure());
+        let saturation_temp = self.get_saturation_temp();
+        let plenum_mixing_time = self.get_plenum_mixing_time();
+        timer.lap(|t| &mut t.control_ms);
         
         // Build rod position lookup for distance-based calculations
         // EXCLUDE AZ (emergency) rods from local power calculations
@@ -1194,9 +4617,20 @@ impl ReactorSimulator {
                 .collect()
         };
         
+        // Channels uncover as the coolant inventory runs low; all of them
+        // draw from the common inlet plenum
+        let (uncovered_fraction, inlet_temp) = {
+            let state = self.state.lock().unwrap();
+            (uncovered_fraction(state.coolant_inventory_fraction), state.inlet_plenum_temp)
+        };
+        self.set_inlet_temp(inlet_temp);
+        
         // Prepare spatial input data from fuel channels
         let spatial_inputs: Vec<fortran_ffi::SpatialChannelInput> = {
-            let channels = self.fuel_channels.lock().unwrap();
+            let mut channels = self.fuel_channels.lock().unwrap();
+            for ch in channels.iter_mut() {
+                ch.uncovered = channel_uncovered(ch.id, uncovered_fraction);
+            }
             
             channels.iter().map(|ch| {
                 // Convert neighbor indices to i32, padding with -1
@@ -1253,7 +4687,21 @@ impl ReactorSimulator {
                 // This creates HOT SPOTS where rods are withdrawn:
                 // - Withdrawn rods (position=1): local_rod_worth = 0 -> high power
                 // - Inserted rods (position=0): local_rod_worth = 0.03 -> normal power
-                let local_rod_worth = 0.03 * (1.0 - avg_rod_position);
+                let mut local_rod_worth = 0.03 * (1.0 - avg_rod_position);
+                let mut coolant_void = ch.coolant_void;
+                
+                // Ruptured tube: channel fully voided, and the lost water absorber
+                // adds RUPTURE_LOCAL_REACTIVITY on top of the nearby rods' effect.
+                // Fortran has no separate local term, so it rides on the local
+                // rod worth (which also raises the channel's power peaking).
+                if ch.ruptured {
+                    local_rod_worth -= channel_defaults::RUPTURE_LOCAL_REACTIVITY
+                        / constants::LOCAL_ROD_WORTH_REACTIVITY_SCALE;
+                    coolant_void = channel_defaults::RUPTURE_VOID_PERCENT;
+                }
+                if ch.uncovered {
+                    coolant_void = channel_defaults::UNCOVERED_VOID_PERCENT;
+                }
                 
                 fortran_ffi::SpatialChannelInput {
                     neutron_flux: ch.neutron_flux,
@@ -1261,10 +4709,15 @@ impl ReactorSimulator {
                     fuel_temp: ch.fuel_temp,
                     coolant_temp: ch.coolant_temp,
                     graphite_temp: ch.graphite_temp,
-                    coolant_void: ch.coolant_void,
+                    coolant_void,
                     iodine: ch.iodine_135,
                     xenon: ch.xenon_135,
+                    promethium: ch.promethium_149,
+                    samarium: ch.samarium_149,
                     local_rod_worth,
+                    enrichment: ch.enrichment,
+                    burnup: ch.burnup,
+                    inlet_temp: ch.inlet_temp,
                     x: ch.x,
                     y: ch.y,
                     neighbors,
@@ -1273,10 +4726,26 @@ impl ReactorSimulator {
         };
         
         // Get current state parameters
-        let (dt, scram_active) = {
+        let (dt, scram_active, thermal_frozen, xenon_frozen) = {
             let state = self.state.lock().unwrap();
-            (state.dt, state.scram_active)
+            // Debug builds check the reactivity breakdown at the step's starting
+            // conditions. The channel reactivities add local terms it does not
+            // see (samarium, fuel state, nearby rods), so only the target is
+            // compared; get_reactivity_balance_residual() reports the rest.
+            if cfg!(debug_assertions) {
+                let breakdown = self.reactivity_breakdown_at(
+                    state.avg_fuel_temp,
+                    state.avg_graphite_temp,
+                    state.avg_coolant_void,
+                    state.xenon_135,
+                    total_rod_worth,
+                );
+                debug_assert_reactivity_balance(&breakdown, None);
+            }
+            (state.dt, state.scram_active, state.thermal_frozen, state.xenon_frozen)
         };
+        let (_, neutron_lifetime, _) = fortran_ffi::get_constants();
+        timer.lap(|t| &mut t.input_prep_ms);
         
         // Call Fortran spatial simulation
         let spatial_outputs = fortran_ffi::spatial_simulation_step(
@@ -1285,36 +4754,90 @@ impl ReactorSimulator {
             scram_active,
             &spatial_inputs,
         );
+        timer.lap(|t| &mut t.ffi_ms);
         
         // Update fuel channels from spatial outputs
+        // Fortran powers are on its own 3200 MW rating; channels carry this core's
+        let power_scale = self.nominal_power_mw / constants::NOMINAL_POWER_MW;
+        let power_ceiling = *self.channel_power_ceiling_mw.lock().unwrap();
+        let channel_volume = self.get_channel_volume();
+        let normalize = *self.normalize_channel_power.lock().unwrap();
         {
             let mut channels = self.fuel_channels.lock().unwrap();
             for (ch, output) in channels.iter_mut().zip(spatial_outputs.iter()) {
+                // A runaway (or NaN) channel power is clamped and flagged
+                // instead of spreading through the averages
+                let output_power = output.local_power * power_scale;
+                ch.power_clamped = output_power.is_nan() || output_power > power_ceiling;
+                let local_power = if ch.power_clamped { power_ceiling } else { output_power };
+                
                 ch.neutron_flux = output.neutron_flux;
                 ch.precursors = output.precursors;
-                ch.fuel_temp = output.fuel_temp;
-                ch.coolant_temp = output.coolant_temp;
-                ch.graphite_temp = output.graphite_temp;
-                ch.coolant_void = output.coolant_void;
-                ch.iodine_135 = output.iodine;
-                ch.xenon_135 = output.xenon;
-                ch.local_power = output.local_power;
+                if !thermal_frozen {
+                    ch.fuel_temp = output.fuel_temp;
+                    ch.coolant_temp = output.coolant_temp;
+                    ch.graphite_temp = output.graphite_temp;
+                    ch.coolant_void = output.coolant_void;
+                }
+                if !xenon_frozen {
+                    ch.iodine_135 = non_negative_poison("I-135", Some(ch.id), output.iodine, dt);
+                    ch.xenon_135 = non_negative_poison("Xe-135", Some(ch.id), output.xenon, dt);
+                }
+                ch.promethium_149 = output.promethium;
+                ch.samarium_149 = output.samarium;
+                ch.local_power = local_power;
                 ch.local_reactivity = output.local_reactivity;
+            }
+            
+            // Normalize before anything is derived from the channel powers
+            if normalize {
+                let solver_power_mw: f64 = spatial_outputs.iter().map(|o| o.local_power * power_scale).sum();
+                normalize_channel_powers(&mut channels, solver_power_mw);
+            }
+            
+            for ch in channels.iter_mut() {
+                let local_power = ch.local_power;
                 
                 // Calculate power density from local power
-                // Channel volume: π * (0.68cm)² * 700cm ≈ 1017 cm³ = 1.017e-3 m³
-                let channel_volume_m3 = 1.017e-3;
-                ch.power_density = output.local_power / channel_volume_m3;
+                ch.power_density = local_power / channel_volume;
+                
+                // Uncovered channels keep their pump flow setting but nothing reaches them
+                if ch.uncovered {
+                    ch.coolant_void = channel_defaults::UNCOVERED_VOID_PERCENT;
+                }
+                let flow_rate = if ch.uncovered { 0.0 } else { ch.flow_rate };
                 
                 // Update outlet temperature based on power and flow
-                if ch.flow_rate > 0.0 {
+                if flow_rate > 0.0 {
                     let cp_water = 4.5e3; // J/(kg·K)
-                    let delta_t = (output.local_power * 1e6) / (ch.flow_rate * cp_water);
+                    let delta_t = (local_power * 1e6) / (flow_rate * cp_water);
                     ch.outlet_temp = ch.inlet_temp + delta_t;
                 }
+                
+                ch.dnbr = fortran_ffi::calc_dnbr(local_power, flow_rate, ch.coolant_void);
+            }
+            
+            // Steam from a ruptured tube carries part of the channel's power
+            // into the graphite stack, shared equally by the channel's column
+            // and its neighbors': ΔT = f·P·dt / (n·C) per column
+            let ruptures: Vec<(usize, f64)> = channels.iter()
+                .filter(|c| c.ruptured)
+                .map(|c| (c.id, c.local_power))
+                .collect();
+            for (id, local_power) in ruptures {
+                channels[id].coolant_void = channel_defaults::RUPTURE_VOID_PERCENT;
+                let mut heated = channels[id].neighbors.clone();
+                heated.push(id);
+                let steam_heat_j = channel_defaults::RUPTURE_STEAM_HEAT_FRACTION * local_power.max(0.0) * 1e6 * dt;
+                let delta_t = steam_heat_j / (heated.len() as f64 * channel_defaults::GRAPHITE_COLUMN_HEAT_CAPACITY_J_K);
+                for idx in heated {
+                    channels[idx].graphite_temp += delta_t;
+                }
             }
         }
         
+        timer.lap(|t| &mut t.output_ms);
+        
         // Calculate global averages from per-channel data
         let (fuel_temps, coolant_temps, graphite_temps, voids, powers, xenons, iodines):
             (Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>) = {
@@ -1345,6 +4868,8 @@ impl ReactorSimulator {
             0.0
         };
         
+        let power_mw = averages.total_power;
+        
         // Update global state from averages
         {
             let mut state = self.state.lock().unwrap();
@@ -1353,28 +4878,44 @@ impl ReactorSimulator {
             state.avg_coolant_temp = averages.avg_coolant_temp;
             state.avg_graphite_temp = averages.avg_graphite_temp;
             state.avg_coolant_void = averages.avg_void;
-            state.power_mw = averages.total_power;
-            state.power_percent = averages.total_power / constants::NOMINAL_POWER_MW * 100.0;
+            state.power_percent = power_mw / self.nominal_power_mw * 100.0;
+            state.power_mw = power_mw;
             state.xenon_135 = averages.avg_xenon;
             state.iodine_135 = avg_iodine;
             
-            // Calculate total neutron population and precursors from channels
+            // Neutron population follows core power (relative to nominal), as in point kinetics
             let channels = self.fuel_channels.lock().unwrap();
-            let total_flux: f64 = channels.iter().map(|c| c.neutron_flux).sum();
-            let total_precursors: f64 = channels.iter().map(|c| c.precursors).sum();
+            // Core xenon worth is the mean of the channel terms the solver used
+            state.xenon_reactivity = channels.iter()
+                .map(|c| fortran_ffi::calc_local_xenon_reactivity(c.xenon_135))
+                .sum::<f64>() / channels.len() as f64;
             let avg_reactivity: f64 = channels.iter().map(|c| c.local_reactivity).sum::<f64>()
                 / channels.len() as f64;
             
-            state.neutron_population = total_flux / channels.len() as f64;
-            state.precursors = total_precursors / channels.len() as f64;
+            let previous_population = state.neutron_population;
+            let relative_power = state.power_percent / 100.0;
+            state.set_neutron_population(relative_power);
+            state.update_startup_indicators(previous_population, dt);
+            
+            // The 2D solver carries single-group precursors per channel. The
+            // 6-group set is only a core-level estimate driven by the power,
+            // kept for the delayed source breakdown and for a later `step()`;
+            // the scalar is its sum, so the two agree here by construction.
+            state.precursors_6 = fortran_ffi::advance_precursors_6group(
+                state.neutron_population,
+                &state.precursors_6,
+                dt,
+            );
+            state.precursors = fortran_ffi::sum_precursors_6group(&state.precursors_6);
+            state.reactivity_rate = if dt > 0.0 { (avg_reactivity - state.reactivity) / dt } else { 0.0 };
             state.reactivity = avg_reactivity;
             state.smoothed_reactivity = avg_reactivity;
-            state.k_eff = 1.0 + avg_reactivity;
-            state.reactivity_dollars = avg_reactivity / constants::BETA_EFF;
+            state.k_eff = k_eff_from_reactivity(avg_reactivity);
+            state.update_reactivity_units();
             
             // Calculate reactor period
             if avg_reactivity.abs() > 1e-10 {
-                state.period = constants::NEUTRON_LIFETIME / avg_reactivity;
+                state.period = neutron_lifetime / avg_reactivity;
             } else {
                 state.period = f64::INFINITY;
             }
@@ -1384,45 +4925,91 @@ impl ReactorSimulator {
                 state.scram_time += dt;
             }
             
-            // Update automatic regulator state (PID integral/derivative terms)
-            if state.auto_regulator.enabled && !state.scram_active {
-                let error = state.auto_regulator.target_power - state.power_percent;
-                let max_integral = 100.0;
-                
-                if error.abs() > state.auto_regulator.deadband {
-                    state.auto_regulator.integral_error =
-                        (state.auto_regulator.integral_error + error * dt).clamp(-max_integral, max_integral);
-                } else {
-                    state.auto_regulator.integral_error *= 0.99;
-                }
-                
-                state.auto_regulator.last_error = error;
-            }
+            state.update_auto_regulator(dt);
+            update_lar_zones(
+                &mut self.lar_zones.lock().unwrap(),
+                &channels,
+                state.power_percent,
+                &state.auto_regulator,
+                state.auto_regulator.enabled && !state.scram_active,
+                dt,
+            );
             
             // Update axial flux distribution
-            state.axial_flux = fortran_ffi::update_axial_flux(50, state.neutron_population);
+            state.axial_flux = self.backend.axial_flux(
+                constants::AXIAL_POINTS,
+                state.neutron_population,
+                self.axial_tilt(),
+            );
             
             // Generate alerts
-            state.alerts.clear();
+            state.clear_alerts();
+            let ruptured_count = channels.iter().filter(|c| c.ruptured).count();
+            if ruptured_count > 0 {
+                state.raise_alert(
+                    AlertCode::ChannelRupture,
+                    format!("CRITICAL: Pressure tube rupture in {} channel(s)!", ruptured_count),
+                );
+            }
+            let clamped_count = channels.iter().filter(|c| c.power_clamped).count();
+            if clamped_count > 0 {
+                state.raise_alert(
+                    AlertCode::ChannelPowerClamped,
+                    format!("WARNING: Power clamped at {:.0} MW in {} channel(s) - spatial solver unstable?", power_ceiling, clamped_count),
+                );
+            }
+            // Local hot spot (e.g. next to a withdrawn rod) that core power hides
+            let density_limit = constants::CHANNEL_POWER_LIMIT_MW / channel_volume;
+            let over_limit = channels.iter()
+                .filter(|c| c.power_density > density_limit);
+            let hot_count = over_limit.clone().count();
+            if let Some(hottest) = over_limit.max_by(|a, b| a.power_density.total_cmp(&b.power_density)) {
+                state.raise_alert(
+                    AlertCode::LocalPowerDensityHigh,
+                    format!(
+                        "WARNING: Local power density {:.0} MW/m³ in channel {} at ({}, {}) - {} channel(s) over limit",
+                        hottest.power_density, hottest.id, hottest.grid_x, hottest.grid_y, hot_count
+                    ),
+                );
+            }
+            if let Some(min) = min_dnbr(&channels) {
+                if min.dnbr < constants::DNBR_LIMIT {
+                    state.raise_alert(
+                        AlertCode::DnbrLow,
+                        format!("WARNING: Minimum DNBR {:.2} in channel {} - approaching boiling crisis!", min.dnbr, min.channel_id),
+                    );
+                }
+            }
             if state.power_percent > 110.0 {
-                state.alerts.push("WARNING: Power exceeds 110% nominal!".to_string());
+                state.raise_alert(AlertCode::PowerHigh, "WARNING: Power exceeds 110% nominal!".to_string());
             }
             if state.reactivity_dollars > 0.5 {
-                state.alerts.push("WARNING: Reactivity exceeds 0.5$!".to_string());
+                state.raise_alert(AlertCode::ReactivityHigh, "WARNING: Reactivity exceeds 0.5$!".to_string());
             }
-            if state.reactivity_dollars >= 1.0 {
-                state.alerts.push("CRITICAL: Prompt critical condition!".to_string());
+            if Criticality::from_dollars(state.reactivity_dollars) == Criticality::PromptCritical {
+                state.raise_alert(AlertCode::PromptCritical, "CRITICAL: Prompt critical condition!".to_string());
             }
             if state.avg_fuel_temp > 2800.0 {
-                state.alerts.push("WARNING: Fuel temperature exceeds limit!".to_string());
+                state.raise_alert(AlertCode::FuelTempHigh, "WARNING: Fuel temperature exceeds limit!".to_string());
             }
             if state.avg_coolant_void > 50.0 {
-                state.alerts.push("WARNING: High void fraction - positive reactivity feedback!".to_string());
+                state.raise_alert(AlertCode::VoidHigh, "WARNING: High void fraction - positive reactivity feedback!".to_string());
             }
             if state.period.is_finite() && state.period > 0.0 && state.period < 30.0 {
                 let period = state.period;
-                state.alerts.push(format!("WARNING: Short reactor period: {:.1}s", period));
+                state.raise_alert(AlertCode::ShortPeriod, format!("WARNING: Short reactor period: {:.1}s", period));
+            }
+            if state.low_power_mode && state.startup_rate_dpm > constants::STARTUP_RATE_LIMIT_DPM {
+                let rate = state.startup_rate_dpm;
+                state.raise_alert(AlertCode::StartupRateHigh, format!("WARNING: High startup rate: {:.2} DPM", rate));
+            }
+            state.update_coolant_inventory(self.nominal_power_mw, dt);
+            if state.coolant_inventory_fraction < constants::COOLANT_LOW_LEVEL_FRACTION {
+                let level = state.coolant_inventory_fraction * 100.0;
+                state.raise_alert(AlertCode::CoolantLevelLow, format!("WARNING: Low coolant inventory: {:.0}%", level));
             }
+            state.update_inlet_plenum(saturation_temp, plenum_mixing_time, dt);
+            self.check_stability(&mut state);
             
             // Check for explosion using Fortran physics-based detection
             // This properly tracks peak power, cumulative energy, and fuel damage
@@ -1434,42 +5021,1075 @@ impl ReactorSimulator {
                     state.reactivity_dollars,
                     state.power_percent,
                 );
+                state.explosion_severity = explosion_severity;
                 
                 if explosion_severity >= 1.0 {
                     state.explosion_occurred = true;
                     state.explosion_time = state.time;
-                    state.alerts.push("*** STEAM EXPLOSION - CORE DESTRUCTION ***".to_string());
+                    state.raise_alert(AlertCode::SteamExplosion, "*** STEAM EXPLOSION - CORE DESTRUCTION ***".to_string());
+                }
+            }
+            
+            state.sort_alerts();
+            self.alert_tracker.lock().unwrap().update(state.time, &state.alert_details);
+            
+            // Update time
+            state.advance_time(dt);
+            
+            self.channel_history.lock().unwrap().record(state.time, &channels);
+            self.reactivity_trace.lock().unwrap().record(state.time, state.reactivity);
+            self.keff_history.lock().unwrap().record(state.time, state.k_eff, self.average_rod_position());
+            // The spatial solver takes the step in one go
+            *self.last_time_step.lock().unwrap() = Some(EffectiveTimeStep { dt, substeps: 1, effective_dt: dt });
+        }
+        
+        self.check_protection_trips();
+        self.record_checkpoint();
+        timer.lap(|t| &mut t.averaging_ms);
+        if let Some(timing) = timer.finish() {
+            *self.last_step_timing.lock().unwrap() = Some(timing);
+        }
+    }
+    
+    /// State and channel fields that changed after `since_version` (0 = all),
+    /// with the version to pass next time
+    /// The version advances with every step, so a client polling once per
+    /// frame only receives the values that actually moved.
+    pub fn get_state_delta(&self, since_version: u64) -> VersionedDelta {
+        let state = self.get_state();
+        let channels = self.fuel_channels.lock().unwrap().clone();
+        self.delta_tracker.lock().unwrap().delta_since(&state, &channels, since_version)
+    }
+    
+    /// Perform one spatial step and return what changed
+    ///
+    /// Captures the state before and after `step_spatial()` and returns
+    /// the signed deltas of the key scalars (power, reactivity, temperatures,
+    /// xenon, period).
+    pub fn step_and_diff(&self) -> StateDelta {
+        let before = self.get_state();
+        self.step_spatial();
+        let after = self.get_state();
+        StateDelta::between(&before, &after)
+    }
+
+    /// Get fuel channels with their independent parameters
+    /// Each channel has its own physics state from 2D spatial simulation
+    /// This is the main method for getting channel data for visualization
+    pub fn get_fuel_channels_synchronized(&self) -> Vec<FuelChannel> {
+        // No synchronization - channels have independent values from spatial physics
+        self.fuel_channels.lock().unwrap().clone()
+    }
+    
+    /// Pre-seed every channel with equilibrium Pm-149/Sm-149 for the given power fraction
+    /// (1.0 = nominal). Equilibrium samarium does not depend on power level,
+    /// so any positive fraction gives the same Sm-149 and only Pm-149 scales.
+    pub fn set_samarium_equilibrium(&self, power_fraction: f64) {
+        let (promethium_eq, samarium_eq) = fortran_ffi::samarium_equilibrium(power_fraction.max(0.0));
+        let mut channels = self.fuel_channels.lock().unwrap();
+        for channel in channels.iter_mut() {
+            channel.promethium_149 = promethium_eq;
+            channel.samarium_149 = samarium_eq;
+        }
+    }
+    
+    /// Jump ahead `hours` in the xenon/iodine transient
+    ///
+    /// Only I-135 and Xe-135 are integrated, through the backend, with the
+    /// power and every rod held where they are: the core average at the
+    /// current core flux, and each channel at that flux scaled by its share
+    /// of the channel power. The clock advances by the same amount. The
+    /// kinetics reactivity is left alone; the next step picks up the new
+    /// poison level, and the spatial xenon term of every channel is resynced
+    /// when the Fortran library is loaded. Fails while xenon is frozen.
+    pub fn fast_forward_xenon(&self, hours: f64) -> Result<(), String> {
+        if !(hours.is_finite() && hours > 0.0 && hours <= constants::XENON_FAST_FORWARD_MAX_HOURS) {
+            return Err(format!(
+                "Fast-forward of {} h out of range (0, {}]",
+                hours, constants::XENON_FAST_FORWARD_MAX_HOURS,
+            ));
+        }
+        let duration = hours * 3600.0;
+        let steps = (duration / constants::XENON_FAST_FORWARD_DT_S).ceil() as usize;
+        let dt = duration / steps as f64;
+        
+        let mut state = self.state.lock().unwrap();
+        if state.xenon_frozen {
+            return Err("Xenon is frozen".to_string());
+        }
+        let flux = state.xenon_flux();
+        for _ in 0..steps {
+            let (iodine, xenon) = self.backend.xenon_step(state.iodine_135, state.xenon_135, flux, dt);
+            state.iodine_135 = non_negative_poison("I-135", None, iodine, dt);
+            state.xenon_135 = non_negative_poison("Xe-135", None, xenon, dt);
+        }
+        state.xenon_reactivity = self.backend.xenon_reactivity(state.xenon_135);
+        
+        let mut channels = self.fuel_channels.lock().unwrap();
+        let mean_power = channels.iter().map(|c| c.local_power.max(0.0)).sum::<f64>() / channels.len() as f64;
+        let resync_local_reactivity = fortran_ffi::library_available();
+        for channel in channels.iter_mut() {
+            let share = if mean_power > 0.0 { channel.local_power.max(0.0) / mean_power } else { 1.0 };
+            let channel_flux = flux * share;
+            let xenon_before = channel.xenon_135;
+            for _ in 0..steps {
+                let (iodine, xenon) = self.backend.xenon_step(channel.iodine_135, channel.xenon_135, channel_flux, dt);
+                channel.iodine_135 = non_negative_poison("I-135", Some(channel.id), iodine, dt);
+                channel.xenon_135 = non_negative_poison("Xe-135", Some(channel.id), xenon, dt);
+            }
+            if resync_local_reactivity {
+                channel.local_reactivity += fortran_ffi::calc_local_xenon_reactivity(channel.xenon_135)
+                    - fortran_ffi::calc_local_xenon_reactivity(xenon_before);
+            }
+        }
+        
+        state.advance_time(duration);
+        Ok(())
+    }
+    
+    /// Estimate when the xenon reactivity bottoms out and how deep it gets
+    ///
+    /// Integrates the core-average I-135/Xe-135 chain on a copy, at the
+    /// current power, for up to `XENON_PROJECTION_HOURS`. The simulation
+    /// itself does not advance.
+    pub fn get_xenon_projection(&self) -> XenonProjection {
+        let (mut iodine, mut xenon, flux) = {
+            let state = self.state.lock().unwrap();
+            (state.iodine_135, state.xenon_135, state.xenon_flux())
+        };
+        let dt = constants::XENON_FAST_FORWARD_DT_S;
+        let steps = (constants::XENON_PROJECTION_HOURS * 3600.0 / dt).ceil() as usize;
+        
+        let current_reactivity = self.backend.xenon_reactivity(xenon);
+        let mut peak_xenon = xenon;
+        let mut peak_step = 0;
+        for step in 1..=steps {
+            let (iodine_new, xenon_new) = self.backend.xenon_step(iodine, xenon, flux, dt);
+            iodine = iodine_new.max(0.0);
+            xenon = xenon_new.max(0.0);
+            if xenon > peak_xenon {
+                peak_xenon = xenon;
+                peak_step = step;
+            }
+        }
+        
+        let peak_reactivity = self.backend.xenon_reactivity(peak_xenon);
+        XenonProjection {
+            hours_to_peak: peak_step as f64 * dt / 3600.0,
+            peak_xenon_135: peak_xenon,
+            current_reactivity,
+            peak_reactivity,
+            additional_reactivity: peak_reactivity - current_reactivity,
+            peak_within_horizon: peak_step < steps,
+        }
+    }
+    
+    /// Project how long until each monitored limit is reached if nothing changes
+    ///
+    /// - Power 110%: extrapolates exponential growth on the current period, t = T·ln(110/P)
+    /// - Period 30 s: the one-group period T ≈ (β - ρ)/(λ_eff·ρ) falls to 30 s at
+    ///   ρ* = β/(1 + λ_eff·30); extrapolates reactivity linearly at the current rate
+    pub fn get_time_to_trip(&self) -> TripProjection {
+        let state = self.state.lock().unwrap();
+        
+        let power_time = if state.power_percent >= constants::POWER_LIMIT_PERCENT {
+            Some(0.0)
+        } else if state.period.is_finite() && state.period > 0.0 && state.power_percent > 0.0 {
+            Some(state.period * (constants::POWER_LIMIT_PERCENT / state.power_percent).ln())
+        } else {
+            None
+        };
+        
+        let period_trip_reactivity =
+            constants::BETA_EFF / (1.0 + constants::LAMBDA_EFF * constants::PERIOD_LIMIT_S);
+        let period_time = if state.period.is_finite() && state.period > 0.0
+            && state.period <= constants::PERIOD_LIMIT_S
+        {
+            Some(0.0)
+        } else if state.reactivity_rate > 0.0 && state.reactivity < period_trip_reactivity {
+            Some((period_trip_reactivity - state.reactivity) / state.reactivity_rate)
+        } else {
+            None
+        };
+        
+        // A NaN (e.g. 0/0 from a stalled rate) is no projection
+        let limits = vec![
+            LimitProjection {
+                limit: "power_high".to_string(),
+                current: state.power_percent,
+                setpoint: constants::POWER_LIMIT_PERCENT,
+                seconds_to_trip: power_time.filter(|t| !t.is_nan()),
+            },
+            LimitProjection {
+                limit: "short_period".to_string(),
+                current: state.period,
+                setpoint: constants::PERIOD_LIMIT_S,
+                seconds_to_trip: period_time.filter(|t| !t.is_nan()),
+            },
+        ];
+        
+        let soonest = limits
+            .iter()
+            .filter_map(|l| Some((l.seconds_to_trip?, l)))
+            .min_by(|(a, _), (b, _)| a.total_cmp(b))
+            .map(|(_, l)| l.clone());
+        
+        TripProjection { limits, soonest }
+    }
+    
+    /// Rupture a fuel channel's pressure tube
+    /// The channel loses its flow and is driven to full void, and the lost water
+    /// absorber adds `RUPTURE_LOCAL_REACTIVITY` to its local reactivity. The
+    /// escaping steam carries `RUPTURE_STEAM_HEAT_FRACTION` of the channel's
+    /// local power into the graphite of the channel and its neighbors.
+    /// Both effects need the spatial model.
+    pub fn rupture_channel(&self, channel_id: usize) -> Result<(), String> {
+        let mut channels = self.fuel_channels.lock().unwrap();
+        let num_channels = channels.len();
+        let channel = channels.get_mut(channel_id).ok_or_else(|| {
+            format!("Invalid channel_id {}: valid range is 0..{}", channel_id, num_channels)
+        })?;
+        channel.ruptured = true;
+        channel.flow_rate = 0.0;
+        channel.coolant_void = channel_defaults::RUPTURE_VOID_PERCENT;
+        Ok(())
+    }
+    
+    /// Set the feedwater flow as a fraction of the steaming rate at nominal power
+    /// Below the boil-off rate the coolant inventory drains; channels start to
+    /// uncover (no flow, full void) once it falls below `COOLANT_UNCOVERY_FRACTION`.
+    /// Uncovering needs the spatial model; `step()` only tracks the inventory.
+    pub fn set_feedwater_rate(&self, fraction: f64) -> Result<(), String> {
+        if !(0.0..=constants::FEEDWATER_MAX_FRACTION).contains(&fraction) {
+            return Err(format!(
+                "Feedwater fraction {} out of range [0, {}]",
+                fraction, constants::FEEDWATER_MAX_FRACTION,
+            ));
+        }
+        self.state.lock().unwrap().feedwater_fraction = fraction;
+        Ok(())
+    }
+    
+    /// Set the coolant inventory directly (scenario setup), 1.0 = normal level
+    pub fn set_coolant_inventory(&self, fraction: f64) -> Result<(), String> {
+        if !(0.0..=1.0).contains(&fraction) {
+            return Err(format!("Coolant inventory fraction {} out of range [0, 1]", fraction));
+        }
+        self.state.lock().unwrap().coolant_inventory_fraction = fraction;
+        Ok(())
+    }
+    
+    /// Get the diffusion neighbors of a channel as (id, grid_x, grid_y)
+    /// Returns an empty list for an unknown channel_id.
+    pub fn get_channel_neighbors(&self, channel_id: usize) -> Vec<(usize, i32, i32)> {
+        let channels = self.fuel_channels.lock().unwrap();
+        match channels.get(channel_id) {
+            Some(channel) => channel.neighbors
+                .iter()
+                .filter_map(|&idx| channels.get(idx))
+                .map(|n| (n.id, n.grid_x, n.grid_y))
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+    
+    /// Get the LAR zones with their member channels and measured power
+    pub fn get_lar_zones(&self) -> Vec<LarZone> {
+        self.lar_zones.lock().unwrap().clone()
+    }
+    
+    /// Clear zone power and PID history (membership is kept)
+    fn reset_lar_zones(&self) {
+        for zone in self.lar_zones.lock().unwrap().iter_mut() {
+            zone.power_percent = 0.0;
+            zone.power_error = 0.0;
+            zone.integral_error = 0.0;
+            zone.last_error = 0.0;
+        }
+    }
+    
+    /// Start recording a channel's power, fuel temperature, void and xenon
+    /// every spatial step (up to `MAX_TRACKED_CHANNELS` at once)
+    pub fn track_channel(&self, channel_id: usize) -> Result<(), String> {
+        let num_channels = self.fuel_channels.lock().unwrap().len();
+        if channel_id >= num_channels {
+            return Err(format!("Invalid channel_id {}: valid range is 0..{}", channel_id, num_channels));
+        }
+        self.channel_history.lock().unwrap().track(channel_id)
+    }
+    
+    /// Stop recording a channel and discard its history
+    /// Returns false if the channel was not tracked.
+    pub fn untrack_channel(&self, channel_id: usize) -> bool {
+        self.channel_history.lock().unwrap().untrack(channel_id)
+    }
+    
+    /// Ids of the channels currently tracked
+    pub fn get_tracked_channels(&self) -> Vec<usize> {
+        self.channel_history.lock().unwrap().tracked()
+    }
+    
+    /// Recorded samples of a tracked channel, oldest first (None if not tracked)
+    pub fn get_channel_history(&self, channel_id: usize) -> Option<Vec<ChannelSample>> {
+        self.channel_history.lock().unwrap().history(channel_id)
+    }
+    
+    /// k_eff of recent steps with the mean rod position, oldest first
+    pub fn get_keff_history(&self) -> Vec<KeffSample> {
+        self.keff_history.lock().unwrap().samples()
+    }
+    
+    /// Mean position of all control rods (0 = inserted, 1 = withdrawn)
+    fn average_rod_position(&self) -> f64 {
+        let rods = self.control_rods.lock().unwrap();
+        if rods.is_empty() {
+            return 1.0;
+        }
+        rods.iter().map(|rod| rod.position).sum::<f64>() / rods.len() as f64
+    }
+    
+    /// Get the core-minimum DNBR and the channel where it occurs
+    /// DNBR is updated by `step_spatial`; before the first step every channel reads 99.
+    pub fn get_min_dnbr(&self) -> Option<MinDnbr> {
+        min_dnbr(&self.fuel_channels.lock().unwrap())
+    }
+    
+    /// Key values of the channels matching `filter`, in id order
+    pub fn query_channels(&self, filter: &ChannelFilter) -> Vec<ChannelSummary> {
+        self.fuel_channels.lock().unwrap().iter()
+            .filter(|c| filter.matches(c))
+            .map(|c| ChannelSummary {
+                id: c.id,
+                grid_x: c.grid_x,
+                grid_y: c.grid_y,
+                local_power: c.local_power,
+                fuel_temp: c.fuel_temp,
+                coolant_temp: c.coolant_temp,
+                coolant_void: c.coolant_void,
+                dnbr: c.dnbr,
+            })
+            .collect()
+    }
+    
+    /// Get the smallest subcooling margin in the core and the channel where
+    /// it occurs, each channel against saturation at its own pressure
+    pub fn get_subcooling_margin(&self) -> Option<SubcoolingMargin> {
+        let channels = self.fuel_channels.lock().unwrap();
+        channels.iter()
+            .map(|c| {
+                let saturation_temp = self.backend.saturation_temp(c.pressure);
+                SubcoolingMargin {
+                    channel_id: c.id,
+                    coolant_temp: c.coolant_temp,
+                    saturation_temp,
+                    margin: saturation_temp - c.coolant_temp,
+                }
+            })
+            .min_by(|a, b| a.margin.total_cmp(&b.margin))
+    }
+    
+    /// Get all channels with a ruptured pressure tube
+    pub fn get_ruptured_channels(&self) -> Vec<FuelChannel> {
+        self.fuel_channels.lock().unwrap()
+            .iter()
+            .filter(|c| c.ruptured)
+            .cloned()
+            .collect()
+    }
+    
+    /// Channels whose power was clamped at the ceiling in the last spatial step
+    pub fn get_clamped_channels(&self) -> Vec<FuelChannel> {
+        self.fuel_channels.lock().unwrap()
+            .iter()
+            .filter(|c| c.power_clamped)
+            .cloned()
+            .collect()
+    }
+    
+    /// Set the per-channel power ceiling of the spatial solver [MW]
+    pub fn set_channel_power_ceiling(&self, ceiling_mw: f64) -> Result<(), String> {
+        if !(ceiling_mw.is_finite() && ceiling_mw > 0.0) {
+            return Err(format!("Channel power ceiling {} MW must be positive", ceiling_mw));
+        }
+        *self.channel_power_ceiling_mw.lock().unwrap() = ceiling_mw;
+        Ok(())
+    }
+    
+    pub fn get_channel_power_ceiling(&self) -> f64 {
+        *self.channel_power_ceiling_mw.lock().unwrap()
+    }
+    
+    /// Fuel volume per channel that power density refers to [m³]
+    pub fn get_channel_volume(&self) -> f64 {
+        *self.channel_volume_m3.lock().unwrap()
+    }
+    
+    /// Set the fuel volume per channel [m³] for non-standard geometries
+    /// Power densities are recomputed right away; the hot-spot alert keeps
+    /// firing at the channel power limit.
+    pub fn set_channel_volume(&self, volume_m3: f64) -> Result<(), String> {
+        validate_channel_volume(volume_m3)?;
+        *self.channel_volume_m3.lock().unwrap() = volume_m3;
+        for channel in self.fuel_channels.lock().unwrap().iter_mut() {
+            channel.power_density = channel.local_power / volume_m3;
+        }
+        Ok(())
+    }
+    
+    /// Arm a drill: one fault from `fault_set` fires at a seeded time
+    /// `window_s.0..=window_s.1` seconds from now. The same seed always picks
+    /// the same fault, target and time. Re-arming replaces a pending drill.
+    pub fn arm_random_fault(
+        &self,
+        seed: u64,
+        fault_set: Vec<FaultKind>,
+        window_s: (f64, f64),
+    ) -> Result<(), String> {
+        let now = self.state.lock().unwrap().time;
+        self.fault_injector.lock().unwrap().arm(now, seed, &fault_set, window_s)
+    }
+    
+    /// Whether a drill is armed and which fault (if any) it fired
+    pub fn get_fault_drill_status(&self) -> FaultDrillStatus {
+        self.fault_injector.lock().unwrap().status()
+    }
+    
+    /// Apply the armed drill fault if its time has come
+    fn inject_due_fault(&self) {
+        let time = self.state.lock().unwrap().time;
+        let due = self.fault_injector.lock().unwrap().take_due(time);
+        let Some(fault) = due else { return };
+        
+        let (target, description) = match fault.kind {
+            FaultKind::StuckRod => {
+                let mut rods = self.control_rods.lock().unwrap();
+                let free: Vec<usize> = (0..rods.len()).filter(|&i| !rods[i].stuck).collect();
+                if free.is_empty() {
+                    (None, "No free control rod to jam".to_string())
+                } else {
+                    let rod = &mut rods[free[(fault.target_draw % free.len() as u64) as usize]];
+                    rod.stuck = true;
+                    (Some(rod.id), format!(
+                        "Control rod {} ({}) stuck at {:.0}% withdrawn",
+                        rod.id, rod.channel_type, rod.position * 100.0
+                    ))
+                }
+            }
+            FaultKind::PumpTrip => {
+                // Two circulation loops, one per core half
+                let loop_id = (fault.target_draw % 2) as usize;
+                let mut channels = self.fuel_channels.lock().unwrap();
+                let mut affected = 0;
+                for ch in channels.iter_mut().filter(|c| (c.x >= 0.0) == (loop_id == 1)) {
+                    ch.flow_rate *= faults::PUMP_TRIP_FLOW_FRACTION;
+                    affected += 1;
+                }
+                (Some(loop_id), format!(
+                    "Main circulation pump trip in loop {} ({} channels)",
+                    loop_id, affected
+                ))
+            }
+            FaultKind::ChannelBlockage => {
+                let mut channels = self.fuel_channels.lock().unwrap();
+                if channels.is_empty() {
+                    (None, "No fuel channel to block".to_string())
+                } else {
+                    let idx = (fault.target_draw % channels.len() as u64) as usize;
+                    let ch = &mut channels[idx];
+                    ch.flow_rate *= faults::CHANNEL_BLOCKAGE_FLOW_FRACTION;
+                    (Some(ch.id), format!(
+                        "Flow blockage in channel {} ({}, {})",
+                        ch.id, ch.grid_x, ch.grid_y
+                    ))
                 }
             }
-            
-            // Update time
-            state.time += dt;
+        };
+        
+        println!("[reactor] Drill fault at t={:.1}s: {}", time, description);
+        self.fault_injector.lock().unwrap().record_fired(FiredFault {
+            kind: fault.kind,
+            time,
+            target,
+            description,
+        });
+    }
+    
+    /// Fraction of the delayed neutron source coming from each precursor
+    /// group (group 1 = longest-lived). Short-lived groups dominate right
+    /// after a power change, long-lived ones once it has settled.
+    pub fn get_delayed_source_breakdown(&self) -> [f64; constants::NUM_DELAYED_GROUPS] {
+        delayed_source_fractions(&self.state.lock().unwrap().precursors_6)
+    }
+    
+    /// Steady-state reactivity split into its sources at the current conditions
+    pub fn get_reactivity_breakdown(&self) -> ReactivityBreakdown {
+        let (fuel_temp, graphite_temp, coolant_void, xenon_135, axial_flux) = {
+            let state = self.state.lock().unwrap();
+            (
+                state.avg_fuel_temp,
+                state.avg_graphite_temp,
+                state.avg_coolant_void,
+                state.xenon_135,
+                state.axial_flux.clone(),
+            )
+        };
+        let rod_worth = self.calculate_total_rod_worth(&axial_flux);
+        self.reactivity_breakdown_at(fuel_temp, graphite_temp, coolant_void, xenon_135, rod_worth)
+    }
+    
+    /// Steady-state reactivity split into its sources at given conditions
+    fn reactivity_breakdown_at(
+        &self,
+        fuel_temp: f64,
+        graphite_temp: f64,
+        coolant_void: f64,
+        xenon_135: f64,
+        rod_worth: f64,
+    ) -> ReactivityBreakdown {
+        let cold_fuel = channel_defaults::FUEL_TEMP_K;
+        let cold_graphite = channel_defaults::GRAPHITE_TEMP_K;
+        let target = |fuel, graphite, void, xenon, rods| self.backend.target_reactivity(fuel, graphite, void, xenon, rods);
+        let base = target(cold_fuel, cold_graphite, 0.0, 0.0, 0.0);
+        ReactivityBreakdown {
+            base,
+            fuel_temperature: target(fuel_temp, cold_graphite, 0.0, 0.0, 0.0) - base,
+            graphite_temperature: target(cold_fuel, graphite_temp, 0.0, 0.0, 0.0) - base,
+            void: target(cold_fuel, cold_graphite, coolant_void, 0.0, 0.0) - base,
+            xenon: self.backend.xenon_reactivity(xenon_135),
+            rods: -rod_worth,
+            total: target(fuel_temp, graphite_temp, coolant_void, xenon_135, rod_worth),
+        }
+    }
+    
+    /// Operational reactivity margin: inserted rod worth expressed in
+    /// equivalent fully inserted manual (RR) rods
+    pub fn get_orm(&self) -> f64 {
+        let rods = self.control_rods.lock().unwrap();
+        let manual: Vec<f64> = rods.iter()
+            .filter(|r| r.rod_type == RodType::Manual)
+            .map(|r| r.effective_worth())
+            .collect();
+        let reference_worth = if manual.is_empty() {
+            rods.iter().map(|r| r.effective_worth()).sum::<f64>() / rods.len().max(1) as f64
+        } else {
+            manual.iter().sum::<f64>() / manual.len() as f64
+        };
+        if reference_worth <= 0.0 {
+            return 0.0;
+        }
+        let inserted: f64 = rods.iter().map(|r| r.effective_worth() * (1.0 - r.position)).sum();
+        inserted / reference_worth
+    }
+    
+    /// Capture everything important right now in one structure
+    pub fn get_blackbox_snapshot(&self) -> BlackboxSnapshot {
+        let captured_at_unix_ms = unix_time_ms();
+        let state = self.get_state();
+        let hottest_channels = blackbox::hottest_channels(
+            &self.fuel_channels.lock().unwrap(),
+            blackbox::BLACKBOX_HOTTEST_CHANNELS,
+        );
+        BlackboxSnapshot {
+            captured_at_unix_ms,
+            simulation_time: state.time,
+            state,
+            control_rods: self.get_control_rods(),
+            hottest_channels,
+            active_alerts: self.get_active_alerts(),
+            reactivity_breakdown: self.get_reactivity_breakdown(),
+            orm: self.get_orm(),
+            reactivity_trace: self.reactivity_trace.lock().unwrap().samples(),
+            metadata: self.get_metadata(),
+            config_hash: format!("{:016x}", self.config_hash()),
+        }
+    }
+    
+    /// Every alert code with its default severity, source and message template
+    pub fn alert_catalog() -> Vec<AlertDefinition> {
+        alerts::alert_catalog()
+    }
+    
+    /// Get alerts held by the annunciator, with first-seen and last-seen times
+    /// An alert stays listed for the hold time after its condition clears.
+    pub fn get_active_alerts(&self) -> Vec<ActiveAlert> {
+        self.alert_tracker.lock().unwrap().active()
+    }
+    
+    /// Set how long an alert stays annunciated after its condition clears [s]
+    pub fn set_alert_hold_time(&self, seconds: f64) -> Result<(), String> {
+        if !(seconds.is_finite() && seconds >= 0.0) {
+            return Err(format!("Alert hold time {} s must be a non-negative number", seconds));
+        }
+        self.alert_tracker.lock().unwrap().set_hold_time(seconds);
+        Ok(())
+    }
+    
+    /// Effective full-power hours of the core since fresh fuel
+    pub fn get_efph(&self) -> f64 {
+        self.state.lock().unwrap().full_power_hours
+    }
+    
+    /// Effective full-power days of the core since fresh fuel
+    pub fn get_efpd(&self) -> f64 {
+        self.get_efph() / 24.0
+    }
+    
+    /// Phase timing of the last `step_spatial()`
+    /// Always None unless built with the `profiling` feature.
+    pub fn get_last_step_timing(&self) -> Option<StepTiming> {
+        self.last_step_timing.lock().unwrap().clone()
+    }
+    
+    /// Get the criticality condition from the current reactivity
+    pub fn get_criticality_state(&self) -> Criticality {
+        Criticality::from_dollars(self.state.lock().unwrap().reactivity_dollars)
+    }
+    
+    /// Simulation time of the last SCRAM, new alert, operator rod move and
+    /// explosion, for "time since" displays
+    pub fn get_event_times(&self) -> EventTimes {
+        let (explosion_occurred, explosion_time) = {
+            let state = self.state.lock().unwrap();
+            (state.explosion_occurred, state.explosion_time)
+        };
+        EventTimes {
+            last_alert: self.alert_tracker.lock().unwrap().last_raised(),
+            last_explosion: explosion_occurred.then_some(explosion_time),
+            ..*self.event_times.lock().unwrap()
+        }
+    }
+    
+    /// Grade the current end state of a run
+    ///
+    /// Checked from worst to best: a latched explosion, then core damage
+    /// (any ruptured channel or fuel at the melting point), then a
+    /// subcritical core below `LOW_POWER_THRESHOLD_PERCENT`. Otherwise a
+    /// SCRAM in progress, a critical core or a core still off critical.
+    pub fn classify_outcome(&self) -> Outcome {
+        let state = self.state.lock().unwrap();
+        if state.explosion_occurred {
+            return Outcome::Exploded { severity: state.explosion_severity };
+        }
+        let core_damaged = self.fuel_channels.lock().unwrap().iter()
+            .any(|c| c.ruptured || c.fuel_temp >= constants::FUEL_MELTING_POINT_K);
+        if core_damaged || state.avg_fuel_temp >= constants::FUEL_MELTING_POINT_K {
+            return Outcome::CoreDamaged;
+        }
+        let criticality = Criticality::from_dollars(state.reactivity_dollars);
+        if state.power_percent < constants::LOW_POWER_THRESHOLD_PERCENT && criticality == Criticality::Subcritical {
+            Outcome::SafeShutdown
+        } else if state.scram_active {
+            Outcome::Scrammed
+        } else if criticality == Criticality::Critical {
+            Outcome::StableOperation { power: state.power_percent }
+        } else {
+            Outcome::Transient { power: state.power_percent }
+        }
+    }
+    
+    /// Current power and total core flow on the power-flow map, with the
+    /// region of the operating envelope they fall in
+    /// Uncovered channels count as having no flow.
+    pub fn get_operating_point(&self) -> OperatingPoint {
+        let power_percent = self.state.lock().unwrap().power_percent;
+        let channels = self.fuel_channels.lock().unwrap();
+        let total_flow: f64 = channels.iter()
+            .filter(|c| !c.uncovered)
+            .map(|c| c.flow_rate)
+            .sum();
+        let nominal_flow = channels.len() as f64 * channel_defaults::FLOW_RATE_KG_S;
+        let flow_percent = if nominal_flow > 0.0 { total_flow / nominal_flow * 100.0 } else { 0.0 };
+        OperatingPoint {
+            power_percent,
+            flow_percent,
+            region: OperatingRegion::classify(power_percent, flow_percent),
+        }
+    }
+    
+    /// Choose the unit of `reactivity_display` (Δk/k, dollars, cents or pcm)
+    pub fn set_reactivity_display_unit(&self, unit: ReactivityUnit) {
+        let mut state = self.state.lock().unwrap();
+        state.reactivity_display_unit = unit;
+        state.update_reactivity_units();
+    }
+    
+    /// Name, description, creation time and plant model of this simulation
+    pub fn get_metadata(&self) -> SimulationMetadata {
+        self.metadata.lock().unwrap().clone()
+    }
+    
+    /// Replace the simulation metadata (kept across resets)
+    /// A `created_at` of 0 keeps the current creation time.
+    pub fn set_metadata(&self, metadata: SimulationMetadata) -> Result<(), String> {
+        if metadata.name.trim().is_empty() {
+            return Err("Simulation name must not be empty".to_string());
+        }
+        let mut current = self.metadata.lock().unwrap();
+        let created_at = if metadata.created_at == 0 { current.created_at } else { metadata.created_at };
+        *current = SimulationMetadata { created_at, ..metadata };
+        Ok(())
+    }
+    
+    /// Geometry hash of the loaded layout, computed when it was loaded
+    /// Equal hashes mean two runs used the same channels and rods.
+    pub fn config_hash(&self) -> u64 {
+        *self.config_hash.lock().unwrap()
+    }
+    
+    /// Channel id by grid position (x, y), built when the layout was loaded
+    pub fn get_grid_index(&self) -> HashMap<(i32, i32), usize> {
+        self.grid_index.lock().unwrap().clone()
+    }
+    
+    /// Grid index as a list (tuple keys do not serialize to JSON), row by row
+    pub fn get_grid_cells(&self) -> Vec<GridCell> {
+        let mut cells: Vec<GridCell> = self.grid_index.lock().unwrap().iter()
+            .map(|(&(grid_x, grid_y), &channel_id)| GridCell { grid_x, grid_y, channel_id })
+            .collect();
+        cells.sort_by_key(|cell| (cell.grid_y, cell.grid_x));
+        cells
+    }
+    
+    /// Get the kinetics constants currently in effect
+    pub fn get_physics_constants(&self) -> PhysicsConstants {
+        let (beta_eff, neutron_lifetime) = self.backend.kinetics_constants();
+        PhysicsConstants {
+            beta_eff,
+            neutron_lifetime,
+            nominal_power_mw: self.nominal_power_mw,
+            prompt_period_at_110_cents: neutron_lifetime / (0.1 * beta_eff),
+        }
+    }
+    
+    /// Fail unless the active backend reads the kinetics tuning held in
+    /// Fortran module variables, so nothing is stored that it would never use
+    fn ensure_kinetics_tunable(&self, setting: &str) -> Result<(), String> {
+        if !self.backend.applies_fortran_tuning() {
+            return Err(format!("The {} backend cannot apply the {}", self.backend.name(), setting));
+        }
+        Ok(())
+    }
+    
+    /// Set the prompt neutron lifetime [s] used by all kinetics solvers
+    /// The default is the RBMK value `constants::NEUTRON_LIFETIME` (1 ms);
+    /// an LWR-like 0.1 ms makes prompt-critical excursions ten times faster.
+    /// `reset()` and `restart_hot()` restore the default.
+    pub fn set_neutron_lifetime(&self, lifetime: f64) -> Result<(), String> {
+        if !(constants::NEUTRON_LIFETIME_MIN..=constants::NEUTRON_LIFETIME_MAX).contains(&lifetime) {
+            return Err(format!(
+                "Neutron lifetime {} s out of range [{}, {}]",
+                lifetime, constants::NEUTRON_LIFETIME_MIN, constants::NEUTRON_LIFETIME_MAX
+            ));
+        }
+        self.ensure_kinetics_tunable("neutron lifetime")?;
+        fortran_ffi::set_neutron_lifetime(lifetime);
+        Ok(())
+    }
+    
+    /// Reactivity [Δk/k] inferred from a measured stable period [s]
+    /// (the inverse of the reactor period calculation). A negative period
+    /// must be longer than the slowest precursor mean life, 1/λ₁ ≈ 80 s.
+    pub fn reactivity_from_period(&self, period: f64) -> Result<f64, String> {
+        let shortest_negative_period = -1.0 / constants::LAMBDA_I[0];
+        if period.is_nan() || period == 0.0 || (period < 0.0 && period >= shortest_negative_period) {
+            return Err(format!(
+                "Period {} s has no stable solution (negative periods must be below {:.1} s)",
+                period, shortest_negative_period
+            ));
+        }
+        if period.is_infinite() {
+            return Ok(0.0);
+        }
+        Ok(self.backend.reactivity_from_period(period))
+    }
+    
+    /// Select the point kinetics integration scheme used by `step()`
+    /// (the spatial solver of `step_spatial()` is not affected). Explicit
+    /// Euler shows the instability of a naive scheme at large time steps.
+    pub fn set_kinetics_solver(&self, solver: KineticsSolver) {
+        *self.kinetics_solver.lock().unwrap() = solver;
+    }
+    
+    pub fn get_kinetics_solver(&self) -> KineticsSolver {
+        *self.kinetics_solver.lock().unwrap()
+    }
+    
+    /// Highest `simulation_realtime` speed that keeps fidelity [× real time]
+    /// Bounded by the steps the loop can run per wall-clock second at the
+    /// current dt and, above critical, by the reactor period: one frame may
+    /// advance at most `REALTIME_PERIOD_FRACTION` of it.
+    pub fn get_max_safe_time_speed(&self) -> f64 {
+        let state = self.state.lock().unwrap();
+        let work_ceiling = constants::REALTIME_STEP_BUDGET_PER_S * state.dt;
+        match Criticality::from_dollars(state.reactivity_dollars) {
+            Criticality::DelayedSupercritical | Criticality::PromptCritical
+                if state.period.is_finite() && state.period > 0.0 =>
+            {
+                let period_ceiling =
+                    constants::REALTIME_PERIOD_FRACTION * state.period / constants::REALTIME_FRAME_INTERVAL_S;
+                work_ceiling.min(period_ceiling)
+            }
+            _ => work_ceiling,
         }
     }
     
-    /// Get fuel channels with their independent parameters
-    /// Each channel has its own physics state from 2D spatial simulation
-    /// This is the main method for getting channel data for visualization
-    pub fn get_fuel_channels_synchronized(&self) -> Vec<FuelChannel> {
-        // No synchronization - channels have independent values from spatial physics
-        self.fuel_channels.lock().unwrap().clone()
+    /// Raise `TimeSpeedHigh` when `time_speed` is above
+    /// `get_max_safe_time_speed()`; true when it is
+    /// The alert is added to the last step's alerts, once per step.
+    pub fn check_time_speed(&self, time_speed: f64) -> bool {
+        let max_speed = self.get_max_safe_time_speed();
+        if time_speed <= max_speed {
+            return false;
+        }
+        let mut state = self.state.lock().unwrap();
+        if !state.alert_details.iter().any(|a| a.code == AlertCode::TimeSpeedHigh) {
+            state.raise_alert(
+                AlertCode::TimeSpeedHigh,
+                format!("WARNING: Time speed {:.0}x exceeds the safe {:.0}x - physics fidelity reduced", time_speed, max_speed),
+            );
+            state.sort_alerts();
+            self.alert_tracker.lock().unwrap().update(state.time, &state.alert_details);
+        }
+        true
+    }
+    
+    /// Time step of the last step after sub-stepping; before the first
+    /// step, the configured dt without sub-steps
+    pub fn get_effective_time_step(&self) -> EffectiveTimeStep {
+        if let Some(step) = *self.last_time_step.lock().unwrap() {
+            return step;
+        }
+        let dt = self.state.lock().unwrap().dt;
+        EffectiveTimeStep { dt, substeps: 1, effective_dt: dt }
+    }
+    
+    /// Integration step the kinetics actually used in the last step [s]
+    pub fn get_effective_dt(&self) -> f64 {
+        self.get_effective_time_step().effective_dt
+    }
+    
+    /// Kinetics sub-steps of the last step (1 = not sub-stepped)
+    pub fn get_substep_count(&self) -> usize {
+        self.get_effective_time_step().substeps
+    }
+    
+    /// Set the graphite-to-channel gas-gap conductance relative to nominal
+    /// Below 1.0 models gap degradation (oxidation, gap closure): graphite
+    /// runs hotter and responds more slowly in both thermal models.
+    pub fn set_graphite_gap_conductance(&self, conductance: f64) -> Result<(), String> {
+        if !(constants::GRAPHITE_GAP_CONDUCTANCE_MIN..=constants::GRAPHITE_GAP_CONDUCTANCE_MAX).contains(&conductance) {
+            return Err(format!(
+                "Graphite gap conductance {} out of range [{}, {}]",
+                conductance, constants::GRAPHITE_GAP_CONDUCTANCE_MIN, constants::GRAPHITE_GAP_CONDUCTANCE_MAX
+            ));
+        }
+        self.ensure_kinetics_tunable("graphite gap conductance")?;
+        fortran_ffi::set_graphite_gap_conductance(conductance);
+        *self.graphite_gap_conductance.lock().unwrap() = conductance;
+        Ok(())
+    }
+    
+    /// Set the effectiveness of the nitrogen-helium circuit cooling the
+    /// graphite stack, relative to nominal. It removes graphite heat
+    /// independently of the coolant: higher values lower the graphite
+    /// temperature (and its feedback) for a given power in both thermal models.
+    pub fn set_graphite_cooling_effectiveness(&self, effectiveness: f64) -> Result<(), String> {
+        if !(constants::GRAPHITE_COOLING_EFFECTIVENESS_MIN..=constants::GRAPHITE_COOLING_EFFECTIVENESS_MAX).contains(&effectiveness) {
+            return Err(format!(
+                "Graphite cooling effectiveness {} out of range [{}, {}]",
+                effectiveness, constants::GRAPHITE_COOLING_EFFECTIVENESS_MIN, constants::GRAPHITE_COOLING_EFFECTIVENESS_MAX
+            ));
+        }
+        self.ensure_kinetics_tunable("graphite cooling effectiveness")?;
+        fortran_ffi::set_graphite_cooling_effectiveness(effectiveness);
+        *self.graphite_cooling_effectiveness.lock().unwrap() = effectiveness;
+        Ok(())
+    }
+    
+    /// Time constant with which the kinetics reactivity follows its
+    /// steady-state target outside a SCRAM [s]
+    pub fn get_reactivity_smoothing_tau(&self) -> f64 {
+        *self.reactivity_smoothing_tau.lock().unwrap()
+    }
+    
+    /// Set the reactivity smoothing time constant [s]
+    /// The damping it adds is numerical, not physical: large values hide fast
+    /// transients, small ones let step-to-step noise through.
+    pub fn set_reactivity_smoothing_tau(&self, tau_s: f64) -> Result<(), String> {
+        validate_reactivity_smoothing_tau(tau_s)?;
+        self.ensure_kinetics_tunable("reactivity smoothing time constant")?;
+        fortran_ffi::set_reactivity_smoothing_tau(tau_s);
+        *self.reactivity_smoothing_tau.lock().unwrap() = tau_s;
+        Ok(())
+    }
+    
+    /// Time constant with which the inlet plenum follows feedwater and
+    /// returning coolant [s]
+    pub fn get_plenum_mixing_time(&self) -> f64 {
+        *self.plenum_mixing_time.lock().unwrap()
+    }
+    
+    /// Set the inlet plenum mixing time constant [s]
+    pub fn set_plenum_mixing_time(&self, seconds: f64) -> Result<(), String> {
+        validate_plenum_mixing_time(seconds)?;
+        *self.plenum_mixing_time.lock().unwrap() = seconds;
+        Ok(())
+    }
+    
+    /// Temperature of the common inlet plenum feeding every channel [K]
+    /// The spatial model shifts each channel's coolant target and outlet
+    /// temperature with it; the point model of `step()` does not take an
+    /// inlet temperature.
+    pub fn get_inlet_plenum_temp(&self) -> f64 {
+        self.state.lock().unwrap().inlet_plenum_temp
+    }
+    
+    /// Select how fuel thermal conductivity depends on burnup
+    /// With `BurnupDegraded` aged channels run hotter for the same power,
+    /// which strengthens their Doppler feedback. Only the per-channel thermal
+    /// step of `step_spatial()` uses channel burnup; `step()` is not affected,
+    /// so this fails when the spatial solver is unavailable.
+    pub fn set_fuel_conductivity_model(&self, model: FuelConductivityModel) -> Result<(), String> {
+        if !self.spatial_solver_available() {
+            return Err("The fuel conductivity model needs the spatial solver (Fortran library not loaded)".to_string());
+        }
+        fortran_ffi::set_fuel_conductivity_model(match model {
+            FuelConductivityModel::Constant => fortran_ffi::FUEL_CONDUCTIVITY_CONSTANT,
+            FuelConductivityModel::BurnupDegraded => fortran_ffi::FUEL_CONDUCTIVITY_BURNUP,
+        });
+        *self.fuel_conductivity_model.lock().unwrap() = model;
+        Ok(())
+    }
+    
+    pub fn get_fuel_conductivity_model(&self) -> FuelConductivityModel {
+        *self.fuel_conductivity_model.lock().unwrap()
+    }
+    
+    /// Feed every channel from the inlet plenum
+    fn set_inlet_temp(&self, temp_k: f64) {
+        for channel in self.fuel_channels.lock().unwrap().iter_mut() {
+            channel.inlet_temp = temp_k;
+        }
+    }
+    
+    /// Get all runtime-tunable parameters in one struct
+    pub fn get_simulation_parameters(&self) -> SimulationParameters {
+        let (_, neutron_lifetime) = self.backend.kinetics_constants();
+        let scram_insertion_time = self.get_scram_insertion_time();
+        let alert_hold_time = self.alert_tracker.lock().unwrap().hold_time();
+        let graphite_gap_conductance = *self.graphite_gap_conductance.lock().unwrap();
+        let graphite_cooling_effectiveness = *self.graphite_cooling_effectiveness.lock().unwrap();
+        let reactivity_smoothing_tau = self.get_reactivity_smoothing_tau();
+        let plenum_mixing_time = self.get_plenum_mixing_time();
+        let channel_volume_m3 = self.get_channel_volume();
+        let fuel_conductivity_model = self.get_fuel_conductivity_model();
+        let absorber_depletion_rate = *self.absorber_depletion_rate.lock().unwrap();
+        let rod_worth_shapes = *self.rod_worth_shapes.lock().unwrap();
+        let state = self.state.lock().unwrap();
+        let ar = &state.auto_regulator;
+        SimulationParameters {
+            dt: state.dt,
+            neutron_lifetime,
+            scram_insertion_time,
+            alert_hold_time,
+            feedwater_fraction: state.feedwater_fraction,
+            graphite_gap_conductance,
+            graphite_cooling_effectiveness,
+            reactivity_smoothing_tau,
+            plenum_mixing_time,
+            channel_volume_m3,
+            fuel_conductivity_model,
+            absorber_depletion_rate,
+            rod_worth_shapes,
+            reactivity_display_unit: state.reactivity_display_unit,
+            ar_kp: ar.kp,
+            ar_ki: ar.ki,
+            ar_kd: ar.kd,
+            ar_rod_speed: ar.rod_speed,
+            ar_deadband: ar.deadband,
+        }
+    }
+    
+    /// Apply a complete set of parameters
+    /// Nothing is changed unless every field is valid and every changed
+    /// setting can be applied by the active backend.
+    pub fn set_simulation_parameters(&self, params: &SimulationParameters) -> Result<(), String> {
+        params.validate()?;
+        let current = self.get_simulation_parameters();
+        let kinetics_changed = params.neutron_lifetime != current.neutron_lifetime
+            || params.graphite_gap_conductance != current.graphite_gap_conductance
+            || params.graphite_cooling_effectiveness != current.graphite_cooling_effectiveness
+            || params.reactivity_smoothing_tau != current.reactivity_smoothing_tau;
+        if kinetics_changed {
+            self.ensure_kinetics_tunable("kinetics parameters")?;
+        }
+        if params.fuel_conductivity_model != current.fuel_conductivity_model {
+            self.set_fuel_conductivity_model(params.fuel_conductivity_model)?;
+        }
+        if kinetics_changed {
+            fortran_ffi::set_neutron_lifetime(params.neutron_lifetime);
+            fortran_ffi::set_graphite_gap_conductance(params.graphite_gap_conductance);
+            fortran_ffi::set_graphite_cooling_effectiveness(params.graphite_cooling_effectiveness);
+            fortran_ffi::set_reactivity_smoothing_tau(params.reactivity_smoothing_tau);
+        }
+        *self.graphite_gap_conductance.lock().unwrap() = params.graphite_gap_conductance;
+        *self.graphite_cooling_effectiveness.lock().unwrap() = params.graphite_cooling_effectiveness;
+        *self.reactivity_smoothing_tau.lock().unwrap() = params.reactivity_smoothing_tau;
+        *self.plenum_mixing_time.lock().unwrap() = params.plenum_mixing_time;
+        self.set_channel_volume(params.channel_volume_m3)?;
+        *self.absorber_depletion_rate.lock().unwrap() = params.absorber_depletion_rate;
+        *self.rod_worth_shapes.lock().unwrap() = params.rod_worth_shapes;
+        *self.scram_insertion_time.lock().unwrap() = params.scram_insertion_time;
+        self.alert_tracker.lock().unwrap().set_hold_time(params.alert_hold_time);
+        
+        let mut state = self.state.lock().unwrap();
+        state.dt = params.dt;
+        state.feedwater_fraction = params.feedwater_fraction;
+        state.reactivity_display_unit = params.reactivity_display_unit;
+        state.update_reactivity_units();
+        let ar = &mut state.auto_regulator;
+        ar.kp = params.ar_kp;
+        ar.ki = params.ar_ki;
+        ar.kd = params.ar_kd;
+        ar.rod_speed = params.ar_rod_speed;
+        ar.deadband = params.ar_deadband;
+        Ok(())
     }
     
     /// Reset simulation to initial state (shutdown, cold, no xenon)
     pub fn reset(&self) {
-        // Reset Fortran explosion tracking state
-        fortran_ffi::reset_explosion_state();
+        // Reset backend history (explosion tracking, 6-group precursors)
+        *self.skipped_resets.lock().unwrap() = self.backend.reset();
+        // A tuned prompt neutron lifetime would otherwise survive in the kinetics
+        tune_fortran(|| fortran_ffi::set_neutron_lifetime(constants::NEUTRON_LIFETIME));
         
-        // Reset Fortran 6-group precursor state
-        fortran_ffi::reset_precursors_6group_state();
+        self.alert_tracker.lock().unwrap().clear();
+        self.fault_injector.lock().unwrap().clear();
+        self.detector_faults.lock().unwrap().clear();
+        *self.event_times.lock().unwrap() = EventTimes::default();
+        self.channel_history.lock().unwrap().clear_samples();
+        self.reactivity_trace.lock().unwrap().clear();
+        self.keff_history.lock().unwrap().clear();
+        *self.last_time_step.lock().unwrap() = None;
+        self.stability_monitor.lock().unwrap().clear();
+        self.checkpoints.lock().unwrap().clear();
+        *self.reactivity_reference.lock().unwrap() = None;
+        self.inverse_m.lock().unwrap().clear();
+        self.reset_lar_zones();
         
+        // The display unit is a client preference and survives the reset
         let mut state = self.state.lock().unwrap();
+        let display_unit = state.reactivity_display_unit;
         *state = ReactorState::default();
+        state.reactivity_display_unit = display_unit;
+        state.update_reactivity_units();
         
         // Reset all control rods to fully inserted (shutdown)
         let mut rods = self.control_rods.lock().unwrap();
         for rod in rods.iter_mut() {
             rod.position = 0.0;  // All rods fully inserted for shutdown
+            rod.stuck = false;   // Clear mechanical faults
+            rod.absorber_depletion = 0.0; // Fresh absorber with the fresh core
         }
         
         // Reset fuel channels to cold shutdown state
@@ -1484,6 +6104,9 @@ impl ReactorSimulator {
             // Thermal-hydraulic parameters (reset to nominal cold values)
             channel.pressure = channel_defaults::PRESSURE_MPA;
             channel.flow_rate = channel_defaults::FLOW_RATE_KG_S;
+            channel.ruptured = false;  // Pressure tubes replaced
+            channel.uncovered = false;
+            channel.power_clamped = false;
             channel.inlet_temp = channel_defaults::INLET_TEMP_K;
             channel.outlet_temp = channel_defaults::OUTLET_TEMP_K;
             
@@ -1496,9 +6119,11 @@ impl ReactorSimulator {
             // Xenon/Iodine (fresh start)
             channel.iodine_135 = channel_defaults::IODINE_135;
             channel.xenon_135 = channel_defaults::XENON_135;
+            channel.promethium_149 = channel_defaults::PROMETHIUM_149;
+            channel.samarium_149 = channel_defaults::SAMARIUM_149;
             
-            // Fuel state - burnup resets to fresh fuel
-            channel.burnup = channel_defaults::BURNUP_MWD_KGU;
+            // Fuel state - burnup resets to the fuel as loaded
+            channel.burnup = channel.initial_burnup;
             // enrichment stays at its value (could be different per channel)
             
             // Control rod position - if channel has a rod, it's inserted
@@ -1508,8 +6133,488 @@ impl ReactorSimulator {
             
             // Local reactivity
             channel.local_reactivity = channel_defaults::LOCAL_REACTIVITY;
+            channel.dnbr = channel_defaults::DNBR;
             
             // Note: neighbors vector is NOT reset - it's a structural property
         }
     }
+    
+    /// Backend resets skipped by the last `reset()`/`restart_hot()`
+    /// Non-empty when the physics library lacks some reset symbols (older DLL).
+    pub fn get_skipped_resets(&self) -> Vec<String> {
+        self.skipped_resets.lock().unwrap().clone()
+    }
+    
+    /// Warm restart after a trip (hot standby, poisoned and burned core)
+    ///
+    /// Resets time, SCRAM, alerts, rods and neutronics like `reset()`, but
+    /// preserves per-channel xenon, iodine and burnup. Temperatures are set
+    /// to a hot-standby profile instead of cold shutdown.
+    pub fn restart_hot(&self) {
+        // Reset backend history (explosion tracking, 6-group precursors)
+        *self.skipped_resets.lock().unwrap() = self.backend.reset();
+        // As in reset(): a tuned prompt neutron lifetime does not survive the restart
+        tune_fortran(|| fortran_ffi::set_neutron_lifetime(constants::NEUTRON_LIFETIME));
+        self.alert_tracker.lock().unwrap().clear();
+        self.fault_injector.lock().unwrap().clear();
+        self.detector_faults.lock().unwrap().clear();
+        *self.event_times.lock().unwrap() = EventTimes::default();
+        self.channel_history.lock().unwrap().clear_samples();
+        self.reactivity_trace.lock().unwrap().clear();
+        self.keff_history.lock().unwrap().clear();
+        *self.last_time_step.lock().unwrap() = None;
+        self.stability_monitor.lock().unwrap().clear();
+        self.checkpoints.lock().unwrap().clear();
+        *self.reactivity_reference.lock().unwrap() = None;
+        self.inverse_m.lock().unwrap().clear();
+        self.reset_lar_zones();
+        
+        // All control rods fully inserted (shutdown), stuck rods stay where they are
+        {
+            let mut rods = self.control_rods.lock().unwrap();
+            for rod in rods.iter_mut() {
+                if !rod.stuck {
+                    rod.position = 0.0;
+                }
+            }
+        }
+        
+        // Reset transient per-channel state, keep poisons and burnup
+        let (avg_iodine, avg_xenon) = {
+            let mut channels = self.fuel_channels.lock().unwrap();
+            for channel in channels.iter_mut() {
+                // Thermal parameters (hot standby)
+                channel.fuel_temp = channel_defaults::HOT_STANDBY_FUEL_TEMP_K;
+                channel.coolant_temp = channel_defaults::HOT_STANDBY_COOLANT_TEMP_K;
+                channel.graphite_temp = channel_defaults::HOT_STANDBY_GRAPHITE_TEMP_K;
+                channel.coolant_void = channel_defaults::COOLANT_VOID_PERCENT;
+                
+                // Thermal-hydraulic parameters (nominal)
+                channel.pressure = channel_defaults::PRESSURE_MPA;
+                channel.flow_rate = channel_defaults::FLOW_RATE_KG_S;
+                channel.ruptured = false;  // Pressure tubes replaced
+                channel.uncovered = false;
+                channel.power_clamped = false;
+                channel.inlet_temp = channel_defaults::INLET_TEMP_K;
+                channel.outlet_temp = channel_defaults::OUTLET_TEMP_K;
+                
+                // Neutronics (shutdown)
+                channel.neutron_flux = channel_defaults::NEUTRON_FLUX;
+                channel.precursors = channel_defaults::PRECURSORS;
+                channel.power_density = channel_defaults::POWER_DENSITY_MW_M3;
+                channel.local_power = channel_defaults::LOCAL_POWER_MW;
+                
+                if channel.has_control_rod {
+                    channel.local_rod_position = 0.0;
+                }
+                channel.local_reactivity = channel_defaults::LOCAL_REACTIVITY;
+                channel.dnbr = channel_defaults::DNBR;
+                
+                // iodine_135, xenon_135, promethium_149, samarium_149 and burnup are preserved
+            }
+            
+            let n = channels.len().max(1) as f64;
+            (
+                channels.iter().map(|c| c.iodine_135).sum::<f64>() / n,
+                channels.iter().map(|c| c.xenon_135).sum::<f64>() / n,
+            )
+        };
+        
+        let mut state = self.state.lock().unwrap();
+        let dt = state.dt;
+        let display_unit = state.reactivity_display_unit;
+        let full_power_hours = state.full_power_hours;
+        *state = ReactorState::default();
+        state.dt = dt;
+        state.reactivity_display_unit = display_unit;
+        state.full_power_hours = full_power_hours; // Same fuel, same age
+        state.update_reactivity_units();
+        state.avg_fuel_temp = channel_defaults::HOT_STANDBY_FUEL_TEMP_K;
+        state.avg_coolant_temp = channel_defaults::HOT_STANDBY_COOLANT_TEMP_K;
+        state.avg_graphite_temp = channel_defaults::HOT_STANDBY_GRAPHITE_TEMP_K;
+        state.iodine_135 = avg_iodine;
+        state.xenon_135 = avg_xenon;
+        state.xenon_reactivity = self.backend.xenon_reactivity(avg_xenon);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Small core on the pure-Rust backend, so the test runs without the library
+    fn pure_rust_sim() -> ReactorSimulator {
+        ReactorSimulator::builder()
+            .fallback_grid(9)
+            .backend(Box::new(crate::pure_rust_backend::PureRustBackend::default()))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_builder_fallback_grid() {
+        let simulator = ReactorSimulator::builder()
+            .fallback_grid(9)
+            .nominal_power_mw(1000.0)
+            .build()
+            .unwrap();
+        let channels = simulator.get_fuel_channels();
+        assert!(!channels.is_empty());
+        assert!(channels.iter().any(|c| c.neighbors.len() == 4));
+        assert_eq!(simulator.get_active_layout(), CUSTOM_LAYOUT_NAME);
+        assert_eq!(simulator.backend_name(), backend::default_backend().name());
+
+        let isolated = ReactorSimulator::builder()
+            .fallback_grid(9)
+            .neighbor_mode(NeighborMode::Isolated)
+            .build()
+            .unwrap();
+        assert!(isolated.get_fuel_channels().iter().all(|c| c.neighbors.is_empty()));
+
+        assert!(ReactorSimulator::builder().channels(Vec::new()).build().is_err());
+        assert!(ReactorSimulator::builder().fallback_grid(9).nominal_power_mw(0.0).build().is_err());
+    }
+
+    #[test]
+    fn test_max_safe_time_speed_follows_period() {
+        let sim = ReactorSimulator::builder().fallback_grid(9).build().unwrap();
+        let dt = sim.get_state().dt;
+        assert_eq!(sim.get_max_safe_time_speed(), constants::REALTIME_STEP_BUDGET_PER_S * dt);
+        assert!(!sim.check_time_speed(1.0));
+
+        {
+            let mut state = sim.state.lock().unwrap();
+            state.reactivity_dollars = 0.5;
+            state.period = 3.0;
+        }
+        let max_speed = sim.get_max_safe_time_speed();
+        assert!((max_speed - constants::REALTIME_PERIOD_FRACTION * 3.0 / constants::REALTIME_FRAME_INTERVAL_S).abs() < 1e-9);
+        assert!(sim.check_time_speed(100.0));
+        assert!(sim.check_time_speed(100.0));
+        let raised = sim.get_state().alert_details.iter().filter(|a| a.code == AlertCode::TimeSpeedHigh).count();
+        assert_eq!(raised, 1);
+    }
+
+    #[test]
+    fn test_tuning_goes_through_the_backend() {
+        let sim = pure_rust_sim();
+        let physics = sim.get_physics_constants();
+        assert_eq!(physics.neutron_lifetime, constants::NEUTRON_LIFETIME);
+
+        // A one-minute period is a few tens of cents
+        let reactivity = sim.reactivity_from_period(60.0).unwrap();
+        assert!(reactivity > 0.0 && reactivity < constants::BETA_EFF);
+        sim.set_simulation_parameters(&sim.get_simulation_parameters()).unwrap();
+
+        // The fallback kinetics keep their lifetime, so the setting is refused
+        assert!(sim.set_neutron_lifetime(1.0e-4).is_err());
+        assert_eq!(sim.get_physics_constants().neutron_lifetime, constants::NEUTRON_LIFETIME);
+        let mut params = sim.get_simulation_parameters();
+        params.reactivity_smoothing_tau = 1.0;
+        assert!(sim.set_simulation_parameters(&params).is_err());
+        assert_eq!(sim.get_reactivity_smoothing_tau(), constants::REACTIVITY_SMOOTHING_TAU_DEFAULT_S);
+    }
+
+    #[test]
+    fn test_spatial_step_falls_back_without_the_library() {
+        if fortran_ffi::library_available() {
+            return;
+        }
+        let sim = pure_rust_sim();
+        sim.step_spatial();
+        assert!(sim.get_state().time > 0.0);
+    }
+
+    #[test]
+    fn test_critical_position_through_the_backend() {
+        let sim = pure_rust_sim();
+        for rod_type in [RodType::Manual, RodType::Automatic, RodType::Shortened] {
+            sim.move_rod_group(rod_type, 1.0);
+        }
+        let position = sim.predict_critical_position("RR").unwrap();
+        assert!((0.0..=1.0).contains(&position));
+        assert!(sim.predict_critical_position("missing").is_err());
+        let equilibrium = sim.equilibrium_rod_position(50.0, "RR").unwrap();
+        assert!((0.0..=1.0).contains(&equilibrium));
+        assert!(sim.equilibrium_rod_position(0.0, "RR").is_err());
+        assert!(!sim.get_control_rods().is_empty());
+    }
+
+    #[test]
+    fn test_shutdown_margin_and_sensitivity_through_the_backend() {
+        let sim = pure_rust_sim();
+        // Withdrawing any group adds reactivity
+        let sensitivity = sim.get_rod_group_sensitivity();
+        assert!(!sensitivity.is_empty());
+        assert!(sensitivity.values().all(|&s| s > 0.0));
+
+        // Withdrawing rods does not change the worst-stuck-rod configuration
+        let margin = sim.get_shutdown_margin();
+        assert!(margin > 0.0);
+        sim.move_rod_group(RodType::Manual, 1.0);
+        assert!((sim.get_shutdown_margin() - margin).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_xenon_fast_forward_leaves_kinetics_alone() {
+        let sim = pure_rust_sim();
+        let reactivity = {
+            let mut state = sim.state.lock().unwrap();
+            state.set_neutron_population(1.0);
+            state.power_percent = 100.0;
+            state.reactivity
+        };
+        sim.fast_forward_xenon(10.0).unwrap();
+        let state = sim.get_state();
+        assert!(state.xenon_135 > 0.0 && state.xenon_reactivity < 0.0);
+        assert_eq!(state.reactivity, reactivity);
+        assert!((state.time - 36_000.0).abs() < 1e-6);
+        assert!(sim.get_fuel_channels().iter().all(|c| c.xenon_135 > 0.0));
+
+        sim.set_freeze_xenon(true);
+        assert!(sim.fast_forward_xenon(1.0).is_err());
+    }
+
+    #[test]
+    fn test_xenon_peaks_hours_after_a_power_cut() {
+        let sim = pure_rust_sim();
+        {
+            let mut state = sim.state.lock().unwrap();
+            state.set_neutron_population(0.5);
+            state.power_percent = 50.0;
+        }
+        // Settle at half power, then cut it
+        sim.fast_forward_xenon(constants::XENON_FAST_FORWARD_MAX_HOURS).unwrap();
+        {
+            let mut state = sim.state.lock().unwrap();
+            state.set_neutron_population(0.0);
+            state.power_percent = 0.0;
+        }
+        let projection = sim.get_xenon_projection();
+        assert!(projection.peak_within_horizon);
+        assert!((7.0..=10.0).contains(&projection.hours_to_peak), "peak at {} h", projection.hours_to_peak);
+        assert!(projection.additional_reactivity < 0.0);
+    }
+
+    #[test]
+    fn test_reactivity_balance_follows_the_kinetics() {
+        let sim = pure_rust_sim();
+        let components = sim.get_reactivity_breakdown().component_sum();
+        sim.state.lock().unwrap().reactivity = components - 0.002;
+        assert!((sim.get_reactivity_balance_residual() + 0.002).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_restart_hot_keeps_poisons_without_fortran() {
+        let sim = pure_rust_sim();
+        {
+            let mut state = sim.state.lock().unwrap();
+            state.set_neutron_population(1.0);
+            state.power_percent = 100.0;
+        }
+        sim.fast_forward_xenon(10.0).unwrap();
+        let channels = sim.get_fuel_channels();
+        let xenon = channels.iter().map(|c| c.xenon_135).sum::<f64>() / channels.len() as f64;
+        sim.restart_hot();
+        let state = sim.get_state();
+        assert!((state.xenon_135 - xenon).abs() <= 1e-9 * xenon);
+        assert!(state.xenon_reactivity < 0.0);
+        assert_eq!(state.time, 0.0);
+    }
+
+    #[test]
+    fn test_rewind_restores_the_run() {
+        let sim = pure_rust_sim();
+        sim.step(); // First checkpoint
+        let state = sim.get_state();
+        let rods = sim.get_control_rods();
+        let channels = sim.get_fuel_channels();
+        
+        sim.move_rod_group(RodType::Manual, 0.5);
+        sim.move_rod(rods[0].id, 0.8);
+        sim.detector_faults.lock().unwrap().insert(0, ActiveDetectorFault { fault: DetectorFault::Dead, since: state.time });
+        while sim.get_state().time < state.time + 2.5 {
+            sim.step();
+        }
+        assert!(sim.get_event_times().last_rod_move.is_some());
+        let positions = |rods: &[ControlRod]| rods.iter().map(|r| r.position).collect::<Vec<_>>();
+        assert_ne!(positions(&sim.get_control_rods()), positions(&rods));
+        
+        assert!(sim.rewind(2.0));
+        let rewound = sim.get_state();
+        assert_eq!(rewound.time, state.time);
+        assert_eq!(rewound.neutron_population, state.neutron_population);
+        assert_eq!(rewound.avg_fuel_temp, state.avg_fuel_temp);
+        assert_eq!(positions(&sim.get_control_rods()), positions(&rods));
+        let fuel_temps = |channels: &[FuelChannel]| channels.iter().map(|c| c.fuel_temp).collect::<Vec<_>>();
+        assert_eq!(fuel_temps(&sim.get_fuel_channels()), fuel_temps(&channels));
+        assert!(sim.get_event_times().last_rod_move.is_none());
+        assert!(sim.detector_faults.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_config_hash_tracks_geometry() {
+        let build = |grid_size| ReactorSimulator::builder().fallback_grid(grid_size).build().unwrap();
+        assert_eq!(build(9).config_hash(), build(9).config_hash());
+        assert_ne!(build(9).config_hash(), build(11).config_hash());
+    }
+
+    #[test]
+    fn test_rod_worth_shape_applies_outside_scram() {
+        let sim = pure_rust_sim();
+        sim.move_rod_group(RodType::Manual, 0.5);
+        let inserted = || sim.get_rod_worth_summary().inserted_worth;
+        let symmetric = inserted();
+        sim.set_rod_worth_shape(&RodType::Manual, 2.0).unwrap();
+        assert!(inserted() > symmetric);
+    }
+
+    #[test]
+    fn test_rod_worth_summary_of_shutdown_core() {
+        // Every rod starts fully inserted
+        let sim = ReactorSimulator::builder().fallback_grid(9).build().unwrap();
+        let summary = sim.get_rod_worth_summary();
+        assert!(summary.available_worth > 0.0);
+        assert!((summary.inserted_fraction - 1.0).abs() < 1e-12);
+        let rods: usize = summary.by_type.iter().map(|g| g.rod_count).sum();
+        assert_eq!(rods, sim.get_control_rods().len());
+    }
+
+    #[test]
+    fn test_control_manifest_covers_every_rod() {
+        let sim = ReactorSimulator::builder().fallback_grid(9).build().unwrap();
+        let manifest = sim.describe_controls();
+        let rod_count = sim.get_control_rods().len();
+        assert_eq!(manifest.rods.len(), rod_count);
+        for command in ["move_rod_group", "move_rod_group_by_channel_type"] {
+            let grouped: usize = manifest.rod_groups.iter()
+                .filter(|g| g.command == command)
+                .map(|g| g.rod_ids.len())
+                .sum();
+            assert_eq!(grouped, rod_count);
+        }
+    }
+
+    #[test]
+    fn test_query_channels_filters_by_thresholds() {
+        let sim = ReactorSimulator::builder().fallback_grid(9).build().unwrap();
+        let count = sim.get_fuel_channels().len();
+        assert_eq!(sim.query_channels(&ChannelFilter::default()).len(), count);
+        sim.fuel_channels.lock().unwrap()[3].fuel_temp = 1500.0;
+        let hot = sim.query_channels(&ChannelFilter { min_fuel_temp: Some(1000.0), ..Default::default() });
+        assert_eq!(hot.iter().map(|c| c.id).collect::<Vec<_>>(), vec![3]);
+    }
+
+    #[test]
+    fn test_normalization_keeps_clamped_channels() {
+        let mut channels = create_fallback_channels(5);
+        for channel in channels.iter_mut() {
+            channel.local_power = 1.0;
+        }
+        channels[0].local_power = 20.0;
+        channels[0].power_clamped = true;
+        let target = 20.0 + 2.0 * (channels.len() - 1) as f64;
+        normalize_channel_powers(&mut channels, target);
+        assert_eq!(channels[0].local_power, 20.0);
+        assert!((channels.iter().map(|c| c.local_power).sum::<f64>() - target).abs() < 1e-9);
+
+        normalize_channel_powers(&mut channels, f64::NAN);
+        assert_eq!(channels[1].local_power, 2.0);
+    }
+
+    #[test]
+    fn test_grid_index_matches_channels() {
+        let sim = ReactorSimulator::builder().fallback_grid(9).build().unwrap();
+        let index = sim.get_grid_index();
+        let channels = sim.get_fuel_channels();
+        assert_eq!(index.len(), channels.len());
+        assert!(channels.iter().all(|c| index[&(c.grid_x, c.grid_y)] == c.id));
+    }
+
+    #[test]
+    fn test_inlet_plenum_settles_at_nominal_inlet() {
+        let mut state = ReactorState { avg_coolant_temp: 600.0, ..ReactorState::default() };
+        for _ in 0..1000 {
+            state.update_inlet_plenum(559.0, 10.0, 1.0);
+        }
+        assert!((state.inlet_plenum_temp - channel_defaults::INLET_TEMP_K).abs() < 1.0);
+
+        // Losing feedwater warms the plenum toward saturation
+        state.feedwater_fraction = 0.0;
+        state.update_inlet_plenum(559.0, 10.0, 1000.0);
+        assert_eq!(state.inlet_plenum_temp, 559.0);
+    }
+
+    #[test]
+    fn test_delayed_source_fractions() {
+        assert_eq!(delayed_source_fractions(&[0.0; constants::NUM_DELAYED_GROUPS]), [0.0; constants::NUM_DELAYED_GROUPS]);
+
+        // Equal precursor populations: the source follows the decay constants
+        let fractions = delayed_source_fractions(&[1.0; constants::NUM_DELAYED_GROUPS]);
+        assert!((fractions.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+        assert!(fractions[5] > fractions[0]);
+    }
+
+    #[test]
+    fn test_operating_region() {
+        assert_eq!(OperatingRegion::classify(0.0, 100.0), OperatingRegion::Shutdown);
+        assert_eq!(OperatingRegion::classify(10.0, 100.0), OperatingRegion::Unstable);
+        assert_eq!(OperatingRegion::classify(100.0, 100.0), OperatingRegion::Allowed);
+        assert_eq!(OperatingRegion::classify(115.0, 120.0), OperatingRegion::OverLimit);
+        assert_eq!(OperatingRegion::classify(60.0, 50.0), OperatingRegion::OverLimit);
+    }
+
+    #[test]
+    fn test_layout_out_of_range_cells() {
+        let cell = |x: i32, y: i32| serde_json::json!({
+            "grid_x": x, "grid_y": y,
+            "original_grid_x": x, "original_grid_y": y,
+            "pixel_x": 0, "pixel_y": 0, "area": 0
+        });
+        let config: LayoutConfig = serde_json::from_value(serde_json::json!({
+            "metadata": { "total_cells": 3, "grid_size": { "width": 48, "height": 48 } },
+            "cells": { "TK": [cell(0, 0), cell(47, 47), cell(99, 99)], "AZ": [cell(-1, 5)] }
+        }))
+        .unwrap();
+
+        let warnings = validate_layout(&config);
+        assert_eq!(warnings.len(), 2);
+        assert!(matches!(&warnings[0], LayoutWarning::OutOfRange { cell_type, grid_x: -1, .. } if cell_type == "AZ"));
+        assert!(matches!(&warnings[1], LayoutWarning::OutOfRange { index: 2, grid_x: 99, grid_y: 99, .. }));
+    }
+
+    #[test]
+    fn test_layout_name_stays_in_layout_dir() {
+        assert!(is_plain_layout_name("opb82"));
+        for name in ["", "../opb82", "config/opb82", "..\\opb82", "/etc/passwd", "C:opb82"] {
+            assert!(!is_plain_layout_name(name), "{:?}", name);
+        }
+        let sim = ReactorSimulator::builder().fallback_grid(9).build().unwrap();
+        assert!(sim.load_layout_named("../config/opb82").unwrap_err().starts_with("Invalid"));
+    }
+
+    #[test]
+    fn test_layout_fresh_channels() {
+        let cell = |x: i32, extra: serde_json::Value| {
+            let mut cell = serde_json::json!({
+                "grid_x": x, "grid_y": 0,
+                "original_grid_x": x, "original_grid_y": 0,
+                "pixel_x": 0, "pixel_y": 0, "area": 0
+            });
+            cell.as_object_mut().unwrap().extend(extra.as_object().unwrap().clone());
+            cell
+        };
+        let config: LayoutConfig = serde_json::from_value(serde_json::json!({
+            "metadata": { "total_cells": 3, "grid_size": { "width": 48, "height": 48 }, "equilibrium_burnup": 12.0 },
+            "cells": { "TK": [
+                cell(0, serde_json::json!({ "fresh": true })),
+                cell(1, serde_json::json!({})),
+                cell(2, serde_json::json!({ "initial_burnup": 20.0 })),
+            ] }
+        }))
+        .unwrap();
+
+        let burnups: Vec<f64> = create_channels_from_config(&config).iter().map(|c| c.burnup).collect();
+        assert_eq!(burnups, vec![0.0, 12.0, 20.0]);
+    }
 }
@@ -0,0 +1,183 @@
+//! Headless HTTP/WebSocket server (feature `server`)
+//!
+//! Exposes the simulator to scripts and remote clients without the Tauri UI.
+//! Every route calls the same `ReactorSimulator` methods as the Tauri
+//! commands, so both front ends see identical behavior.
+//!
+//! | Method | Path                 | Body                  | Returns              |
+//! |--------|----------------------|-----------------------|----------------------|
+//! | GET    | `/state`             |                       | `ReactorState`       |
+//! | POST   | `/step`              | `{"steps": n}` (opt.) | `SimulationResponse` |
+//! | POST   | `/rods/:id`          | `{"position": p}`     | `Vec<ControlRod>`    |
+//! | POST   | `/scram`             |                       | `ReactorState`       |
+//! | POST   | `/target_power`      | `{"target_percent": p}` | `TargetPowerResponse` |
+//! | GET    | `/ws`                | (WebSocket upgrade)   | `ReactorState` per step |
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::Response;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::Deserialize;
+use tokio::sync::broadcast;
+
+use crate::commands::{SimulationResponse, TargetPowerResponse};
+use crate::reactor::{ControlRod, ReactorSimulator, ReactorState};
+
+/// Number of unsent states buffered per WebSocket client before it lags
+const STATE_CHANNEL_CAPACITY: usize = 64;
+
+/// Shared state of the server routes
+#[derive(Clone)]
+struct ServerState {
+    simulator: Arc<ReactorSimulator>,
+    updates: broadcast::Sender<String>,
+}
+
+impl ServerState {
+    /// Push the current state to all WebSocket clients
+    fn publish(&self, state: &ReactorState) {
+        if let Ok(json) = serde_json::to_string(state) {
+            // No subscribers is not an error
+            let _ = self.updates.send(json);
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct StepRequest {
+    steps: Option<usize>,
+}
+
+#[derive(Deserialize)]
+struct RodPositionRequest {
+    position: f64,
+}
+
+#[derive(Deserialize)]
+struct TargetPowerRequest {
+    target_percent: f64,
+}
+
+/// Build the router for a simulator
+pub fn router(simulator: Arc<ReactorSimulator>) -> Router {
+    let (updates, _) = broadcast::channel(STATE_CHANNEL_CAPACITY);
+    let state = ServerState { simulator, updates };
+
+    Router::new()
+        .route("/state", get(get_state))
+        .route("/step", post(step))
+        .route("/rods/:id", post(move_rod))
+        .route("/scram", post(scram))
+        .route("/target_power", post(set_target_power))
+        .route("/ws", get(ws_upgrade))
+        .with_state(state)
+}
+
+/// Serve the simulator on `addr` until the process exits
+pub async fn serve(simulator: Arc<ReactorSimulator>, addr: SocketAddr) -> std::io::Result<()> {
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    log::info!("RBMK simulator server listening on {}", addr);
+    axum::serve(listener, router(simulator)).await
+}
+
+async fn get_state(State(server): State<ServerState>) -> Json<ReactorState> {
+    Json(server.simulator.get_state())
+}
+
+/// Advance the simulation (one step unless `steps` is given)
+async fn step(
+    State(server): State<ServerState>,
+    body: Option<Json<StepRequest>>,
+) -> Result<Json<SimulationResponse>, (StatusCode, String)> {
+    let steps = body.and_then(|Json(req)| req.steps).unwrap_or(1);
+    let simulator = server.simulator.clone();
+    let worker = server.clone();
+
+    // Physics steps are blocking FFI calls; keep them off the async workers
+    let response = tokio::task::spawn_blocking(move || {
+        for _ in 0..steps {
+            simulator.step_spatial();
+            worker.publish(&simulator.get_state());
+        }
+        SimulationResponse {
+            state: simulator.get_state(),
+            control_rods: simulator.get_control_rods(),
+        }
+    })
+    .await
+    .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(response))
+}
+
+async fn move_rod(
+    State(server): State<ServerState>,
+    Path(rod_id): Path<usize>,
+    Json(req): Json<RodPositionRequest>,
+) -> Result<Json<Vec<ControlRod>>, (StatusCode, String)> {
+    if !server.simulator.move_rod(rod_id, req.position) {
+        let rod_count = server.simulator.control_rods.lock().unwrap().len();
+        return Err((
+            StatusCode::NOT_FOUND,
+            format!("Invalid rod_id {}: valid range is 0..{}", rod_id, rod_count),
+        ));
+    }
+    Ok(Json(server.simulator.get_control_rods()))
+}
+
+async fn scram(State(server): State<ServerState>) -> Json<ReactorState> {
+    server.simulator.scram();
+    let state = server.simulator.get_state();
+    server.publish(&state);
+    Json(state)
+}
+
+async fn set_target_power(
+    State(server): State<ServerState>,
+    Json(req): Json<TargetPowerRequest>,
+) -> Json<TargetPowerResponse> {
+    let target = server.simulator.set_target_power(req.target_percent);
+    Json(TargetPowerResponse {
+        auto_regulator: server.simulator.get_auto_regulator(),
+        target,
+    })
+}
+
+async fn ws_upgrade(ws: WebSocketUpgrade, State(server): State<ServerState>) -> Response {
+    ws.on_upgrade(move |socket| push_states(socket, server))
+}
+
+/// Send the current state, then every state published after a step
+async fn push_states(mut socket: WebSocket, server: ServerState) {
+    let mut updates = server.updates.subscribe();
+
+    if let Ok(json) = serde_json::to_string(&server.simulator.get_state()) {
+        if socket.send(Message::Text(json)).await.is_err() {
+            return;
+        }
+    }
+
+    loop {
+        tokio::select! {
+            update = updates.recv() => match update {
+                Ok(json) => {
+                    if socket.send(Message::Text(json)).await.is_err() {
+                        return;
+                    }
+                }
+                // Slow client: skip the missed states and carry on
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => return,
+            },
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => return,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! Detection of numerical divergence
+//!
+//! An integrator run with too large a time step gives itself away by power
+//! that swings up and down on alternate steps with a growing amplitude. Real
+//! kinetics never reverses direction every single step, so such a run of
+//! power changes is flagged as non-physical and a smaller time step is
+//! suggested (or applied, when automatic reduction is enabled).
+
+use serde::{Deserialize, Serialize};
+
+use crate::reactor::constants;
+
+/// Consecutive sign-alternating, growing power changes that count as divergence
+pub const DIVERGENCE_WINDOW_STEPS: usize = 6;
+
+/// Power changes smaller than this are noise and break a run [% nominal]
+pub const DIVERGENCE_NOISE_FLOOR_PERCENT: f64 = 0.01;
+
+/// Result of the divergence watch
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StabilityStatus {
+    pub diverging: bool,                // Divergence detected on the last step
+    pub oscillating_steps: usize,       // Length of the current alternating, growing run
+    pub detections: u32,                // Detections since the last reset
+    pub last_detected_at: Option<f64>,  // Simulation time of the last detection [s]
+    pub suggested_dt: Option<f64>,      // Half the time step in use at that detection [s]
+    pub auto_reduce_dt: bool,           // Apply the suggested time step automatically
+}
+
+/// Watches the power after each step for growing step-to-step oscillation
+#[derive(Debug, Clone, Default)]
+pub struct StabilityMonitor {
+    last_power: Option<f64>,
+    last_delta: f64,
+    run: usize,
+    diverging: bool,
+    detections: u32,
+    last_detected_at: Option<f64>,
+    suggested_dt: Option<f64>,
+    auto_reduce_dt: bool,
+}
+
+impl StabilityMonitor {
+    /// Feed the power after a step taken with `dt`; true when divergence is detected
+    pub fn record(&mut self, time: f64, power_percent: f64, dt: f64) -> bool {
+        self.diverging = false;
+        let Some(previous) = self.last_power.replace(power_percent) else {
+            return false;
+        };
+        let delta = power_percent - previous;
+        let alternating_growth = delta.abs() > DIVERGENCE_NOISE_FLOOR_PERCENT
+            && delta.signum() != self.last_delta.signum()
+            && delta.abs() > self.last_delta.abs();
+        self.run = if alternating_growth { self.run + 1 } else { 0 };
+        self.last_delta = delta;
+
+        if self.run >= DIVERGENCE_WINDOW_STEPS {
+            self.run = 0;
+            self.diverging = true;
+            self.detections += 1;
+            self.last_detected_at = Some(time);
+            self.suggested_dt = Some((dt / 2.0).max(constants::TIME_STEP_MIN_S));
+        }
+        self.diverging
+    }
+
+    /// Time step to switch to after the last detection [s]
+    pub fn suggested_dt(&self) -> Option<f64> {
+        self.suggested_dt
+    }
+
+    pub fn auto_reduce_dt(&self) -> bool {
+        self.auto_reduce_dt
+    }
+
+    pub fn set_auto_reduce_dt(&mut self, enabled: bool) {
+        self.auto_reduce_dt = enabled;
+    }
+
+    /// Forget the watched history; the auto-reduce setting is kept
+    pub fn clear(&mut self) {
+        *self = StabilityMonitor { auto_reduce_dt: self.auto_reduce_dt, ..Default::default() };
+    }
+
+    pub fn status(&self) -> StabilityStatus {
+        StabilityStatus {
+            diverging: self.diverging,
+            oscillating_steps: self.run,
+            detections: self.detections,
+            last_detected_at: self.last_detected_at,
+            suggested_dt: self.suggested_dt,
+            auto_reduce_dt: self.auto_reduce_dt,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_growing_oscillation_is_detected() {
+        let mut monitor = StabilityMonitor::default();
+        // Smooth exponential growth is physical
+        for i in 0..50 {
+            assert!(!monitor.record(i as f64, 50.0 * 1.01f64.powi(i), 0.1));
+        }
+
+        // Alternating swings of growing amplitude are not
+        let mut detected = false;
+        for i in 0..2 * DIVERGENCE_WINDOW_STEPS {
+            let swing = if i % 2 == 0 { 1.0 } else { -1.0 } * (i + 1) as f64;
+            detected |= monitor.record(50.0 + i as f64, 80.0 + swing, 0.1);
+        }
+        assert!(detected);
+        assert_eq!(monitor.suggested_dt(), Some(0.05));
+    }
+}
@@ -0,0 +1,97 @@
+//! Approach to criticality (1/M plot)
+//!
+//! During a startup the operator withdraws rods in increments and records
+//! the source-range count rate after each one. The inverse multiplication
+//! 1/M = C₀/C falls toward zero as the core nears criticality, so a straight
+//! line through the last two points predicts the critical rod position
+//! before it is reached.
+
+use serde::{Deserialize, Serialize};
+
+/// Points kept on one 1/M plot
+pub const MAX_INVERSE_M_POINTS: usize = 200;
+
+/// One recorded point of the 1/M plot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InverseMPoint {
+    pub rod_position: f64, // Rod bank withdrawal (0 = inserted, 1 = withdrawn)
+    pub count_rate: f64,   // Source-range count rate [counts/s]
+    pub inverse_m: f64,    // Count rate of the first point / this count rate
+}
+
+/// The 1/M plot with its extrapolation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InverseMCurve {
+    pub points: Vec<InverseMPoint>,
+    /// Where the line through the last two points reaches 1/M = 0
+    /// (None with fewer than two points or if 1/M is not falling)
+    pub predicted_critical_position: Option<f64>,
+}
+
+/// Recorded (rod position, count rate) pairs, in order
+#[derive(Debug, Clone, Default)]
+pub struct InverseMRecorder {
+    points: Vec<(f64, f64)>,
+}
+
+impl InverseMRecorder {
+    pub fn record(&mut self, rod_position: f64, count_rate: f64) -> Result<(), String> {
+        if !(count_rate.is_finite() && count_rate > 0.0) {
+            return Err(format!("Count rate {} must be positive", count_rate));
+        }
+        if self.points.len() == MAX_INVERSE_M_POINTS {
+            return Err(format!("1/M plot is full ({} points)", MAX_INVERSE_M_POINTS));
+        }
+        self.points.push((rod_position, count_rate));
+        Ok(())
+    }
+
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+
+    pub fn curve(&self) -> InverseMCurve {
+        let Some(&(_, reference)) = self.points.first() else {
+            return InverseMCurve { points: Vec::new(), predicted_critical_position: None };
+        };
+        let points: Vec<InverseMPoint> = self.points.iter()
+            .map(|&(rod_position, count_rate)| InverseMPoint {
+                rod_position,
+                count_rate,
+                inverse_m: reference / count_rate,
+            })
+            .collect();
+
+        let predicted_critical_position = match points.as_slice() {
+            [.., a, b] if b.rod_position != a.rod_position => {
+                let slope = (b.inverse_m - a.inverse_m) / (b.rod_position - a.rod_position);
+                (slope < 0.0).then(|| b.rod_position - b.inverse_m / slope)
+            }
+            _ => None,
+        };
+
+        InverseMCurve { points, predicted_critical_position }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extrapolates_to_critical() {
+        let mut recorder = InverseMRecorder::default();
+        assert!(recorder.curve().predicted_critical_position.is_none());
+
+        // M = 1 / (1 - x / 0.8): critical at 80 % withdrawal
+        for x in [0.0, 0.2, 0.4] {
+            recorder.record(x, 100.0 / (1.0 - x / 0.8)).unwrap();
+        }
+        let curve = recorder.curve();
+        assert_eq!(curve.points.len(), 3);
+        assert_eq!(curve.points[0].inverse_m, 1.0);
+        assert!((curve.predicted_critical_position.unwrap() - 0.8).abs() < 1e-9);
+
+        assert!(recorder.record(0.5, 0.0).is_err());
+    }
+}
@@ -0,0 +1,243 @@
+//! Compact binary state stream for high-rate logging
+//!
+//! Each simulation step can be appended to a stream as one fixed-size,
+//! little-endian record. There is no header or framing: a stream is simply
+//! a concatenation of records, so it can be appended to at any time and
+//! read back by splitting it into `RECORD_SIZE` byte chunks.
+//!
+//! Record layout (156 bytes, all values little-endian):
+//!
+//! | Offset | Type | Field                |
+//! |--------|------|----------------------|
+//! | 0      | f64  | time [s]             |
+//! | 8      | f64  | dt [s]               |
+//! | 16     | f64  | power_mw             |
+//! | 24     | f64  | power_percent        |
+//! | 32     | f64  | neutron_population   |
+//! | 40     | f64  | precursors           |
+//! | 48     | f64  | k_eff                |
+//! | 56     | f64  | reactivity [Δk/k]    |
+//! | 64     | f64  | reactivity_dollars   |
+//! | 72     | f64  | period [s]           |
+//! | 80     | f64  | iodine_135           |
+//! | 88     | f64  | xenon_135            |
+//! | 96     | f64  | xenon_reactivity     |
+//! | 104    | f64  | avg_fuel_temp [K]    |
+//! | 112    | f64  | avg_coolant_temp [K] |
+//! | 120    | f64  | avg_graphite_temp [K]|
+//! | 128    | f64  | avg_coolant_void [%] |
+//! | 136    | f64  | scram_time [s]       |
+//! | 144    | f64  | explosion_time [s]   |
+//! | 152    | u32  | flags (see `FLAG_*`) |
+
+use serde::{Deserialize, Serialize};
+use std::io::{self, Read, Write};
+
+use crate::reactor::ReactorState;
+
+/// Size of one encoded record in bytes
+pub const RECORD_SIZE: usize = 19 * 8 + 4;
+
+/// Flag bit: SCRAM active
+pub const FLAG_SCRAM_ACTIVE: u32 = 1;
+/// Flag bit: steam explosion has occurred
+pub const FLAG_EXPLOSION: u32 = 2;
+/// Flag bit: automatic regulator enabled
+pub const FLAG_AUTO_REGULATOR: u32 = 4;
+
+/// Scalar state captured in one binary record
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StateRecord {
+    pub time: f64,
+    pub dt: f64,
+    pub power_mw: f64,
+    pub power_percent: f64,
+    pub neutron_population: f64,
+    pub precursors: f64,
+    pub k_eff: f64,
+    pub reactivity: f64,
+    pub reactivity_dollars: f64,
+    pub period: f64,
+    pub iodine_135: f64,
+    pub xenon_135: f64,
+    pub xenon_reactivity: f64,
+    pub avg_fuel_temp: f64,
+    pub avg_coolant_temp: f64,
+    pub avg_graphite_temp: f64,
+    pub avg_coolant_void: f64,
+    pub scram_time: f64,
+    pub explosion_time: f64,
+    pub flags: u32,
+}
+
+impl StateRecord {
+    /// Capture the scalar fields of a reactor state
+    pub fn from_state(state: &ReactorState) -> Self {
+        let mut flags = 0;
+        if state.scram_active {
+            flags |= FLAG_SCRAM_ACTIVE;
+        }
+        if state.explosion_occurred {
+            flags |= FLAG_EXPLOSION;
+        }
+        if state.auto_regulator.enabled {
+            flags |= FLAG_AUTO_REGULATOR;
+        }
+
+        Self {
+            time: state.time,
+            dt: state.dt,
+            power_mw: state.power_mw,
+            power_percent: state.power_percent,
+            neutron_population: state.neutron_population,
+            precursors: state.precursors,
+            k_eff: state.k_eff,
+            reactivity: state.reactivity,
+            reactivity_dollars: state.reactivity_dollars,
+            period: state.period,
+            iodine_135: state.iodine_135,
+            xenon_135: state.xenon_135,
+            xenon_reactivity: state.xenon_reactivity,
+            avg_fuel_temp: state.avg_fuel_temp,
+            avg_coolant_temp: state.avg_coolant_temp,
+            avg_graphite_temp: state.avg_graphite_temp,
+            avg_coolant_void: state.avg_coolant_void,
+            scram_time: state.scram_time,
+            explosion_time: state.explosion_time,
+            flags,
+        }
+    }
+
+    /// Values in record order (excluding flags)
+    fn values(&self) -> [f64; 19] {
+        [
+            self.time,
+            self.dt,
+            self.power_mw,
+            self.power_percent,
+            self.neutron_population,
+            self.precursors,
+            self.k_eff,
+            self.reactivity,
+            self.reactivity_dollars,
+            self.period,
+            self.iodine_135,
+            self.xenon_135,
+            self.xenon_reactivity,
+            self.avg_fuel_temp,
+            self.avg_coolant_temp,
+            self.avg_graphite_temp,
+            self.avg_coolant_void,
+            self.scram_time,
+            self.explosion_time,
+        ]
+    }
+
+    /// Encode into the fixed little-endian layout
+    pub fn to_bytes(&self) -> [u8; RECORD_SIZE] {
+        let mut bytes = [0u8; RECORD_SIZE];
+        for (i, value) in self.values().iter().enumerate() {
+            bytes[i * 8..(i + 1) * 8].copy_from_slice(&value.to_le_bytes());
+        }
+        bytes[RECORD_SIZE - 4..].copy_from_slice(&self.flags.to_le_bytes());
+        bytes
+    }
+
+    /// Decode from the fixed little-endian layout
+    pub fn from_bytes(bytes: &[u8; RECORD_SIZE]) -> Self {
+        let f = |i: usize| {
+            let mut buf = [0u8; 8];
+            buf.copy_from_slice(&bytes[i * 8..(i + 1) * 8]);
+            f64::from_le_bytes(buf)
+        };
+        let mut flag_bytes = [0u8; 4];
+        flag_bytes.copy_from_slice(&bytes[RECORD_SIZE - 4..]);
+
+        Self {
+            time: f(0),
+            dt: f(1),
+            power_mw: f(2),
+            power_percent: f(3),
+            neutron_population: f(4),
+            precursors: f(5),
+            k_eff: f(6),
+            reactivity: f(7),
+            reactivity_dollars: f(8),
+            period: f(9),
+            iodine_135: f(10),
+            xenon_135: f(11),
+            xenon_reactivity: f(12),
+            avg_fuel_temp: f(13),
+            avg_coolant_temp: f(14),
+            avg_graphite_temp: f(15),
+            avg_coolant_void: f(16),
+            scram_time: f(17),
+            explosion_time: f(18),
+            flags: u32::from_le_bytes(flag_bytes),
+        }
+    }
+}
+
+/// Append one record for the given state to a writer
+pub fn write_record(writer: &mut dyn Write, state: &ReactorState) -> io::Result<()> {
+    writer.write_all(&StateRecord::from_state(state).to_bytes())
+}
+
+/// Read all records from a binary state stream
+///
+/// Fails with `InvalidData` if the stream ends in the middle of a record.
+pub fn read_state_stream(reader: &mut dyn Read) -> io::Result<Vec<StateRecord>> {
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data)?;
+
+    if data.len() % RECORD_SIZE != 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Truncated state stream: {} trailing bytes", data.len() % RECORD_SIZE),
+        ));
+    }
+
+    Ok(data
+        .chunks_exact(RECORD_SIZE)
+        .map(|chunk| {
+            let mut bytes = [0u8; RECORD_SIZE];
+            bytes.copy_from_slice(chunk);
+            StateRecord::from_bytes(&bytes)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut state = ReactorState {
+            time: 12.5,
+            power_percent: 87.0,
+            scram_active: true,
+            ..Default::default()
+        };
+
+        let mut stream = Vec::new();
+        write_record(&mut stream, &state).unwrap();
+        state.time = 12.6;
+        write_record(&mut stream, &state).unwrap();
+        assert_eq!(stream.len(), 2 * RECORD_SIZE);
+
+        let records = read_state_stream(&mut stream.as_slice()).unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].time, 12.5);
+        assert_eq!(records[1].time, 12.6);
+        assert_eq!(records[1].power_percent, 87.0);
+        assert!(records[1].period.is_infinite());
+        assert_eq!(records[1].flags, FLAG_SCRAM_ACTIVE);
+    }
+
+    #[test]
+    fn test_truncated_stream() {
+        let stream = vec![0u8; RECORD_SIZE + 3];
+        assert!(read_state_stream(&mut stream.as_slice()).is_err());
+    }
+}
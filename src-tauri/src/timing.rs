@@ -0,0 +1,66 @@
+//! Step timing instrumentation (feature `profiling`)
+//!
+//! With the feature enabled, `step_spatial()` records the wall-clock time of
+//! each of its phases into a `StepTiming`. Without it the timer compiles to
+//! nothing and `ReactorSimulator::get_last_step_timing()` always returns None.
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "profiling")]
+use std::time::Instant;
+
+/// Wall-clock duration of each phase of the last `step_spatial()` [ms]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StepTiming {
+    pub control_ms: f64,    // Regulator, drill faults, SCRAM travel, rod worth
+    pub input_prep_ms: f64, // Building the per-channel Fortran inputs
+    pub ffi_ms: f64,        // Fortran spatial step
+    pub output_ms: f64,     // Copying outputs back into the channels
+    pub averaging_ms: f64,  // Core averages, kinetics bookkeeping, alerts
+    pub total_ms: f64,
+}
+
+/// Times consecutive phases of one step
+#[cfg(feature = "profiling")]
+pub(crate) struct PhaseTimer {
+    started: Instant,
+    last: Instant,
+    timing: StepTiming,
+}
+
+#[cfg(feature = "profiling")]
+impl PhaseTimer {
+    pub(crate) fn start() -> Self {
+        let now = Instant::now();
+        Self { started: now, last: now, timing: StepTiming::default() }
+    }
+
+    /// Charge the time since the previous lap to one phase
+    pub(crate) fn lap(&mut self, phase: impl FnOnce(&mut StepTiming) -> &mut f64) {
+        let now = Instant::now();
+        *phase(&mut self.timing) += (now - self.last).as_secs_f64() * 1e3;
+        self.last = now;
+    }
+
+    pub(crate) fn finish(mut self) -> Option<StepTiming> {
+        self.timing.total_ms = self.started.elapsed().as_secs_f64() * 1e3;
+        Some(self.timing)
+    }
+}
+
+/// No-op timer when profiling is disabled
+#[cfg(not(feature = "profiling"))]
+pub(crate) struct PhaseTimer;
+
+#[cfg(not(feature = "profiling"))]
+impl PhaseTimer {
+    pub(crate) fn start() -> Self {
+        PhaseTimer
+    }
+
+    pub(crate) fn lap(&mut self, _phase: impl FnOnce(&mut StepTiming) -> &mut f64) {}
+
+    pub(crate) fn finish(self) -> Option<StepTiming> {
+        None
+    }
+}